@@ -0,0 +1,38 @@
+use std::f32::consts::TAU;
+
+/// A second angle φ, shown on its own circle and added to θ on a third, for
+/// exploring the angle-addition identities.
+#[derive(Debug, Default)]
+pub struct AngleSum {
+    active: bool,
+    /// The second angle in radians, within `[0, 2π)`.
+    pub phi: f32,
+    /// The rate φ moves at in radians per second.
+    pub rate: f32,
+}
+
+impl AngleSum {
+    pub fn toggle(&mut self) {
+        self.active = !self.active;
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn update(&mut self, delta_time: f32, is_running: bool) {
+        if self.active && is_running {
+            self.phi = (self.phi + self.rate * delta_time).rem_euclid(TAU);
+        }
+    }
+
+    /// Moves φ by `delta` radians, wrapping to `[0, 2π)`.
+    pub fn shift_phi(&mut self, delta: f32) {
+        self.phi = (self.phi + delta).rem_euclid(TAU);
+    }
+
+    /// Changes the rate of φ by `delta`, without letting it go backwards.
+    pub fn shift_rate(&mut self, delta: f32) {
+        self.rate = (self.rate + delta).max(0.0);
+    }
+}
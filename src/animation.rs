@@ -0,0 +1,85 @@
+use nannou::image::{
+    self,
+    codecs::gif::{GifEncoder, Repeat},
+    Delay, Frame,
+};
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The number of frames in one revolution, one every 5°.
+const ANIMATION_FRAMES: usize = 72;
+/// How long each frame of the animation is shown for, in milliseconds.
+const FRAME_MILLIS: u32 = 50;
+
+/// Renders one revolution of theta, one frame per step, to be encoded as a
+/// looping GIF once every frame has been saved.
+#[derive(Debug)]
+pub struct AnimationExport {
+    dir: PathBuf,
+    frame: Option<usize>,
+}
+
+impl AnimationExport {
+    /// Creates an export which saves its frames to `dir`.
+    pub const fn new(dir: PathBuf) -> Self {
+        Self { dir, frame: None }
+    }
+
+    /// Moves on to the next frame, returning its angle, or `None` once every
+    /// frame has been rendered.
+    pub fn next_frame(&mut self) -> Option<f32> {
+        let frame = self.frame.map_or(0, |f| f + 1);
+        self.frame = Some(frame);
+
+        (frame < ANIMATION_FRAMES).then(|| {
+            frame as f32 / ANIMATION_FRAMES as f32 * std::f32::consts::TAU
+        })
+    }
+
+    /// The path the current frame is saved to.
+    pub fn current_path(&self) -> Option<PathBuf> {
+        let frame = self.frame.filter(|&f| f < ANIMATION_FRAMES)?;
+        Some(self.dir.join(format!("{frame:03}.png")))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+/// Encodes the frames saved in `frames` into a looping GIF at `out` on
+/// another thread, then deletes the frames.
+pub fn spawn_gif_encoder(frames: PathBuf, out: PathBuf) {
+    std::thread::spawn(move || match write_gif(&frames, &out) {
+        Ok(()) => {
+            _ = fs::remove_dir_all(&frames);
+            println!("exported animation to {}", out.display());
+        }
+        Err(e) => eprintln!("failed to encode animation: {e}"),
+    });
+}
+
+fn write_gif(frames: &Path, out: &Path) -> io::Result<()> {
+    let mut encoder = GifEncoder::new(File::create(out)?);
+    encoder
+        .set_repeat(Repeat::Infinite)
+        .map_err(io::Error::other)?;
+
+    for frame in 0..ANIMATION_FRAMES {
+        let image = image::open(frames.join(format!("{frame:03}.png")))
+            .map_err(io::Error::other)?
+            .to_rgba8();
+
+        encoder
+            .encode_frame(Frame::from_parts(
+                image,
+                0,
+                0,
+                Delay::from_numer_denom_ms(FRAME_MILLIS, 1),
+            ))
+            .map_err(io::Error::other)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use std::f32::consts::{PI, TAU};
+
+/// Slows θ down as it passes bookmarked angles, easing the rate down to a
+/// fraction of itself within a window either side of each one.
+#[derive(Debug)]
+pub struct BulletTime {
+    active: bool,
+    /// How far either side of a bookmark the slowdown reaches, in radians.
+    window: f32,
+    /// The fraction of the rate θ turns at when it's on a bookmark.
+    factor: f32,
+}
+
+impl BulletTime {
+    pub const fn new(window: f32, factor: f32) -> Self {
+        Self { active: false, window, factor }
+    }
+
+    pub fn toggle(&mut self) {
+        self.active = !self.active;
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub const fn window(&self) -> f32 {
+        self.window
+    }
+
+    /// What the rate is multiplied by with θ at `theta`, from the factor on
+    /// a bookmark up to `1` outside the window of every bookmark.
+    pub fn rate_scale(&self, theta: f32, bookmarks: &[f32]) -> f32 {
+        if !self.active || self.window <= 0.0 {
+            return 1.0;
+        }
+
+        let nearest = bookmarks
+            .iter()
+            .map(|&bookmark| {
+                // the shortest way round, in case they're either side of 0
+                ((theta - bookmark + PI).rem_euclid(TAU) - PI).abs()
+            })
+            .fold(f32::INFINITY, f32::min);
+
+        if nearest >= self.window {
+            return 1.0;
+        }
+
+        // smoothstep, so the rate eases in and out rather than jumping
+        let t = nearest / self.window;
+        let ease = t * t * (3.0 - 2.0 * t);
+
+        self.factor + (1.0 - self.factor) * ease
+    }
+}
@@ -0,0 +1,132 @@
+use nannou::prelude::*;
+use nannou::wgpu::{BlendComponent, BlendFactor, BlendOperation};
+
+/// How far the brightness can be raised or lowered.
+pub const MAX_BRIGHTNESS: f32 = 0.5;
+pub const MIN_CONTRAST: f32 = 0.5;
+pub const MAX_CONTRAST: f32 = 2.0;
+
+/// How far each key press moves the brightness or contrast.
+const STEP: f32 = 0.05;
+
+/// A brightness and contrast adjustment applied to everything drawn, for
+/// projectors which wash the colours out, and the screen used to tune it.
+#[derive(Debug)]
+pub struct Calibration {
+    shown: bool,
+    /// Added to every colour, from `-MAX_BRIGHTNESS` to `MAX_BRIGHTNESS`.
+    brightness: f32,
+    /// How far colours are pushed away from mid-grey, where `1` leaves them
+    /// as they are.
+    contrast: f32,
+}
+
+impl Calibration {
+    pub fn new(brightness: f32, contrast: f32) -> Self {
+        Self {
+            shown: false,
+            brightness: brightness.clamp(-MAX_BRIGHTNESS, MAX_BRIGHTNESS),
+            contrast: contrast.clamp(MIN_CONTRAST, MAX_CONTRAST),
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        self.shown = !self.shown;
+    }
+
+    /// Hides the calibration screen, returning whether it was shown.
+    pub fn close(&mut self) -> bool {
+        std::mem::replace(&mut self.shown, false)
+    }
+
+    pub const fn is_shown(&self) -> bool {
+        self.shown
+    }
+
+    pub const fn brightness(&self) -> f32 {
+        self.brightness
+    }
+
+    pub const fn contrast(&self) -> f32 {
+        self.contrast
+    }
+
+    /// Adjusts the brightness with `Up` / `Down` and the contrast with
+    /// `Right` / `Left`, or resets both with `0`, returning whether `key`
+    /// was one of them.
+    pub fn key_pressed(&mut self, key: Key) -> bool {
+        match key {
+            Key::Up => self.brightness += STEP,
+            Key::Down => self.brightness -= STEP,
+            Key::Right => self.contrast += STEP,
+            Key::Left => self.contrast -= STEP,
+            Key::Key0 => {
+                self.brightness = 0.0;
+                self.contrast = 1.0;
+            }
+            _ => return false,
+        }
+
+        // rounded, so repeated steps don't drift away from round numbers
+        self.brightness = ((self.brightness / STEP).round() * STEP)
+            .clamp(-MAX_BRIGHTNESS, MAX_BRIGHTNESS);
+        self.contrast = ((self.contrast / STEP).round() * STEP)
+            .clamp(MIN_CONTRAST, MAX_CONTRAST);
+
+        true
+    }
+
+    /// Adjusts everything already drawn within `rect`.
+    ///
+    /// This draws over it with blending which scales and then offsets the
+    /// colours beneath, so that it applies to the whole palette without
+    /// touching any of the colours themselves.
+    pub fn apply(&self, draw: &Draw, rect: Rect) {
+        let identity = self.brightness.abs() < f32::EPSILON
+            && (self.contrast - 1.0).abs() < f32::EPSILON;
+        if identity {
+            return;
+        }
+
+        let blend = |src_factor, dst_factor, operation, value: f32| {
+            draw.color_blend(BlendComponent {
+                src_factor,
+                dst_factor,
+                operation,
+            })
+            .rect()
+            .xy(rect.xy())
+            .wh(rect.wh())
+            .color(Rgba::new(value, value, value, 1.0));
+        };
+
+        // the blend factors only go up to 1, so a contrast above 1 is made by
+        // adding a fraction of the colour beneath back onto itself
+        if self.contrast >= 1.0 {
+            blend(
+                BlendFactor::Dst,
+                BlendFactor::One,
+                BlendOperation::Add,
+                self.contrast - 1.0,
+            );
+        }
+        else {
+            blend(
+                BlendFactor::Zero,
+                BlendFactor::Src,
+                BlendOperation::Add,
+                self.contrast,
+            );
+        }
+
+        // contrast is about mid-grey, so scaling has to be offset back
+        let offset = 0.5 * (1.0 - self.contrast) + self.brightness;
+        let operation = if offset >= 0.0 {
+            BlendOperation::Add
+        }
+        else {
+            BlendOperation::ReverseSubtract
+        };
+        blend(BlendFactor::One, BlendFactor::One, operation, offset.abs());
+    }
+}
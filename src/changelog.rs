@@ -0,0 +1,18 @@
+/// The changelog, compiled into the binary.
+const CHANGELOG: &str = include_str!("../CHANGELOG.md");
+
+/// The version of this build.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The entries listed under `version`'s heading in the changelog.
+pub fn entries_for(version: &str) -> Vec<&'static str> {
+    let heading = format!("## {version}");
+
+    CHANGELOG
+        .lines()
+        .skip_while(|line| line.trim() != heading)
+        .skip(1)
+        .take_while(|line| !line.starts_with("## "))
+        .filter_map(|line| line.trim().strip_prefix("- "))
+        .collect()
+}
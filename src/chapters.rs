@@ -0,0 +1,87 @@
+/// How long a chapter's title card is shown for, in seconds.
+const TITLE_CARD_DURATION: f32 = 3.0;
+/// How long the title card takes to fade out at the end, in seconds.
+const TITLE_CARD_FADE: f32 = 0.6;
+
+/// A named point in a script's timeline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Chapter {
+    pub title: String,
+    /// The script time the chapter starts at, in seconds.
+    pub start: f32,
+}
+
+/// Follows which of the script's chapters is playing, showing a title card
+/// when a new one starts.
+#[derive(Debug, Default)]
+pub struct Chapters {
+    chapters: Vec<Chapter>,
+    current: Option<usize>,
+    card_remaining: f32,
+    menu_visible: bool,
+}
+
+impl Chapters {
+    /// Updates the chapter list and the chapter playing at `time`.
+    pub fn update(&mut self, chapters: &[Chapter], time: f32, delta_time: f32) {
+        if self.chapters != chapters {
+            self.chapters = chapters.to_vec();
+        }
+
+        self.card_remaining = (self.card_remaining - delta_time).max(0.0);
+
+        let playing = self.chapters.iter().rposition(|ch| ch.start <= time);
+        if playing != self.current {
+            self.current = playing;
+            self.card_remaining = if playing.is_some() {
+                TITLE_CARD_DURATION
+            }
+            else {
+                0.0
+            };
+        }
+    }
+
+    /// Removes all chapters, such as when the script is reloaded.
+    pub fn clear(&mut self) {
+        *self = Self { menu_visible: self.menu_visible, ..Self::default() };
+    }
+
+    pub fn list(&self) -> &[Chapter] {
+        &self.chapters
+    }
+
+    pub const fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Returns the start time of the chapter at `index`. Its title card is
+    /// shown again, even if it's the one already playing.
+    pub fn jump(&mut self, index: usize) -> Option<f32> {
+        let start = self.chapters.get(index)?.start;
+        self.current = None;
+
+        Some(start)
+    }
+
+    /// The title of the chapter which just started and the opacity of its
+    /// card, if one is being shown.
+    pub fn title_card(&self) -> Option<(&str, f32)> {
+        if self.card_remaining <= 0.0 {
+            return None;
+        }
+
+        let chapter = &self.chapters[self.current?];
+        let alpha = (self.card_remaining / TITLE_CARD_FADE).min(1.0);
+
+        Some((&chapter.title, alpha))
+    }
+
+    pub fn toggle_menu(&mut self) {
+        self.menu_visible = !self.menu_visible;
+    }
+
+    pub const fn is_menu_visible(&self) -> bool {
+        self.menu_visible
+    }
+}
@@ -0,0 +1,304 @@
+use crate::consts::*;
+use crate::view::{Placement, View};
+use nannou::prelude::*;
+use std::f32::MAX as INF;
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct TrigValues {
+    /// Sine function
+    pub sin: f32,
+    /// Cosine function
+    pub cos: f32,
+    /// Tangent function
+    pub tan: f32,
+    /// Cotangent function
+    pub cot: f32,
+    /// Secant function
+    pub sec: f32,
+    /// Cosecant function
+    pub csc: f32,
+}
+
+impl TrigValues {
+    /// The value of each function at `theta`, which may be infinite.
+    pub fn new(theta: f32) -> Self {
+        let (sin, cos) = theta.sin_cos();
+
+        Self {
+            sin,
+            cos,
+            tan: theta.tan(),
+            cot: theta.tan().recip(),
+            sec: cos.recip(),
+            csc: sin.recip(),
+        }
+    }
+
+    pub fn clamp_inf(&mut self) {
+        self.tan = self.tan.clamp(-INF, INF);
+        self.cot = self.cot.clamp(-INF, INF);
+        self.sec = self.sec.clamp(-INF, INF);
+        self.csc = self.csc.clamp(-INF, INF);
+    }
+}
+
+impl std::ops::Mul<f32> for TrigValues {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self {
+            sin: self.sin * rhs,
+            cos: self.cos * rhs,
+            tan: self.tan * rhs,
+            cot: self.cot * rhs,
+            sec: self.sec * rhs,
+            csc: self.csc * rhs,
+        }
+    }
+}
+
+impl TrigValues {
+    /// The start and end of `function`'s line, in world coordinates.
+    pub fn line(&self, function: Function) -> (Vec2, Vec2) {
+        let point = vec2(self.cos, self.sin);
+
+        match function {
+            Function::Sin => (vec2(self.cos, 0.0), point),
+            Function::Cos => (Vec2::ZERO, vec2(self.cos, 0.0)),
+            Function::Tan => (vec2(1.0, 0.0), vec2(1.0, self.tan)),
+            Function::Cot => (point, vec2(0.0, self.csc)),
+            Function::Sec => (Vec2::ZERO, vec2(1.0, self.tan)),
+            Function::Csc => (Vec2::ZERO, vec2(0.0, self.csc)),
+        }
+    }
+}
+
+/// One of the six functions, each drawn as a line on the circle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Function {
+    Sin,
+    Cos,
+    Tan,
+    Cot,
+    Sec,
+    Csc,
+}
+
+impl Function {
+    pub const ALL: [Self; 6] = [
+        Self::Sin,
+        Self::Cos,
+        Self::Tan,
+        Self::Cot,
+        Self::Sec,
+        Self::Csc,
+    ];
+
+    pub const fn color(self) -> Rgb {
+        match self {
+            Self::Sin => SIN_COLOR,
+            Self::Cos => COS_COLOR,
+            Self::Tan => TAN_COLOR,
+            Self::Cot => COT_COLOR,
+            Self::Sec => SEC_COLOR,
+            Self::Csc => CSC_COLOR,
+        }
+    }
+
+    /// A value whose sign is the side of its line the function's label sits
+    /// on at `theta`, and which is near zero where the side changes.
+    ///
+    /// Labels sit on the outside of the triangle their line is part of, so
+    /// sin and csc swap sides when the point crosses the y-axis, cos and cot
+    /// when it crosses the x-axis, and sec when tan changes sign. tan's line
+    /// is always on the outside of its triangle, so its label moves in
+    /// towards the rest of the figure while the point is on the left.
+    pub fn label_side(self, theta: f32) -> f32 {
+        let (sin, cos) = theta.sin_cos();
+
+        match self {
+            Self::Sin | Self::Tan => cos,
+            Self::Cos => -sin,
+            Self::Cot => sin,
+            Self::Sec => sin * cos,
+            Self::Csc => -cos,
+        }
+    }
+
+    /// Where the function's label sits beside the middle of its line at
+    /// `theta`. `side` is the sign of [`Function::label_side`], which may be
+    /// eased between `-1` and `1` as it changes.
+    pub fn label_placement(self, theta: f32, side: f32) -> Placement {
+        let mut values = TrigValues::new(theta);
+        values.clamp_inf();
+        let TrigValues { sin, cos, tan, csc, .. } = values;
+
+        let (anchor, offset) = match self {
+            Self::Sin => (vec2(cos, sin * 0.5), vec2(LABEL_GAP * side, 0.0)),
+            Self::Cos => {
+                (vec2(cos * 0.5, 0.0), vec2(0.0, AXIS_LABEL_GAP * side))
+            }
+            Self::Tan => (vec2(1.0, tan * 0.5), vec2(LABEL_GAP * side, 0.0)),
+            // cot and sec lean away from the lines which cross them near
+            // the middle, further the steeper the lines are
+            Self::Cot => {
+                let x_dir = if sin < 0.0 { -1.0 } else { 1.0 };
+                (
+                    vec2(cos * 0.5, (sin + csc) * 0.5),
+                    vec2(x_dir * cos * 20.0, (12.0 + sin.abs() * 8.0) * side),
+                )
+            }
+            Self::Sec => {
+                let lean = tan.signum() * sin.abs();
+                (
+                    vec2(0.5, tan * 0.5),
+                    vec2(-tan * 5.0 - lean * 10.0, 18.0 * side),
+                )
+            }
+            Self::Csc => (vec2(0.0, csc * 0.5), vec2(LABEL_GAP * side, 0.0)),
+        };
+
+        Placement::new(anchor, offset)
+    }
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Sin => SIN_LABEL,
+            Self::Cos => COS_LABEL,
+            Self::Tan => TAN_LABEL,
+            Self::Cot => COT_LABEL,
+            Self::Sec => SEC_LABEL,
+            Self::Csc => CSC_LABEL,
+        }
+    }
+}
+
+/// Which of the functions' lines are shown.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug)]
+pub struct Visible {
+    pub sin: bool,
+    pub cos: bool,
+    pub tan: bool,
+    pub cot: bool,
+    pub sec: bool,
+    pub csc: bool,
+}
+
+impl Visible {
+    pub const fn shows(&self, function: Function) -> bool {
+        match function {
+            Function::Sin => self.sin,
+            Function::Cos => self.cos,
+            Function::Tan => self.tan,
+            Function::Cot => self.cot,
+            Function::Sec => self.sec,
+            Function::Csc => self.csc,
+        }
+    }
+}
+
+impl Default for Visible {
+    fn default() -> Self {
+        Self {
+            sin: true,
+            cos: true,
+            tan: true,
+            cot: true,
+            sec: true,
+            csc: true,
+        }
+    }
+}
+
+/// The unit circle at an angle θ, with the line of each function, for
+/// drawing into other nannou sketches.
+#[derive(Clone, Copy, Debug)]
+pub struct TrigCircle {
+    pub theta: f32,
+    pub visible: Visible,
+    pub labels: bool,
+    /// The colour of the circle, the radius and the point on the circle.
+    pub color: Rgba,
+}
+
+impl TrigCircle {
+    pub fn new(theta: f32) -> Self {
+        Self {
+            theta,
+            visible: Visible::default(),
+            labels: true,
+            color: Rgba::new(1.0, 1.0, 1.0, 1.0),
+        }
+    }
+
+    #[must_use]
+    pub const fn visible(mut self, visible: Visible) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    #[must_use]
+    pub const fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    #[must_use]
+    pub const fn color(mut self, color: Rgba) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Draws the circle centred on `center`.
+    pub fn draw(&self, draw: &Draw, center: Vec2, radius: f32) {
+        let view = View::new(center, radius);
+
+        let mut values = TrigValues::new(self.theta);
+        // some values can be inf, so this is needed to prevent a geometry error!
+        values.clamp_inf();
+        let point = view.to_pixels(vec2(values.cos, values.sin));
+
+        draw.ellipse()
+            .no_fill()
+            .radius(radius)
+            .stroke_weight(STROKE_WEIGHT - 1.0)
+            .stroke(self.color)
+            .xy(center);
+
+        for function in Function::ALL {
+            if !self.visible.shows(function) {
+                continue;
+            }
+
+            let (start, end) = values.line(function);
+            let (start, end) = (view.to_pixels(start), view.to_pixels(end));
+            let color = function.color();
+
+            draw.line()
+                .start(start)
+                .end(end)
+                .color(color)
+                .stroke_weight(STROKE_WEIGHT);
+
+            if self.labels {
+                let pos = view.place(function.label_placement(
+                    self.theta,
+                    function.label_side(self.theta).signum(),
+                ));
+
+                draw.text(function.label())
+                    .xy(pos)
+                    .font_size(LABEL_FONT_SIZE)
+                    .color(color);
+            }
+        }
+
+        draw.line()
+            .start(center)
+            .end(point)
+            .color(self.color)
+            .stroke_weight(STROKE_WEIGHT);
+        draw.ellipse().radius(8.0).color(self.color).xy(point);
+    }
+}
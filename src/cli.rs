@@ -0,0 +1,142 @@
+use crate::display::value_rows_at;
+use crate::export::{write_figure_pdf, FigureProfile};
+use crate::labels::Label;
+use crate::ramp::RateRamp;
+use crate::watermark::Watermark;
+use std::path::PathBuf;
+
+pub const USAGE: &str =
+    "usage: trig_visuals [--theta <radians>] [--rate <rad/s>] \
+                     [--ramp [<from>:]<to>:<secs>] \
+                     [--hide <sin,cos,tan,cot,sec,csc>] [--light] \
+                     [--fullscreen]
+       trig_visuals render --theta <radians> --out <file.pdf> \
+                     [--profile print|screen]";
+
+/// Runs the `render` subcommand, which writes a figure of the circle at a
+/// given angle without opening a window.
+pub fn render(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut theta = 0.0;
+    let mut out = None;
+    let mut profile = FigureProfile::default();
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for {arg}"))
+        };
+
+        match arg.as_str() {
+            "--theta" => theta = parse_finite(&arg, &value()?)?,
+            "--out" => out = Some(PathBuf::from(value()?)),
+            "--profile" => {
+                profile = match value()?.as_str() {
+                    "print" => FigureProfile::Print,
+                    "screen" => FigureProfile::Screen,
+                    other => return Err(format!("unknown profile: {other}")),
+                };
+            }
+            _ => return Err(format!("unknown argument: {arg}\n{USAGE}")),
+        }
+    }
+
+    let out = out.ok_or_else(|| String::from(USAGE))?;
+    // figures are drawn as vectors, so only PDF output is supported; images
+    // need the window's renderer
+    match out.extension().and_then(|ext| ext.to_str()) {
+        Some("pdf") => {}
+        Some("png") => {
+            return Err(format!(
+                "render only writes PDFs; write {} and convert it, e.g. \
+                 with `pdftoppm -png -singlefile -r 300 {} {}`, or take a \
+                 screenshot in the app with F12",
+                out.with_extension("pdf").display(),
+                out.with_extension("pdf").display(),
+                out.with_extension("").display(),
+            ));
+        }
+        _ => return Err(format!("{} must be a .pdf file", out.display())),
+    }
+
+    // there's no window here, so the watermark comes from the state file
+    let watermark = Watermark::load();
+    write_figure_pdf(&out, theta, &value_rows_at(theta), profile, &watermark)
+        .map_err(|e| format!("failed to write {}: {e}", out.display()))?;
+
+    println!("rendered {}", out.display());
+    Ok(())
+}
+
+/// The state to launch the app in, set from the command line.
+#[derive(Clone, Debug, Default)]
+pub struct InitialState {
+    pub theta: Option<f32>,
+    pub rate: Option<f32>,
+    /// A change in the rate to start with.
+    pub ramp: Option<RateRamp>,
+    /// The functions to hide.
+    pub hidden: Vec<Label>,
+    pub light: bool,
+    pub fullscreen: bool,
+    /// Whether the usage was asked for, rather than launching the app.
+    pub help: bool,
+}
+
+/// Parses the options for launching the app.
+pub fn initial_state(
+    mut args: impl Iterator<Item = String>,
+) -> Result<InitialState, String> {
+    let mut state = InitialState::default();
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for {arg}"))
+        };
+
+        match arg.as_str() {
+            "--theta" => state.theta = Some(parse_finite(&arg, &value()?)?),
+            "--rate" => state.rate = Some(parse_finite(&arg, &value()?)?),
+            "--ramp" => {
+                let value = value()?;
+                state.ramp = Some(
+                    RateRamp::parse(&value)
+                        .ok_or_else(|| format!("invalid --ramp: {value}"))?,
+                );
+            }
+            "--hide" => {
+                for name in value()?.split(',').map(str::trim) {
+                    state.hidden.push(match name {
+                        "sin" => Label::Sin,
+                        "cos" => Label::Cos,
+                        "tan" => Label::Tan,
+                        "cot" => Label::Cot,
+                        "sec" => Label::Sec,
+                        "csc" => Label::Csc,
+                        _ => return Err(format!("unknown function: {name}")),
+                    });
+                }
+            }
+            "--light" => state.light = true,
+            "--fullscreen" => state.fullscreen = true,
+            "--help" | "-h" => state.help = true,
+            _ => return Err(format!("unknown argument: {arg}\n{USAGE}")),
+        }
+    }
+
+    Ok(state)
+}
+
+/// Parses the value of the option `arg`, which must be a finite number.
+fn parse_finite(arg: &str, value: &str) -> Result<f32, String> {
+    let parsed = value
+        .parse::<f32>()
+        .map_err(|e| format!("invalid {arg}: {e}"))?;
+
+    if parsed.is_finite() {
+        Ok(parsed)
+    }
+    else {
+        Err(format!("invalid {arg}: {value} isn't a finite number"))
+    }
+}
@@ -0,0 +1,75 @@
+use crate::locale::Locale;
+use std::f32::consts::{PI, TAU};
+
+/// Session clock which only advances while the circle is in motion.
+#[derive(Debug, Default)]
+pub struct Clock {
+    elapsed_secs: f32,
+}
+
+impl Clock {
+    pub const fn with_elapsed(elapsed_secs: f32) -> Self {
+        Self { elapsed_secs }
+    }
+
+    pub fn update(&mut self, delta_time: f32, is_running: bool) {
+        if is_running {
+            self.elapsed_secs += delta_time;
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.elapsed_secs = 0.0;
+    }
+
+    pub const fn elapsed_secs(&self) -> f32 {
+        self.elapsed_secs
+    }
+
+    /// The time taken to complete one revolution at `rate`, or `None` if the
+    /// circle isn't moving.
+    pub fn revolution_secs(rate: f32) -> Option<f32> {
+        (rate > 0.0).then(|| TAU / rate)
+    }
+}
+
+/// Formats `secs` as `m:ss.s`, using `locale`'s decimal separator.
+pub fn fmt_duration(secs: f32, locale: Locale) -> String {
+    let mins = (secs / 60.0).floor();
+    let secs = locale.format(secs - mins * 60.0, 1);
+    let padding = if secs.len() < 4 { "0" } else { "" };
+    format!("{mins:.0}:{padding}{secs}")
+}
+
+/// Counts down to the moment θ next reaches a target angle.
+#[derive(Debug)]
+pub struct Countdown {
+    /// The target angle in radians, within `[0, 2π)`.
+    pub target: f32,
+    /// Whether to pause the circle once θ reaches the target.
+    pub auto_pause: bool,
+}
+
+impl Default for Countdown {
+    fn default() -> Self {
+        Self { target: PI, auto_pause: false }
+    }
+}
+
+impl Countdown {
+    /// Moves the target by `delta` radians, wrapping to `[0, 2π)`.
+    pub fn shift_target(&mut self, delta: f32) {
+        self.target = (self.target + delta).rem_euclid(TAU);
+    }
+
+    /// The angle left to travel from `theta` before reaching the target.
+    pub fn distance_from(&self, theta: f32) -> f32 {
+        (self.target - theta).rem_euclid(TAU)
+    }
+
+    /// The time left until θ reaches the target at `rate`, or `None` if the
+    /// circle isn't moving.
+    pub fn remaining_secs(&self, theta: f32, rate: f32) -> Option<f32> {
+        (rate > 0.0).then(|| self.distance_from(theta) / rate)
+    }
+}
@@ -0,0 +1,18 @@
+use nannou::prelude::*;
+
+/// One of the lines in the scene.
+#[derive(Clone, Copy, Debug)]
+pub struct Segment {
+    pub start: Vec2,
+    pub end: Vec2,
+    pub color: Rgb,
+}
+
+/// A copy of the scene's lines at one moment, drawn faintly beneath the live
+/// scene so the effect of changing a parameter can be seen side by side.
+#[derive(Clone, Debug)]
+pub struct Before {
+    pub segments: Vec<Segment>,
+    pub radius: f32,
+    pub theta: f32,
+}
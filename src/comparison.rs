@@ -0,0 +1,49 @@
+use std::f32::consts::{PI, TAU};
+use trig_visuals::TrigValues;
+
+/// A second angle ψ drawn faintly on the same circle as θ, with its values
+/// beside θ's, for comparing the two.
+#[derive(Debug, Default)]
+pub struct Comparison {
+    active: bool,
+    /// The second angle in radians, within `[0, 2π)`.
+    psi: f32,
+}
+
+impl Comparison {
+    /// Starts comparing against `theta`, so that ψ moves away from where θ
+    /// is, or stops comparing.
+    pub fn toggle(&mut self, theta: f32) {
+        self.active = !self.active;
+        self.psi = theta;
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub const fn psi(&self) -> f32 {
+        self.psi
+    }
+
+    /// Moves ψ by `delta` radians, wrapping to `[0, 2π)`.
+    pub fn shift_psi(&mut self, delta: f32) {
+        self.psi = (self.psi + delta).rem_euclid(TAU);
+    }
+
+    /// The value of each function at ψ, which may be infinite.
+    pub fn values(&self) -> TrigValues {
+        TrigValues::new(self.psi)
+    }
+
+    /// θ − ψ, the shortest way round, within `(-π, π]`.
+    pub fn difference(&self, theta: f32) -> f32 {
+        let diff = (theta - self.psi).rem_euclid(TAU);
+        if diff > PI {
+            diff - TAU
+        }
+        else {
+            diff
+        }
+    }
+}
@@ -3,9 +3,45 @@ use std::marker::PhantomData as PD;
 
 pub const DEFAULT_RATE: f32 = 0.25;
 pub const RATE_INCREMENT: f32 = 0.08;
+/// The slowest and fastest everything can be run at, as multiples of real
+/// time.
+pub const MIN_TIME_SCALE: f32 = 1.0 / 16.0;
+pub const MAX_TIME_SCALE: f32 = 4.0;
+/// How much the rate changes per radian of a two-finger rotation.
+pub const TOUCH_RATE_PER_RADIAN: f32 = RATE_INCREMENT;
 pub const STROKE_WEIGHT: f32 = 3.0;
 pub const LABEL_FONT_SIZE: u32 = 15;
+/// The distance from a line to the label beside it, in pixels.
+pub const LABEL_GAP: f32 = 22.0;
+/// The distance from the x-axis or the radius to the label over it, in
+/// pixels.
+pub const AXIS_LABEL_GAP: f32 = 15.0;
 pub const UNIT_RADIUS: f32 = 200.0;
+/// The smallest the circle's radius can be set to, in pixels.
+pub const MIN_RADIUS: f32 = 10.0;
+pub const MEASURE_PICK_DISTANCE: f32 = 25.0;
+pub const NODE_PICK_DISTANCE: f32 = 20.0;
+pub const COUNTDOWN_TARGET_INCREMENT: f32 = std::f32::consts::PI / 12.0;
+pub const ANGLE_SUM_INCREMENT: f32 = std::f32::consts::PI / 12.0;
+pub const COMPARISON_INCREMENT: f32 = std::f32::consts::PI / 12.0;
+pub const JUMP_PULSE_SECS: f32 = 0.6;
+pub const WATERMARK_HEIGHT: f32 = 64.0;
+pub const WATERMARK_PDF_HEIGHT: f32 = 48.0;
+pub const HISTORY_SLIDER_OFFSET: f32 = 25.0;
+pub const HISTORY_SLIDER_WIDTH: f32 = 600.0;
+/// The height of each entry in the "what's new" panel.
+pub const WHATS_NEW_ROW_HEIGHT: f32 = 26.0;
+/// The height of the "what's new" panel around its entries, and the space
+/// left between it and the edges of the window.
+pub const WHATS_NEW_PADDING: f32 = 120.0;
+pub const WHATS_NEW_MARGIN: f32 = 60.0;
+/// The gap between the circle and the start of the wave traces.
+pub const TRACE_GAP: f32 = 20.0;
+/// The length of one period of each wave trace in pixels.
+pub const TRACE_PERIOD: f32 = 240.0;
+
+/// The angles jumped to by the number keys, in twelfths of π.
+pub const JUMP_TWELFTHS: [u32; 9] = [0, 2, 3, 4, 6, 9, 12, 18, 21];
 
 pub const FADE_TIME_SECS: f32 = 0.3;
 pub const FADE_INTENSITY: f32 = 0.925;
@@ -16,6 +52,9 @@ pub const TAN_LABEL: &str = "tan θ";
 pub const COT_LABEL: &str = "cot θ";
 pub const SEC_LABEL: &str = "sec θ";
 pub const CSC_LABEL: &str = "csc θ";
+pub const SINH_LABEL: &str = "sinh t";
+pub const COSH_LABEL: &str = "cosh t";
+pub const TANH_LABEL: &str = "tanh t";
 
 pub const SIN_COLOR: Rgb = Rgb { red: 1.0, green: 0.0, blue: 0.0, standard: PD };
 pub const COS_COLOR: Rgb = Rgb { red: 1.0, green: 1.0, blue: 0.0, standard: PD };
@@ -23,3 +62,31 @@ pub const TAN_COLOR: Rgb = Rgb { red: 0.0, green: 1.0, blue: 0.0, standard: PD }
 pub const COT_COLOR: Rgb = Rgb { red: 0.0, green: 1.0, blue: 1.0, standard: PD };
 pub const SEC_COLOR: Rgb = Rgb { red: 0.0, green: 0.4, blue: 1.0, standard: PD };
 pub const CSC_COLOR: Rgb = Rgb { red: 1.0, green: 0.0, blue: 1.0, standard: PD };
+pub const KEY_COLOR: Rgb = Rgb { red: 0.0, green: 0.69, blue: 0.25, standard: PD };
+pub const BROADCAST_TAN_COLOR: Rgb = Rgb { red: 0.95, green: 0.55, blue: 0.25, standard: PD };
+pub const BROADCAST_COT_COLOR: Rgb = Rgb { red: 0.7, green: 0.6, blue: 1.0, standard: PD };
+pub const DE_MOIVRE_COLOR: Rgb = Rgb { red: 0.7, green: 0.5, blue: 1.0, standard: PD };
+pub const REFERENCE_ANGLE_COLOR: Rgb = Rgb { red: 0.55, green: 1.0, blue: 0.45, standard: PD };
+pub const REFERENCE_RAY_COLOR: Rgb = Rgb { red: 1.0, green: 0.85, blue: 0.5, standard: PD };
+pub const ANGLE_SUM_COLOR: Rgb = Rgb { red: 1.0, green: 0.45, blue: 0.7, standard: PD };
+/// The colours of the parts of the angle-sum construction built on cos β and
+/// on sin β.
+pub const IDENTITY_COS_COLOR: Rgb = Rgb { red: 1.0, green: 0.75, blue: 0.3, standard: PD };
+pub const IDENTITY_SIN_COLOR: Rgb = Rgb { red: 0.4, green: 0.8, blue: 1.0, standard: PD };
+pub const VELOCITY_COLOR: Rgb = Rgb { red: 0.3, green: 0.9, blue: 0.9, standard: PD };
+/// The colour of area under the sine trace which counts against the integral.
+pub const NEGATIVE_AREA_COLOR: Rgb = Rgb { red: 0.3, green: 0.5, blue: 1.0, standard: PD };
+pub const ACCELERATION_COLOR: Rgb = Rgb { red: 1.0, green: 0.55, blue: 0.3, standard: PD };
+pub const RECORDING_COLOR: Rgb = Rgb { red: 0.9, green: 0.3, blue: 0.3, standard: PD };
+/// The colours of the first to fourth quadrants on the θ arc.
+pub const QUADRANT_COLORS: [Rgb; 4] = [
+    Rgb { red: 0.3, green: 0.9, blue: 0.5, standard: PD },
+    Rgb { red: 0.3, green: 0.6, blue: 1.0, standard: PD },
+    Rgb { red: 0.8, green: 0.4, blue: 1.0, standard: PD },
+    Rgb { red: 1.0, green: 0.6, blue: 0.2, standard: PD },
+];
+pub const INVERSE_COLOR: Rgb = Rgb { red: 0.4, green: 0.9, blue: 0.6, standard: PD };
+pub const MEASURE_COLOR: Rgb = Rgb { red: 1.0, green: 0.6, blue: 0.0, standard: PD };
+pub const DOMAIN_NEGATIVE_COLOR: Rgb = Rgb { red: 0.9, green: 0.3, blue: 0.3, standard: PD };
+pub const DOMAIN_LARGE_COLOR: Rgb = Rgb { red: 0.3, green: 0.6, blue: 1.0, standard: PD };
+pub const DOMAIN_UNDEFINED_COLOR: Rgb = Rgb { red: 1.0, green: 0.8, blue: 0.2, standard: PD };
@@ -0,0 +1,199 @@
+use crate::locale::Locale;
+use crate::model::{AngleUnit, Theme};
+use std::backtrace::Backtrace;
+use std::fmt::Write as _;
+use std::fs;
+use std::panic;
+use std::sync::Mutex;
+use trig_visuals::Visible;
+
+/// The file a crash report is written to, relative to the working directory.
+pub const CRASH_FILE: &str = "trig_visuals.crash";
+
+/// How often a new snapshot is recorded, in seconds.
+pub const SNAPSHOT_INTERVAL: f32 = 1.0;
+
+/// The most recent snapshot, written out by the panic hook.
+static LATEST: Mutex<Option<Snapshot>> = Mutex::new(None);
+
+/// The parts of the scene needed to resume after a crash.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    pub theta: f32,
+    pub rate: f32,
+    pub radius: f32,
+    pub is_running: bool,
+    pub draw_labels: bool,
+    pub draw_values: bool,
+    pub draw_theta: bool,
+    pub elapsed_secs: f32,
+    pub countdown_target: f32,
+    pub visible: Visible,
+    pub theme: Theme,
+    pub locale: Locale,
+    pub angle_unit: AngleUnit,
+    pub bookmarks: Vec<f32>,
+}
+
+impl Snapshot {
+    /// Stores this snapshot so that it's saved if the app panics.
+    pub fn record(self) {
+        if let Ok(mut latest) = LATEST.lock() {
+            *latest = Some(self);
+        }
+    }
+
+    fn to_lines(&self) -> String {
+        let visible = self.visible;
+        let bookmarks = self
+            .bookmarks
+            .iter()
+            .map(f32::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!(
+            "theta = {}\nrate = {}\nradius = {}\nis_running = {}\n\
+             draw_labels = {}\ndraw_values = {}\ndraw_theta = {}\n\
+             elapsed_secs = {}\ncountdown_target = {}\n\
+             visible = {} {} {} {} {} {}\ntheme = {}\nlocale = {}\n\
+             angle_unit = {}\nbookmarks = {bookmarks}\n",
+            self.theta,
+            self.rate,
+            self.radius,
+            self.is_running,
+            self.draw_labels,
+            self.draw_values,
+            self.draw_theta,
+            self.elapsed_secs,
+            self.countdown_target,
+            visible.sin,
+            visible.cos,
+            visible.tan,
+            visible.cot,
+            visible.sec,
+            visible.csc,
+            theme_name(self.theme),
+            locale_name(self.locale),
+            angle_unit_name(self.angle_unit),
+        )
+    }
+
+    fn from_lines(contents: &str) -> Option<Self> {
+        let value = |key: &str| {
+            contents
+                .lines()
+                .filter_map(|line| line.split_once('='))
+                .find(|(k, _)| k.trim() == key)
+                .map(|(_, v)| v.trim())
+        };
+        let float = |key: &str| value(key)?.parse::<f32>().ok();
+        let flag = |key: &str| value(key).map(|v| v == "true");
+        let mut shown =
+            value("visible")?.split_whitespace().map(|v| v == "true");
+        let mut next_shown = || shown.next();
+
+        Some(Self {
+            theta: float("theta")?,
+            rate: float("rate")?,
+            radius: float("radius")?,
+            is_running: flag("is_running")?,
+            draw_labels: flag("draw_labels")?,
+            draw_values: flag("draw_values")?,
+            draw_theta: flag("draw_theta")?,
+            elapsed_secs: float("elapsed_secs")?,
+            countdown_target: float("countdown_target")?,
+            visible: Visible {
+                sin: next_shown()?,
+                cos: next_shown()?,
+                tan: next_shown()?,
+                cot: next_shown()?,
+                sec: next_shown()?,
+                csc: next_shown()?,
+            },
+            theme: match value("theme")? {
+                "light" => Theme::Light,
+                _ => Theme::Dark,
+            },
+            locale: match value("locale")? {
+                "decimal_comma" => Locale::DecimalComma,
+                _ => Locale::DecimalPoint,
+            },
+            angle_unit: match value("angle_unit")? {
+                "degrees" => AngleUnit::Degrees,
+                "turns" => AngleUnit::Turns,
+                "pi_multiples" => AngleUnit::PiMultiples,
+                _ => AngleUnit::Radians,
+            },
+            bookmarks: value("bookmarks")?
+                .split(',')
+                .filter_map(|b| b.trim().parse().ok())
+                .collect(),
+        })
+    }
+}
+
+const fn theme_name(theme: Theme) -> &'static str {
+    match theme {
+        Theme::Dark => "dark",
+        Theme::Light => "light",
+    }
+}
+
+const fn locale_name(locale: Locale) -> &'static str {
+    match locale {
+        Locale::DecimalPoint => "decimal_point",
+        Locale::DecimalComma => "decimal_comma",
+    }
+}
+
+const fn angle_unit_name(unit: AngleUnit) -> &'static str {
+    match unit {
+        AngleUnit::Radians => "radians",
+        AngleUnit::Degrees => "degrees",
+        AngleUnit::Turns => "turns",
+        AngleUnit::PiMultiples => "pi_multiples",
+    }
+}
+
+/// Installs a panic hook which writes the latest snapshot and a backtrace to
+/// the crash file before running the default hook.
+pub fn install_hook() {
+    let default_hook = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+        let mut report = LATEST
+            .lock()
+            .ok()
+            .and_then(|latest| latest.as_ref().map(Snapshot::to_lines))
+            .unwrap_or_default();
+
+        _ = write!(
+            report,
+            "\n# {info}\n# {}",
+            Backtrace::force_capture().to_string().replace('\n', "\n# ")
+        );
+
+        match fs::write(CRASH_FILE, report) {
+            Ok(()) => eprintln!(
+                "trig_visuals crashed; the session was saved to {CRASH_FILE} \
+                 and will be restored on the next launch"
+            ),
+            Err(e) => eprintln!("failed to write crash file: {e}"),
+        }
+
+        default_hook(info);
+    }));
+}
+
+/// Reads and removes the crash file, returning the snapshot it holds, if any.
+pub fn take_snapshot() -> Option<Snapshot> {
+    let contents = fs::read_to_string(CRASH_FILE).ok()?;
+
+    if let Err(e) = fs::remove_file(CRASH_FILE) {
+        eprintln!("failed to remove crash file: {e}");
+    }
+
+    Snapshot::from_lines(&contents)
+}
@@ -0,0 +1,59 @@
+/// The highest power of z the stepper goes up to.
+pub const MAX_POWER: u32 = 8;
+
+/// Steps through the powers of z = e^(iθ), which by De Moivre's theorem lie
+/// on the unit circle at multiples of θ.
+#[derive(Debug)]
+pub struct DeMoivre {
+    active: bool,
+    power: u32,
+}
+
+impl Default for DeMoivre {
+    fn default() -> Self {
+        Self { active: false, power: 1 }
+    }
+}
+
+impl DeMoivre {
+    pub fn toggle(&mut self) {
+        self.active = !self.active;
+        self.power = 1;
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// The highest power currently shown.
+    pub const fn power(&self) -> u32 {
+        self.power
+    }
+
+    pub fn step_forward(&mut self) {
+        self.power = (self.power + 1).min(MAX_POWER);
+    }
+
+    pub fn step_back(&mut self) {
+        self.power = (self.power - 1).max(1);
+    }
+}
+
+/// Formats `z` raised to `power`, such as `z³`.
+pub fn fmt_power(power: u32) -> String {
+    const SUPERSCRIPTS: [char; 10] =
+        ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+    if power == 1 {
+        return String::from("z");
+    }
+
+    std::iter::once('z')
+        .chain(
+            power
+                .to_string()
+                .bytes()
+                .map(|digit| SUPERSCRIPTS[(digit - b'0') as usize]),
+        )
+        .collect()
+}
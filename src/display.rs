@@ -0,0 +1,306 @@
+use crate::labels::Label;
+use std::collections::HashMap;
+use std::f32::consts::{FRAC_1_SQRT_2, PI, SQRT_2};
+
+/// How a value is shown in the value panel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValueFormat {
+    /// The number of decimal places.
+    pub decimals: usize,
+    /// Whether to show the exact value (such as `√3/2` or `3π/4`) when θ is
+    /// at a special angle.
+    pub exact: bool,
+}
+
+impl Default for ValueFormat {
+    fn default() -> Self {
+        Self { decimals: 2, exact: false }
+    }
+}
+
+/// Per-function value formats used by the value panel.
+#[derive(Clone, Debug)]
+pub struct DisplaySettings {
+    formats: HashMap<Label, ValueFormat>,
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        let precise = ValueFormat { decimals: 3, exact: true };
+        let coarse = ValueFormat { decimals: 2, exact: true };
+
+        let formats = [
+            (Label::Theta, coarse),
+            (Label::Sin, precise),
+            (Label::Cos, precise),
+            (Label::Tan, coarse),
+            (Label::Cot, coarse),
+            (Label::Sec, coarse),
+            (Label::Csc, coarse),
+        ]
+        .into_iter()
+        .collect();
+
+        Self { formats }
+    }
+}
+
+impl DisplaySettings {
+    pub fn format_of(&self, label: Label) -> ValueFormat {
+        self.formats.get(&label).copied().unwrap_or_default()
+    }
+}
+
+// --- *** --- //
+
+/// The 16 standard angles of the unit circle within `[0, 2π)`, in twelfths
+/// of π.
+pub const STANDARD_TWELFTHS: [u32; 16] =
+    [0, 2, 3, 4, 6, 8, 9, 10, 12, 14, 15, 16, 18, 20, 21, 22];
+
+/// Range bars for unbounded functions extend to values of this magnitude.
+pub const RANGE_BAR_MAX: f32 = 10.0;
+
+/// Where `val` sits along a range bar, within `[-1, 1]`. Bounded functions
+/// are placed linearly over `[-1, 1]`, and unbounded ones logarithmically up
+/// to ±[`RANGE_BAR_MAX`].
+pub fn range_bar_position(val: f32, is_bounded: bool) -> f32 {
+    if val.is_nan() {
+        return 0.0;
+    }
+
+    if is_bounded {
+        val.clamp(-1.0, 1.0)
+    }
+    else {
+        let magnitude = val.abs().ln_1p() / RANGE_BAR_MAX.ln_1p();
+        magnitude.min(1.0).copysign(val)
+    }
+}
+
+/// If `theta` is a multiple of π/4 or π/6, returns it as a whole number of
+/// twelfths of π within `[0, 24)`.
+pub fn special_angle_twelfths(theta: f32) -> Option<u32> {
+    let twelfths = theta / (PI / 12.0);
+    let rounded = twelfths.round();
+
+    if (twelfths - rounded).abs() > 1.0e-3 {
+        return None;
+    }
+
+    let rounded = (rounded as i32).rem_euclid(24) as u32;
+    (rounded.is_multiple_of(2) || rounded.is_multiple_of(3)).then_some(rounded)
+}
+
+/// Formats `twelfths` of π as a reduced fraction, such as `3π/4`.
+pub fn fmt_pi_fraction(twelfths: u32) -> String {
+    if twelfths == 0 {
+        return String::from("0");
+    }
+
+    let divisor = gcd(twelfths, 12);
+
+    match (twelfths / divisor, 12 / divisor) {
+        (1, 1) => String::from("π"),
+        (num, 1) => format!("{num}π"),
+        (1, den) => format!("π/{den}"),
+        (num, den) => format!("{num}π/{den}"),
+    }
+}
+
+/// Formats `twelfths` of π as a reduced fraction of a turn, such as `3/8`.
+pub fn fmt_turn_fraction(twelfths: u32) -> String {
+    if twelfths == 0 {
+        return String::from("0");
+    }
+
+    let divisor = gcd(twelfths, 24);
+
+    match (twelfths / divisor, 24 / divisor) {
+        (num, 1) => num.to_string(),
+        (num, den) => format!("{num}/{den}"),
+    }
+}
+
+/// The fraction nearest to `val` with a denominator of at most
+/// `max_denominator`, as `(numerator, denominator)` in lowest terms, if it's
+/// within `tolerance`. The numerator has the sign of `val`.
+pub fn nearest_fraction(
+    val: f32,
+    max_denominator: u32,
+    tolerance: f32,
+) -> Option<(i32, u32)> {
+    if !val.is_finite() {
+        return None;
+    }
+
+    let magnitude = val.abs();
+    (1..=max_denominator.max(1))
+        .map(|den| {
+            let num = (magnitude * den as f32).round();
+            (num as u32, den, (magnitude - num / den as f32).abs())
+        })
+        .min_by(|a, b| a.2.total_cmp(&b.2))
+        .filter(|&(.., error)| error <= tolerance)
+        // rounding can leave a multiple such as 2/4 a hair closer than 1/2
+        .map(|(num, den, _)| {
+            let divisor = gcd(num, den);
+            let num = (num / divisor) as i32;
+            (if val < 0.0 { -num } else { num }, den / divisor)
+        })
+}
+
+/// Formats `num/den` of π as an improper fraction, followed by the mixed
+/// number if it's more than one: `5π/4 = 1¼π`, or `-5π/4 = -1¼π` if it's
+/// negative.
+pub fn fmt_pi_multiple(num: i32, den: u32) -> String {
+    let sign = if num < 0 { "-" } else { "" };
+    let num = num.unsigned_abs();
+    let improper = match (num, den) {
+        (0, _) => return String::from("0"),
+        (1, 1) => format!("{sign}π"),
+        (num, 1) => format!("{sign}{num}π"),
+        (1, den) => format!("{sign}π/{den}"),
+        (num, den) => format!("{sign}{num}π/{den}"),
+    };
+
+    let (whole, rem) = (num / den, num % den);
+    if whole == 0 || rem == 0 {
+        return improper;
+    }
+
+    let fraction = vulgar_fraction(rem, den)
+        .map_or_else(|| format!(" {rem}/{den}"), String::from);
+
+    format!("{improper} = {sign}{whole}{fraction}π")
+}
+
+/// The single character for a common fraction, such as `¾`.
+fn vulgar_fraction(num: u32, den: u32) -> Option<&'static str> {
+    Some(match (num, den) {
+        (1, 2) => "½",
+        (1, 3) => "⅓",
+        (2, 3) => "⅔",
+        (1, 4) => "¼",
+        (3, 4) => "¾",
+        (1, 5) => "⅕",
+        (2, 5) => "⅖",
+        (3, 5) => "⅗",
+        (4, 5) => "⅘",
+        (1, 6) => "⅙",
+        (5, 6) => "⅚",
+        (1, 8) => "⅛",
+        (3, 8) => "⅜",
+        (5, 8) => "⅝",
+        (7, 8) => "⅞",
+        _ => return None,
+    })
+}
+
+/// The exact form of a trig value at a special angle, such as `√3/2`.
+pub fn exact_value(val: f32) -> Option<String> {
+    const EXACT: [(f32, &str); 10] = [
+        (0.0, "0"),
+        (0.5, "1/2"),
+        (FRAC_1_SQRT_2, "√2/2"),
+        (0.866_025_4, "√3/2"),
+        (1.0, "1"),
+        (0.577_350_3, "√3/3"),
+        (1.732_050_8, "√3"),
+        (SQRT_2, "√2"),
+        (2.0, "2"),
+        (1.154_700_5, "2√3/3"),
+    ];
+
+    let (_, exact) = EXACT
+        .iter()
+        .find(|(exact, _)| (val.abs() - exact).abs() < 1.0e-3)?;
+
+    Some(if val < 0.0 && *exact != "0" {
+        format!("-{exact}")
+    }
+    else {
+        String::from(*exact)
+    })
+}
+
+const fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    }
+    else {
+        gcd(b, a % b)
+    }
+}
+
+/// Formats a complex number as `a + bi` from its formatted real and
+/// imaginary parts.
+pub fn fmt_complex(re: &str, im: &str) -> String {
+    let (sign, im) = im.strip_prefix('-').map_or(('+', im), |im| ('-', im));
+
+    if im.contains('/') {
+        format!("{re} {sign} ({im})i")
+    }
+    else {
+        format!("{re} {sign} {im}i")
+    }
+}
+
+/// θ and the value of each function at `theta`, exact at special angles,
+/// for exports which aren't tied to the current display settings.
+pub fn value_rows_at(theta: f32) -> Vec<(String, String)> {
+    let twelfths = special_angle_twelfths(theta);
+    let fmt = |val: f32| {
+        if val.abs() > 1.0e6 {
+            String::from("undefined")
+        }
+        else {
+            exact_value(val)
+                .filter(|_| twelfths.is_some())
+                .unwrap_or_else(|| format!("{val:.3}"))
+        }
+    };
+
+    let (sin, cos) = theta.sin_cos();
+    let radians =
+        twelfths.map_or_else(|| format!("{theta:.3}"), fmt_pi_fraction);
+
+    vec![
+        (String::from("θ"), format!("{radians} ({:.0}º)", theta.to_degrees())),
+        (String::from("sin θ"), fmt(sin)),
+        (String::from("cos θ"), fmt(cos)),
+        (String::from("tan θ"), fmt(sin / cos)),
+        (String::from("cot θ"), fmt(cos / sin)),
+        (String::from("sec θ"), fmt(cos.recip())),
+        (String::from("csc θ"), fmt(sin.recip())),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_fraction_is_in_lowest_terms() {
+        assert_eq!(nearest_fraction(0.5, 12, 1.0e-4), Some((1, 2)));
+        assert_eq!(nearest_fraction(1.25, 12, 1.0e-4), Some((5, 4)));
+        assert_eq!(nearest_fraction(2.0 / 3.0, 12, 1.0e-4), Some((2, 3)));
+        assert_eq!(nearest_fraction(2.0, 12, 1.0e-4), Some((2, 1)));
+        assert_eq!(nearest_fraction(0.0, 12, 1.0e-4), Some((0, 1)));
+    }
+
+    #[test]
+    fn nearest_fraction_keeps_the_sign() {
+        assert_eq!(nearest_fraction(-0.5, 12, 1.0e-4), Some((-1, 2)));
+        assert_eq!(nearest_fraction(-1.25, 12, 1.0e-4), Some((-5, 4)));
+        assert_eq!(fmt_pi_multiple(-1, 2), "-π/2");
+        assert_eq!(fmt_pi_multiple(-5, 4), "-5π/4 = -1¼π");
+    }
+
+    #[test]
+    fn nearest_fraction_rejects_distant_and_invalid_values() {
+        assert_eq!(nearest_fraction(0.1234, 4, 1.0e-3), None);
+        assert_eq!(nearest_fraction(f32::NAN, 12, 1.0e-4), None);
+        assert_eq!(nearest_fraction(f32::INFINITY, 12, 1.0e-4), None);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::labels::Label;
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+/// The number of steps the circle is divided into when finding regions.
+const RESOLUTION: u32 = 720;
+
+/// Shades the parts of the circle where a function is negative, greater than
+/// 1 in magnitude, or undefined.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DomainShading {
+    function: Option<Label>,
+}
+
+impl DomainShading {
+    /// Cycles through each function, then off.
+    pub fn cycle(&mut self) {
+        self.function = match self.function {
+            None => Some(Label::Sin),
+            Some(Label::Sin) => Some(Label::Cos),
+            Some(Label::Cos) => Some(Label::Tan),
+            Some(Label::Tan) => Some(Label::Cot),
+            Some(Label::Cot) => Some(Label::Sec),
+            Some(Label::Sec) => Some(Label::Csc),
+            Some(_) => None,
+        }
+    }
+
+    /// The function being shaded, if any.
+    pub const fn function(self) -> Option<Label> {
+        self.function
+    }
+}
+
+/// Evaluates `function` at `theta`. `Theta` and `Unit` aren't functions, so
+/// return `theta` and `1` respectively.
+pub fn evaluate(function: Label, theta: f32) -> f32 {
+    let (sin, cos) = theta.sin_cos();
+
+    match function {
+        Label::Sin => sin,
+        Label::Cos => cos,
+        Label::Tan => sin / cos,
+        Label::Cot => cos / sin,
+        Label::Sec => cos.recip(),
+        Label::Csc => sin.recip(),
+        Label::Theta => theta,
+        Label::Unit => 1.0,
+    }
+}
+
+/// The angles within `[0, 2π)` at which `function` is undefined.
+pub const fn undefined_angles(function: Label) -> &'static [f32] {
+    match function {
+        Label::Tan | Label::Sec => &[FRAC_PI_2, 3.0 * FRAC_PI_2],
+        Label::Cot | Label::Csc => &[0.0, PI],
+        _ => &[],
+    }
+}
+
+/// The ranges of angles within `[0, 2π)` for which `predicate` holds for
+/// `function`, as `(start, end)` pairs.
+pub fn regions(
+    function: Label,
+    predicate: impl Fn(f32) -> bool,
+) -> Vec<(f32, f32)> {
+    let step = TAU / RESOLUTION as f32;
+    let mut regions = Vec::new();
+    let mut start = None;
+
+    for i in 0..=RESOLUTION {
+        let angle = i as f32 * step;
+        // each step is tested at its midpoint, so asymptotes are never hit
+        let holds =
+            i < RESOLUTION && predicate(evaluate(function, angle + step * 0.5));
+
+        match (holds, start) {
+            (true, None) => start = Some(angle),
+            (false, Some(from)) => {
+                regions.push((from, angle));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    regions
+}
@@ -0,0 +1,151 @@
+use std::f32::consts::PI;
+
+/// The most circles which can be chained.
+const MAX_CIRCLES: usize = 32;
+
+/// The waveform the circles' frequencies and amplitudes are taken from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Waveform {
+    #[default]
+    Square,
+    Sawtooth,
+    Triangle,
+    /// The circles listed by `epicycles` in the state file.
+    Custom,
+}
+
+impl Waveform {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Square => "square",
+            Self::Sawtooth => "sawtooth",
+            Self::Triangle => "triangle",
+            Self::Custom => "custom",
+        }
+    }
+}
+
+/// A chain of rotating circles whose end traces a periodic waveform, each
+/// circle turning at a whole multiple of θ.
+#[derive(Debug)]
+pub struct Epicycles {
+    waveform: Waveform,
+    /// The number of circles chained for the preset waveforms.
+    count: usize,
+    /// The frequency and amplitude of each circle of the custom waveform.
+    custom: Vec<(f32, f32)>,
+}
+
+impl Epicycles {
+    /// Creates the epicycles, with `custom` as the frequency and amplitude of
+    /// each circle of the custom waveform, which is skipped if it's empty.
+    pub const fn new(custom: Vec<(f32, f32)>) -> Self {
+        Self { waveform: Waveform::Square, count: 4, custom }
+    }
+
+    pub const fn waveform(&self) -> Waveform {
+        self.waveform
+    }
+
+    pub fn cycle_waveform(&mut self) {
+        self.waveform = match self.waveform {
+            Waveform::Square => Waveform::Sawtooth,
+            Waveform::Sawtooth => Waveform::Triangle,
+            Waveform::Triangle if !self.custom.is_empty() => Waveform::Custom,
+            Waveform::Triangle | Waveform::Custom => Waveform::Square,
+        }
+    }
+
+    /// Adds a circle to the chain, or removes one if `delta` is negative.
+    /// The custom waveform always has the circles it was given.
+    pub fn change_count(&mut self, delta: isize) {
+        self.count = self
+            .count
+            .saturating_add_signed(delta)
+            .clamp(1, MAX_CIRCLES);
+    }
+
+    /// The frequency (as a multiple of θ) and amplitude of each circle, from
+    /// the largest outwards. Negative amplitudes start the circle half a turn
+    /// round.
+    pub fn circles(&self) -> Vec<(f32, f32)> {
+        let n = |i: usize| (i + 1) as f32;
+        let odd = |i: usize| (2 * i + 1) as f32;
+
+        match self.waveform {
+            Waveform::Square => (0..self.count)
+                .map(|i| (odd(i), 4.0 / (odd(i) * PI)))
+                .collect(),
+            Waveform::Sawtooth => (0..self.count)
+                .map(|i| {
+                    let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+                    (n(i), sign * 2.0 / (n(i) * PI))
+                })
+                .collect(),
+            Waveform::Triangle => (0..self.count)
+                .map(|i| {
+                    let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+                    (odd(i), sign * 8.0 / (PI * PI * odd(i) * odd(i)))
+                })
+                .collect(),
+            Waveform::Custom => self.custom.clone(),
+        }
+    }
+
+    /// The height of the chain's end at `theta`, the sum of every circle's
+    /// sine.
+    pub fn value_at(circles: &[(f32, f32)], theta: f32) -> f32 {
+        circles
+            .iter()
+            .map(|&(freq, amp)| amp * (freq * theta).sin())
+            .sum()
+    }
+}
+
+/// Parses a list of circles such as `1:1, 3:0.33`, as frequency and
+/// amplitude pairs, skipping any which can't be read or aren't finite.
+pub fn parse_circles(list: &str) -> Vec<(f32, f32)> {
+    let parse = |text: &str| {
+        text.trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|val| val.is_finite())
+    };
+
+    list.split(',')
+        .filter_map(|pair| {
+            let (freq, amp) = pair.split_once(':')?;
+            Some((parse(freq)?, parse(amp)?))
+        })
+        .take(MAX_CIRCLES)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_circles_skips_malformed_pairs() {
+        assert_eq!(
+            parse_circles("1:1, 3: 0.33, 5, x:1, 7:y"),
+            vec![(1.0, 1.0), (3.0, 0.33)]
+        );
+        assert!(parse_circles("").is_empty());
+    }
+
+    #[test]
+    fn parse_circles_skips_non_finite_values() {
+        assert_eq!(
+            parse_circles("nan:1, 1:inf, 2:-inf, 3:0.5"),
+            vec![(3.0, 0.5)]
+        );
+    }
+
+    #[test]
+    fn parse_circles_keeps_at_most_max_circles() {
+        let list = vec!["1:1"; MAX_CIRCLES + 8].join(",");
+
+        assert_eq!(parse_circles(&list).len(), MAX_CIRCLES);
+    }
+}
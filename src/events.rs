@@ -0,0 +1,168 @@
+use crate::display::STANDARD_TWELFTHS;
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+/// Something notable that happened to θ during a frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// θ moved into a different quadrant, numbered 1 to 4.
+    QuadrantChanged { from: u8, to: u8 },
+    /// θ passed 0 while moving forward, completing the `count`th revolution
+    /// since the app started.
+    RevolutionCompleted { count: u32 },
+    /// θ passed one of the standard angles while moving forward, given in
+    /// twelfths of π.
+    SpecialAngleHit { twelfths: u32 },
+    /// θ passed the countdown's target while moving forward.
+    CountdownReached,
+}
+
+impl Event {
+    /// A short name for the event, as seen by scripts.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::QuadrantChanged { .. } => "quadrant_changed",
+            Self::RevolutionCompleted { .. } => "revolution_completed",
+            Self::SpecialAngleHit { .. } => "special_angle_hit",
+            Self::CountdownReached => "countdown_reached",
+        }
+    }
+}
+
+/// The quadrant `theta` (within `[0, 2π)`) lies in, numbered 1 to 4.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn quadrant(theta: f32) -> u8 {
+    (theta / FRAC_PI_2).floor().clamp(0.0, 3.0) as u8 + 1
+}
+
+/// Watches θ from frame to frame and reports the events between them.
+#[derive(Debug, Default)]
+pub struct Events {
+    last_theta: Option<f32>,
+    revolutions: u32,
+    current: Vec<Event>,
+}
+
+impl Events {
+    /// Finds the events between the last frame's θ and `theta`, including
+    /// passing the countdown's `target`, if there is one.
+    ///
+    /// θ is taken to have moved the shorter way around, so if `is_moving` and
+    /// it moved forward, every angle passed along the way is reported. If θ
+    /// jumped, or moved backward, only a change of quadrant is reported.
+    pub fn update(&mut self, theta: f32, is_moving: bool, target: Option<f32>) {
+        self.current.clear();
+
+        let Some(last) = self.last_theta.replace(theta)
+        else {
+            return;
+        };
+        if last == theta {
+            return;
+        }
+
+        // in [-π, π), so a step back isn't taken as most of a turn forward
+        let delta = (theta - last + PI).rem_euclid(TAU) - PI;
+
+        if is_moving && delta > 0.0 {
+            let end = last + delta;
+            let passes = |angle: f32| {
+                let angle = if angle <= last { angle + TAU } else { angle };
+                angle <= end
+            };
+
+            for twelfths in STANDARD_TWELFTHS {
+                if !passes(twelfths as f32 * PI / 12.0) {
+                    continue;
+                }
+
+                if twelfths == 0 {
+                    self.revolutions += 1;
+                    self.current.push(Event::RevolutionCompleted {
+                        count: self.revolutions,
+                    });
+                }
+                self.current.push(Event::SpecialAngleHit { twelfths });
+            }
+
+            if target.is_some_and(passes) {
+                self.current.push(Event::CountdownReached);
+            }
+        }
+
+        let (from, to) = (quadrant(last), quadrant(theta));
+        if from != to {
+            self.current.push(Event::QuadrantChanged { from, to });
+        }
+    }
+
+    /// The events which happened this frame.
+    pub fn current(&self) -> &[Event] {
+        &self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events_between(from: f32, to: f32, is_moving: bool) -> Vec<Event> {
+        let mut events = Events::default();
+        events.update(from, is_moving, None);
+        events.update(to, is_moving, None);
+        events.current().to_vec()
+    }
+
+    #[test]
+    fn moving_forward_hits_the_angles_passed() {
+        assert_eq!(
+            events_between(0.1, 1.1, true),
+            [
+                Event::SpecialAngleHit { twelfths: 2 },
+                Event::SpecialAngleHit { twelfths: 3 },
+                Event::SpecialAngleHit { twelfths: 4 },
+            ]
+        );
+    }
+
+    #[test]
+    fn passing_zero_completes_a_revolution() {
+        let mut events = Events::default();
+        events.update(TAU - 0.1, true, None);
+
+        for count in 1..=2 {
+            events.update(0.05, true, None);
+            assert!(events
+                .current()
+                .contains(&Event::RevolutionCompleted { count }));
+            events.update(TAU - 0.1, false, None);
+        }
+    }
+
+    #[test]
+    fn stepping_back_hits_nothing() {
+        assert_eq!(events_between(1.1, 0.1, true), []);
+        assert_eq!(
+            events_between(0.1, TAU - 0.1, true),
+            [Event::QuadrantChanged { from: 1, to: 4 }]
+        );
+    }
+
+    #[test]
+    fn jumps_only_change_quadrant() {
+        assert_eq!(
+            events_between(0.1, PI * 0.75, false),
+            [Event::QuadrantChanged { from: 1, to: 2 }]
+        );
+    }
+
+    #[test]
+    fn the_countdown_target_is_reached_once() {
+        let mut events = Events::default();
+        events.update(3.0, true, Some(PI));
+        events.update(3.2, true, Some(PI));
+        assert!(events.current().contains(&Event::CountdownReached));
+
+        events.update(3.4, true, Some(PI));
+        assert!(!events.current().contains(&Event::CountdownReached));
+    }
+}
@@ -0,0 +1,571 @@
+use crate::consts::*;
+use crate::poster::PosterAngle;
+use crate::watermark::Watermark;
+use nannou::image;
+use nannou::prelude::{vec2, Rgb, Vec2};
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use trig_visuals::{Function, TrigValues, View};
+
+/// The directory exported files are written to, relative to the working
+/// directory.
+pub const EXPORT_DIR: &str = "exports";
+
+/// Returns a path in the export directory named `{stem}_{timestamp}.{ext}`,
+/// creating the directory if needed.
+pub fn export_path(stem: &str, ext: &str) -> io::Result<PathBuf> {
+    fs::create_dir_all(EXPORT_DIR)?;
+
+    Ok(Path::new(EXPORT_DIR).join(format!("{stem}_{}.{ext}", timestamp())))
+}
+
+/// Creates and returns a directory in the export directory named
+/// `{stem}_{timestamp}`.
+pub fn export_dir(stem: &str) -> io::Result<PathBuf> {
+    let dir = Path::new(EXPORT_DIR).join(format!("{stem}_{}", timestamp()));
+    fs::create_dir_all(&dir)?;
+
+    Ok(dir)
+}
+
+fn timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+// --- *** --- //
+
+/// Writes `rows` as a single-page, large-print A4 PDF.
+pub fn write_large_print_pdf(
+    path: &Path,
+    title: &str,
+    rows: &[(String, String)],
+    watermark: &Watermark,
+) -> io::Result<()> {
+    const FONT_SIZE: f32 = 28.0;
+    const LEADING: f32 = 44.0;
+    const TOP: f32 = 770.0;
+
+    let mut content = String::new();
+    pdf_text(&mut content, title, FONT_SIZE, [56.0, TOP]);
+    for (i, (name, value)) in rows.iter().enumerate() {
+        pdf_text(
+            &mut content,
+            &format!("{name} = {value}"),
+            FONT_SIZE,
+            // leaving a blank line under the title
+            [56.0, TOP - (i + 2) as f32 * LEADING],
+        );
+    }
+
+    write_pdf(path, [595, 842], &content, watermark)
+}
+
+/// Writes an A3 poster of the unit circle with `angles` labelled around the
+/// rim.
+pub fn write_poster_pdf(
+    path: &Path,
+    title: &str,
+    angles: &[PosterAngle],
+    watermark: &Watermark,
+) -> io::Result<()> {
+    const CENTER: [f32; 2] = [421.0, 560.0];
+    const RADIUS: f32 = 250.0;
+    const LABEL_RADIUS: f32 = RADIUS + 95.0;
+
+    let [cx, cy] = CENTER;
+    let mut content = String::new();
+
+    pdf_centered_text(&mut content, title, 32.0, [cx, 1100.0]);
+
+    // axes
+    let reach = LABEL_RADIUS - 40.0;
+    _ = writeln!(
+        content,
+        "0.6 G 0.75 w {} {cy} m {} {cy} l S {cx} {} m {cx} {} l S",
+        cx - reach,
+        cx + reach,
+        cy - reach,
+        cy + reach
+    );
+
+    for angle in angles {
+        let (sin, cos) = angle.theta.sin_cos();
+        let rim = [cx + cos * RADIUS, cy + sin * RADIUS];
+
+        _ = writeln!(
+            content,
+            "0.8 G 0.5 w {cx} {cy} m {:.1} {:.1} l S",
+            rim[0], rim[1]
+        );
+        content.push_str("0 g\n");
+        pdf_circle(&mut content, rim, 4.0);
+        content.push_str("f\n");
+
+        let [x, y] = [cx + cos * LABEL_RADIUS, cy + sin * LABEL_RADIUS];
+        pdf_centered_text(&mut content, &angle.degrees, 12.0, [x, y + 12.0]);
+        pdf_centered_text(&mut content, &angle.radians, 12.0, [x, y - 2.0]);
+        pdf_centered_text(&mut content, &angle.coords, 11.0, [x, y - 16.0]);
+    }
+
+    content.push_str("0 G 2 w\n");
+    pdf_circle(&mut content, CENTER, RADIUS);
+    content.push_str("S\n");
+
+    write_pdf(path, [842, 1191], &content, watermark)
+}
+
+/// The colour scheme of a rendered figure.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FigureProfile {
+    /// Dark lines on white, for printing.
+    #[default]
+    Print,
+    /// The app's own colours on black.
+    Screen,
+}
+
+/// Writes a figure of the unit circle and its trig lines at `theta`, with
+/// `rows` listed in the corner.
+pub fn write_figure_pdf(
+    path: &Path,
+    theta: f32,
+    rows: &[(String, String)],
+    profile: FigureProfile,
+    watermark: &Watermark,
+) -> io::Result<()> {
+    const SIZE: f32 = 500.0;
+    const CENTER: [f32; 2] = [SIZE * 0.5, SIZE * 0.45];
+    const RADIUS: f32 = 130.0;
+    // keeps lines for very large values from producing huge coordinates
+    const LIMIT: f32 = 10.0;
+
+    let is_print = profile == FigureProfile::Print;
+    let fg = if is_print { 0.0 } else { 1.0 };
+    // bright colours such as yellow are darkened to show up on paper
+    let stroke = |content: &mut String, color: Rgb| {
+        let k = if is_print { 0.75 } else { 1.0 };
+        _ = writeln!(
+            content,
+            "{:.2} {:.2} {:.2} RG {:.2} {:.2} {:.2} rg",
+            color.red * k,
+            color.green * k,
+            color.blue * k,
+            color.red * k,
+            color.green * k,
+            color.blue * k
+        );
+    };
+
+    let [cx, cy] = CENTER;
+    let view = View::new(vec2(cx, cy), RADIUS);
+    let at = |x: f32, y: f32| {
+        let pos = view.to_pixels(
+            vec2(x, y).clamp(Vec2::splat(-LIMIT), Vec2::splat(LIMIT)),
+        );
+        [pos.x, pos.y]
+    };
+    let line = |content: &mut String, from: [f32; 2], to: [f32; 2]| {
+        _ = writeln!(
+            content,
+            "{:.1} {:.1} m {:.1} {:.1} l S",
+            from[0], from[1], to[0], to[1]
+        );
+    };
+
+    let mut content = String::new();
+    if !is_print {
+        _ = writeln!(content, "0 g 0 0 {SIZE} {SIZE} re f");
+    }
+
+    // axes and circle
+    _ = writeln!(content, "0.5 G 0.75 w");
+    line(&mut content, [0.0, cy], [SIZE, cy]);
+    line(&mut content, [cx, 0.0], [cx, SIZE]);
+    _ = writeln!(content, "{fg} G 1.5 w");
+    pdf_circle(&mut content, CENTER, RADIUS);
+    content.push_str("S\n");
+
+    let values = TrigValues::new(theta);
+    let (sin, cos) = (values.sin, values.cos);
+
+    content.push_str("2 w\n");
+    for function in Function::ALL {
+        let (from, to) = values.line(function);
+
+        stroke(&mut content, function.color());
+        line(&mut content, at(from.x, from.y), at(to.x, to.y));
+    }
+
+    // the point and the radius to it
+    _ = writeln!(content, "{fg} G {fg} g 1 w");
+    line(&mut content, at(0.0, 0.0), at(cos, sin));
+    pdf_circle(&mut content, at(cos, sin), 4.0);
+    content.push_str("f\n");
+
+    _ = writeln!(content, "{fg} g");
+    for (i, (name, value)) in rows.iter().enumerate() {
+        pdf_text(
+            &mut content,
+            &format!("{name} = {value}"),
+            12.0,
+            [20.0, SIZE - 25.0 - i as f32 * 16.0],
+        );
+    }
+
+    write_pdf(path, [SIZE as u32, SIZE as u32], &content, watermark)
+}
+
+/// Appends a circle path (without painting it) to `content`.
+fn pdf_circle(content: &mut String, center: [f32; 2], radius: f32) {
+    // control point distance for approximating a quarter circle
+    let k = 0.552_285 * radius;
+    let r = radius;
+    let [x, y] = center;
+
+    _ = writeln!(content, "{:.1} {y:.1} m", x + r);
+    for [(x1, y1), (x2, y2), (x3, y3)] in [
+        [(r, k), (k, r), (0.0, r)],
+        [(-k, r), (-r, k), (-r, 0.0)],
+        [(-r, -k), (-k, -r), (0.0, -r)],
+        [(k, -r), (r, -k), (r, 0.0)],
+    ] {
+        _ = writeln!(
+            content,
+            "{:.1} {:.1} {:.1} {:.1} {:.1} {:.1} c",
+            x + x1,
+            y + y1,
+            x + x2,
+            y + y2,
+            x + x3,
+            y + y3
+        );
+    }
+}
+
+/// Appends `text` centred horizontally on `pos` to `content`.
+fn pdf_centered_text(
+    content: &mut String,
+    text: &str,
+    size: f32,
+    pos: [f32; 2],
+) {
+    // rough average glyph width of Helvetica, as the font metrics aren't
+    // available here
+    let width = text.chars().count() as f32 * size * 0.5;
+    pdf_text(content, text, size, [pos[0] - width / 2.0, pos[1]]);
+}
+
+/// Appends `text` starting at `pos` to `content`, using the Symbol font for
+/// θ, π and √.
+fn pdf_text(content: &mut String, text: &str, size: f32, pos: [f32; 2]) {
+    _ = write!(content, "BT 1 0 0 1 {:.1} {:.1} Tm", pos[0], pos[1]);
+
+    let mut runs: Vec<(bool, String)> = Vec::new();
+    for ch in text.chars() {
+        let (symbol, escaped) = match ch {
+            'θ' => (true, String::from("q")),
+            'π' => (true, String::from("p")),
+            '√' => (true, String::from("\\326")),
+            _ => (false, pdf_string(ch.encode_utf8(&mut [0; 4]))),
+        };
+
+        match runs.last_mut() {
+            Some((is_symbol, run)) if *is_symbol == symbol => {
+                run.push_str(&escaped);
+            }
+            _ => runs.push((symbol, escaped)),
+        }
+    }
+
+    for (symbol, run) in runs {
+        let font = if symbol { "/F2" } else { "/F1" };
+        _ = write!(content, " {font} {size} Tf ({run}) Tj");
+    }
+    content.push_str(" ET\n");
+}
+
+/// Writes a single-page PDF with the given page size in points and content
+/// stream. Helvetica is available as `/F1` and Symbol as `/F2`. The
+/// watermark, if one is set, is drawn over the content.
+fn write_pdf(
+    path: &Path,
+    size: [u32; 2],
+    content: &str,
+    watermark: &Watermark,
+) -> io::Result<()> {
+    let [width, height] = size;
+    let mut content = content.to_string();
+    let mut objects: Vec<Vec<u8>> = Vec::new();
+    let mut resources = String::from("/Font << /F1 5 0 R /F2 6 0 R >>");
+
+    if !watermark.is_empty() {
+        // the graphics state and image are objects 7 to 9, after the fonts
+        let image = watermark.image.as_ref().and_then(|path| {
+            pdf_image(path)
+                .map_err(|e| eprintln!("failed to load watermark image: {e}"))
+                .ok()
+        });
+        let image_size = image.as_ref().map_or([0.0, 0.0], |image| {
+            let scale = WATERMARK_PDF_HEIGHT / image.height as f32;
+            [image.width as f32 * scale, WATERMARK_PDF_HEIGHT]
+        });
+        let font_size = 10.0;
+        let place = watermark.place(
+            [[0.0, 0.0], [width as f32, height as f32]],
+            image_size,
+            font_size,
+        );
+
+        content.push_str("q /GS1 gs\n");
+        if let Some(image) = image {
+            let [x, y] = place.image;
+            let [w, h] = image_size;
+            _ = writeln!(
+                content,
+                "q {w:.1} 0 0 {h:.1} {:.1} {:.1} cm /Im1 Do Q",
+                x - w / 2.0,
+                y - h / 2.0
+            );
+
+            objects.extend(image.objects());
+            resources.push_str(" /XObject << /Im1 8 0 R >>");
+        }
+        if let Some(text) = &watermark.text {
+            let [x, y] = place.text;
+            content.push_str("0.3 g\n");
+            pdf_centered_text(
+                &mut content,
+                text,
+                font_size,
+                [x, y - font_size * 0.35],
+            );
+        }
+        content.push_str("Q\n");
+
+        objects.insert(
+            0,
+            format!(
+                "<< /Type /ExtGState /CA {0} /ca {0} >>",
+                watermark.opacity
+            )
+            .into_bytes(),
+        );
+        resources.push_str(" /ExtGState << /GS1 7 0 R >>");
+    }
+
+    let mut pdf_objects: Vec<Vec<u8>> = [
+        String::from("<< /Type /Catalog /Pages 2 0 R >>"),
+        String::from("<< /Type /Pages /Kids [3 0 R] /Count 1 >>"),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {width} {height}] \
+             /Contents 4 0 R /Resources << {resources} >> >>",
+        ),
+        format!("<< /Length {} >>\nstream\n{content}endstream", content.len()),
+        String::from(
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica \
+             /Encoding /WinAnsiEncoding >>",
+        ),
+        String::from("<< /Type /Font /Subtype /Type1 /BaseFont /Symbol >>"),
+    ]
+    .into_iter()
+    .map(String::into_bytes)
+    .collect();
+    pdf_objects.extend(objects);
+
+    let mut pdf: Vec<u8> = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::with_capacity(pdf_objects.len());
+
+    for (i, object) in pdf_objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend(format!("{} 0 obj\n", i + 1).bytes());
+        pdf.extend(object);
+        pdf.extend(b"\nendobj\n");
+    }
+
+    let xref_offset = pdf.len();
+    let mut trailer =
+        format!("xref\n0 {}\n0000000000 65535 f \n", pdf_objects.len() + 1);
+    for offset in offsets {
+        _ = writeln!(trailer, "{offset:010} 00000 n ");
+    }
+    _ = write!(
+        trailer,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+        pdf_objects.len() + 1
+    );
+    pdf.extend(trailer.bytes());
+
+    fs::write(path, pdf)
+}
+
+/// An image decoded for embedding in a PDF.
+struct PdfImage {
+    width: u32,
+    height: u32,
+    rgb: Vec<u8>,
+    alpha: Vec<u8>,
+}
+
+impl PdfImage {
+    /// The image and its alpha mask as PDF objects, which must be numbered 8
+    /// and 9.
+    fn objects(self) -> [Vec<u8>; 2] {
+        let Self { width, height, rgb, alpha } = self;
+        let stream = |extra: &str, color_space: &str, data: Vec<u8>| {
+            let mut object = format!(
+                "<< /Type /XObject /Subtype /Image /Width {width} \
+                 /Height {height} /ColorSpace {color_space} \
+                 /BitsPerComponent 8{extra} /Length {} >>\nstream\n",
+                data.len()
+            )
+            .into_bytes();
+            object.extend(data);
+            object.extend(b"\nendstream");
+            object
+        };
+
+        [
+            stream(" /SMask 9 0 R", "/DeviceRGB", rgb),
+            stream("", "/DeviceGray", alpha),
+        ]
+    }
+}
+
+/// Loads the image at `path` for embedding in a PDF.
+fn pdf_image(path: &Path) -> Result<PdfImage, image::ImageError> {
+    let image = image::open(path)?.to_rgba8();
+    let (width, height) = image.dimensions();
+    let mut rgb = Vec::with_capacity((width * height * 3) as usize);
+    let mut alpha = Vec::with_capacity((width * height) as usize);
+
+    for pixel in image.pixels() {
+        rgb.extend(&pixel.0[..3]);
+        alpha.push(pixel.0[3]);
+    }
+
+    Ok(PdfImage { width, height, rgb, alpha })
+}
+
+/// Escapes `text` for a PDF string literal, replacing characters which the
+/// standard fonts can't show.
+fn pdf_string(text: &str) -> String {
+    let mut out = String::new();
+
+    for ch in text.chars() {
+        match ch {
+            '(' | ')' | '\\' => {
+                out.push('\\');
+                out.push(ch);
+            }
+            'θ' => out.push_str("theta"),
+            '\u{2009}' => out.push(' '),
+            'π' => out.push_str("pi"),
+            // WinAnsi code for the masculine ordinal, used as a degree sign
+            'º' => out.push_str("\\272"),
+            _ if ch.is_ascii() => out.push(ch),
+            _ => out.push('?'),
+        }
+    }
+
+    out
+}
+
+// --- *** --- //
+
+/// Writes `rows` as uncontracted Braille ASCII, wrapped to the standard
+/// 40-cell line of a BRF file.
+pub fn write_brf(
+    path: &Path,
+    title: &str,
+    rows: &[(String, String)],
+) -> io::Result<()> {
+    const LINE_CELLS: usize = 40;
+
+    let mut brf = String::new();
+
+    let lines = std::iter::once(String::from(title))
+        .chain(rows.iter().map(|(name, value)| format!("{name} = {value}")));
+
+    for line in lines {
+        let cells = braille_ascii(&line);
+        let cells: Vec<char> = cells.chars().collect();
+
+        for chunk in cells.chunks(LINE_CELLS) {
+            brf.extend(chunk);
+            brf.push_str("\r\n");
+        }
+    }
+
+    brf.push('\u{c}');
+    fs::write(path, brf)
+}
+
+/// Transcribes `text` to uncontracted (grade 1) UEB in North American
+/// Braille ASCII. A root sign covers the number after it. Unsupported
+/// characters are dropped.
+fn braille_ascii(text: &str) -> String {
+    // the radical sign and its termination indicator
+    const RADICAL: char = '%';
+    const END_RADICAL: char = '+';
+
+    let mut out = String::new();
+    let mut in_number = false;
+    let mut in_radical = false;
+
+    for ch in text.chars() {
+        let is_numeric = ch.is_ascii_digit()
+            || (in_number && matches!(ch, '.' | ',' | '\u{2009}'));
+
+        if in_radical && in_number && !is_numeric {
+            out.push(END_RADICAL);
+            in_radical = false;
+        }
+
+        if ch.is_ascii_digit() && !in_number {
+            out.push('#');
+        }
+        // letters a-j directly after a number would read as digits
+        else if in_number && matches!(ch, 'a'..='j') {
+            out.push(';');
+        }
+        in_number = is_numeric;
+
+        match ch {
+            '1'..='9' => out.push((b'A' + (ch as u8 - b'1')) as char),
+            '0' => out.push('J'),
+            'a'..='z' => out.push(ch.to_ascii_uppercase()),
+            'A'..='Z' => {
+                out.push(',');
+                out.push(ch);
+            }
+            '.' => out.push('4'),
+            ',' => out.push('1'),
+            ' ' => out.push(' '),
+            '=' => out.push_str("\"7"),
+            '+' => out.push_str("\"6"),
+            '-' => out.push_str("\"-"),
+            '/' => out.push_str("_/"),
+            '(' => out.push_str("\"<"),
+            ')' => out.push_str("\">"),
+            'º' => out.push_str("^J"),
+            'θ' => out.push_str(".?"),
+            'π' => out.push_str(".P"),
+            '√' => {
+                out.push(RADICAL);
+                in_radical = true;
+            }
+            _ => {}
+        }
+    }
+
+    if in_radical {
+        out.push(END_RADICAL);
+    }
+
+    out
+}
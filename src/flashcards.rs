@@ -0,0 +1,62 @@
+use crate::display::{fmt_pi_fraction, STANDARD_TWELFTHS};
+use std::path::{Path, PathBuf};
+
+/// One flashcard image: a standard angle with its values shown or hidden.
+#[derive(Clone, Copy, Debug)]
+pub struct Card {
+    /// The angle in twelfths of π.
+    pub twelfths: u32,
+    pub show_values: bool,
+}
+
+impl Card {
+    /// The card's file name, such as `07_3pi_4_values.png`.
+    fn file_name(self, idx: usize) -> String {
+        let angle = fmt_pi_fraction(self.twelfths)
+            .replace('π', "pi")
+            .replace('/', "_");
+        let variant = if self.show_values { "values" } else { "blank" };
+
+        format!("{idx:02}_{angle}_{variant}.png")
+    }
+}
+
+/// Renders a card for each standard angle, one per frame, with values shown
+/// and hidden.
+#[derive(Debug)]
+pub struct FlashcardExport {
+    dir: PathBuf,
+    frame: Option<usize>,
+}
+
+impl FlashcardExport {
+    pub const fn new(dir: PathBuf) -> Self {
+        Self { dir, frame: None }
+    }
+
+    /// Moves on to the next card, returning `None` once every card has been
+    /// rendered.
+    pub fn next_card(&mut self) -> Option<Card> {
+        let frame = self.frame.map_or(0, |f| f + 1);
+        self.frame = Some(frame);
+        self.current()
+    }
+
+    /// The card being rendered this frame.
+    pub fn current(&self) -> Option<Card> {
+        let frame = self.frame?;
+        let twelfths = *STANDARD_TWELFTHS.get(frame / 2)?;
+
+        Some(Card { twelfths, show_values: frame % 2 == 0 })
+    }
+
+    /// The path the current card is saved to.
+    pub fn current_path(&self) -> Option<PathBuf> {
+        let card = self.current()?;
+        Some(self.dir.join(card.file_name(self.frame? / 2)))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
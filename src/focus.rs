@@ -0,0 +1,63 @@
+/// An interactive element which can be reached from the keyboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Focusable {
+    /// A row of the value panel, by its index into the value rects.
+    ValueRow(usize),
+    HistorySlider,
+    Protractor,
+}
+
+/// Tracks which element has keyboard focus, so that the app can be used
+/// without a mouse.
+#[derive(Debug, Default)]
+pub struct Focus {
+    current: Option<Focusable>,
+}
+
+impl Focus {
+    pub const fn current(&self) -> Option<Focusable> {
+        self.current
+    }
+
+    pub fn is_focused(&self, element: Focusable) -> bool {
+        self.current == Some(element)
+    }
+
+    /// Moves focus to the element after the current one in `available`,
+    /// wrapping around to the first.
+    pub fn next(&mut self, available: &[Focusable]) {
+        self.step(available, 1);
+    }
+
+    /// Moves focus to the element before the current one in `available`,
+    /// wrapping around to the last.
+    pub fn previous(&mut self, available: &[Focusable]) {
+        self.step(available, available.len().saturating_sub(1));
+    }
+
+    /// Clears focus, returning whether anything was focused.
+    pub fn clear(&mut self) -> bool {
+        self.current.take().is_some()
+    }
+
+    /// Clears focus if the focused element is no longer in `available`.
+    pub fn retain(&mut self, available: &[Focusable]) {
+        if self.current.is_some_and(|el| !available.contains(&el)) {
+            self.current = None;
+        }
+    }
+
+    fn step(&mut self, available: &[Focusable], offset: usize) {
+        if available.is_empty() {
+            self.current = None;
+            return;
+        }
+
+        let idx = self
+            .current
+            .and_then(|el| available.iter().position(|&other| other == el))
+            .map_or(0, |idx| (idx + offset) % available.len());
+
+        self.current = Some(available[idx]);
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::VecDeque;
+
+/// The time between recorded samples in seconds.
+pub const SAMPLE_INTERVAL: f32 = 1.0 / 20.0;
+/// The maximum number of samples kept (ten minutes' worth).
+const CAPACITY: usize = 20 * 60 * 10;
+
+/// The state of the circle at one moment of the session.
+#[derive(Clone, Copy, Debug)]
+pub struct Sample {
+    /// When the sample was recorded, in seconds since the run started.
+    pub secs: f32,
+    pub theta: f32,
+    pub rate: f32,
+    pub is_running: bool,
+}
+
+/// A record of θ, rate and pauses over the session, which can be scrubbed
+/// back through.
+#[derive(Debug, Default)]
+pub struct History {
+    samples: VecDeque<Sample>,
+    /// The time since the run started, which the samples are stamped with.
+    run_secs: f32,
+    /// Every sample recorded over the session, stamped with the time since
+    /// the session started, which is kept when a run is taken or rewound.
+    session: Vec<Sample>,
+    session_secs: f32,
+    since_last_sample: f32,
+    /// The index of the sample being viewed while scrubbing.
+    scrub: Option<usize>,
+}
+
+impl History {
+    /// Records the state if enough time has passed since the last sample.
+    /// Nothing is recorded while scrubbing.
+    pub fn record(
+        &mut self,
+        delta_time: f32,
+        theta: f32,
+        rate: f32,
+        is_running: bool,
+    ) {
+        if self.scrub.is_some() {
+            return;
+        }
+
+        self.run_secs += delta_time;
+        self.session_secs += delta_time;
+        self.since_last_sample += delta_time;
+        if self.since_last_sample < SAMPLE_INTERVAL && !self.samples.is_empty()
+        {
+            return;
+        }
+
+        // the remainder is carried over so that samples are spaced evenly
+        // whatever the frame rate, though a long frame can't be made up
+        self.since_last_sample %= SAMPLE_INTERVAL;
+        if self.samples.len() == CAPACITY {
+            self.samples.pop_front();
+        }
+        let sample = Sample { secs: self.run_secs, theta, rate, is_running };
+        self.samples.push_back(sample);
+        self.session
+            .push(Sample { secs: self.session_secs, ..sample });
+    }
+
+    /// The recorded duration in seconds.
+    pub fn duration_secs(&self) -> f32 {
+        match (self.samples.front(), self.samples.back()) {
+            (Some(first), Some(last)) => last.secs - first.secs,
+            _ => 0.0,
+        }
+    }
+
+    /// Moves the scrub position to `t` (`0` being the oldest sample and `1`
+    /// the newest, in time), returning the sample there.
+    pub fn scrub_to(&mut self, t: f32) -> Option<Sample> {
+        let first = self.samples.front()?.secs;
+        let target = first + t.clamp(0.0, 1.0) * self.duration_secs();
+
+        // the sample nearest to the target time
+        let after = self
+            .samples
+            .partition_point(|sample| sample.secs < target)
+            .min(self.samples.len() - 1);
+        let idx = match after.checked_sub(1) {
+            Some(before)
+                if target - self.samples[before].secs
+                    < self.samples[after].secs - target =>
+            {
+                before
+            }
+            _ => after,
+        };
+
+        self.scrub = Some(idx);
+        self.samples.get(idx).copied()
+    }
+
+    /// Stops scrubbing, discarding everything recorded after the scrub
+    /// position so that recording continues from there.
+    pub fn end_scrub(&mut self) {
+        if let Some(idx) = self.scrub.take() {
+            self.samples.truncate(idx + 1);
+            self.run_secs = self.samples.back().map_or(0.0, |last| last.secs);
+            self.since_last_sample = 0.0;
+        }
+    }
+
+    /// Removes and returns everything recorded so far, starting a new run.
+    pub fn take_run(&mut self) -> Vec<Sample> {
+        self.scrub = None;
+        self.run_secs = 0.0;
+        self.since_last_sample = 0.0;
+        self.samples.drain(..).collect()
+    }
+
+    /// Everything recorded so far, oldest first.
+    #[cfg(test)]
+    fn samples(&self) -> impl Iterator<Item = &Sample> {
+        self.samples.iter()
+    }
+
+    /// Everything recorded over the whole session, oldest first, including
+    /// runs which were taken or rewound.
+    pub fn session(&self) -> &[Sample] {
+        &self.session
+    }
+
+    pub const fn is_scrubbing(&self) -> bool {
+        self.scrub.is_some()
+    }
+
+    /// The scrub position from `0` to `1`, in time, which is `1` when not
+    /// scrubbing.
+    pub fn position(&self) -> f32 {
+        let duration = self.duration_secs();
+
+        match (self.scrub, self.samples.front()) {
+            (Some(idx), Some(first)) if duration > 0.0 => {
+                (self.samples[idx].secs - first.secs) / duration
+            }
+            _ => 1.0,
+        }
+    }
+}
+
+// --- *** --- //
+
+/// A previous run replayed alongside the current one for comparison.
+#[derive(Debug)]
+pub struct Ghost {
+    samples: Vec<Sample>,
+    elapsed_secs: f32,
+}
+
+impl Ghost {
+    /// Creates a ghost from a recorded run, returning `None` if it's empty.
+    pub fn new(samples: Vec<Sample>) -> Option<Self> {
+        (!samples.is_empty()).then_some(Self { samples, elapsed_secs: 0.0 })
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.elapsed_secs += delta_time;
+    }
+
+    /// The playback time as a time in the recorded run.
+    fn run_secs(&self) -> f32 {
+        self.samples[0].secs + self.elapsed_secs
+    }
+
+    /// The index of the last sample recorded by the current playback time,
+    /// which stays on the last sample once the run has finished.
+    fn current_idx(&self) -> usize {
+        let secs = self.run_secs();
+
+        self.samples
+            .partition_point(|sample| sample.secs <= secs)
+            .saturating_sub(1)
+    }
+
+    pub fn current(&self) -> Sample {
+        self.samples[self.current_idx()]
+    }
+
+    /// Up to `len` samples leading up to the current one, oldest first.
+    pub fn trail(&self, len: usize) -> &[Sample] {
+        let end = self.current_idx() + 1;
+        &self.samples[end.saturating_sub(len)..end]
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.samples
+            .last()
+            .is_some_and(|last| self.run_secs() >= last.secs)
+    }
+
+    /// The θ the run started from.
+    pub fn start_theta(&self) -> f32 {
+        self.samples[0].theta
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records `secs` of frames `frame_secs` long, with θ as the frame count.
+    fn record_frames(history: &mut History, secs: f32, frame_secs: f32) {
+        for frame in 0..(secs / frame_secs).round() as u32 {
+            history.record(frame_secs, frame as f32, 1.0, true);
+        }
+    }
+
+    #[test]
+    fn record_spaces_samples_by_the_interval_at_any_frame_rate() {
+        for fps in [30.0, 60.0, 144.0] {
+            let mut history = History::default();
+            record_frames(&mut history, 10.0, 1.0 / fps);
+
+            let count = history.samples().count();
+            assert!((199..=201).contains(&count), "{count} samples at {fps}");
+            assert!((history.duration_secs() - 10.0).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn scrub_to_finds_the_sample_at_a_time() {
+        let mut history = History::default();
+        record_frames(&mut history, 10.0, 1.0 / 60.0);
+
+        let sample = history.scrub_to(0.5).unwrap();
+        let first = history.samples().next().unwrap().secs;
+
+        assert!((sample.secs - first - 5.0).abs() <= SAMPLE_INTERVAL);
+        assert!((history.position() - 0.5).abs() < 0.01);
+        assert!(history.is_scrubbing());
+    }
+
+    #[test]
+    fn nothing_is_recorded_while_scrubbing() {
+        let mut history = History::default();
+        record_frames(&mut history, 1.0, 1.0 / 60.0);
+        let count = history.samples().count();
+
+        history.scrub_to(0.5);
+        record_frames(&mut history, 1.0, 1.0 / 60.0);
+
+        assert_eq!(history.samples().count(), count);
+    }
+
+    #[test]
+    fn end_scrub_truncates_and_continues_from_there() {
+        let mut history = History::default();
+        record_frames(&mut history, 10.0, 1.0 / 60.0);
+
+        let kept = history.scrub_to(0.25).unwrap();
+        history.end_scrub();
+
+        assert_eq!(history.samples().last().unwrap().secs, kept.secs);
+        assert_eq!(history.position(), 1.0);
+
+        record_frames(&mut history, 1.0, 1.0 / 60.0);
+        let secs: Vec<f32> = history.samples().map(|s| s.secs).collect();
+
+        assert!(secs.windows(2).all(|pair| pair[1] > pair[0]));
+        assert!((history.duration_secs() - 3.5).abs() < 0.1);
+    }
+
+    #[test]
+    fn take_run_starts_a_new_run() {
+        let mut history = History::default();
+        record_frames(&mut history, 1.0, 1.0 / 60.0);
+
+        let run = history.take_run();
+
+        assert!(!run.is_empty());
+        assert_eq!(history.samples().count(), 0);
+        assert_eq!(history.session().len(), run.len());
+
+        history.record(1.0 / 60.0, 0.0, 1.0, true);
+        assert!(history.samples().next().unwrap().secs < 0.1);
+    }
+
+    #[test]
+    fn the_session_keeps_rewound_samples() {
+        let mut history = History::default();
+        record_frames(&mut history, 10.0, 1.0 / 60.0);
+        let count = history.session().len();
+
+        history.scrub_to(0.25);
+        history.end_scrub();
+        record_frames(&mut history, 1.0, 1.0 / 60.0);
+
+        let session = history.session();
+        assert!(session.len() >= count + 19);
+        assert!(session.windows(2).all(|pair| pair[1].secs > pair[0].secs));
+    }
+
+    #[test]
+    fn the_ghost_keeps_to_the_recorded_times() {
+        let mut history = History::default();
+        // recorded at 30 fps, so each sample's θ is its time in thirtieths
+        record_frames(&mut history, 2.0, 1.0 / 30.0);
+        let mut ghost = Ghost::new(history.take_run()).unwrap();
+
+        for _ in 0..60 {
+            ghost.update(1.0 / 60.0);
+        }
+        let theta = ghost.current().theta;
+
+        assert!((theta / 30.0 - 1.0).abs() <= SAMPLE_INTERVAL, "{theta}");
+        assert!(!ghost.is_finished());
+
+        for _ in 0..60 {
+            ghost.update(1.0 / 60.0);
+        }
+        assert!(ghost.is_finished());
+    }
+}
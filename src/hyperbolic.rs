@@ -0,0 +1,66 @@
+use nannou::prelude::*;
+
+/// The largest hyperbolic angle shown, either side of zero.
+pub const MAX_HYPERBOLIC_ANGLE: f32 = 1.5;
+/// The size of the unit hyperbola relative to the radius of the circle, so
+/// that it fits in the same space.
+pub const HYPERBOLA_SCALE: f32 = 0.6;
+/// The distance from the sinh and tanh lines to their labels, in pixels,
+/// which is wider than for the circle to clear the curve.
+pub const HYPERBOLIC_LABEL_GAP: f32 = 30.0;
+
+/// Whether the circular functions are shown on the unit circle, the
+/// hyperbolic functions on the unit hyperbola, or a chain of epicycles.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Mode {
+    #[default]
+    Circular,
+    Hyperbolic,
+    Epicycles,
+}
+
+impl Mode {
+    /// Switches to `mode`, or back to the unit circle if it's already in
+    /// `mode`.
+    pub fn toggle(&mut self, mode: Self) {
+        *self = if *self == mode { Self::Circular } else { mode };
+    }
+}
+
+/// The hyperbolic functions at a hyperbolic angle `t`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HyperbolicValues {
+    pub t: f32,
+    pub sinh: f32,
+    pub cosh: f32,
+    pub tanh: f32,
+}
+
+impl HyperbolicValues {
+    /// The values at the hyperbolic angle θ is mapped to. As θ turns, the
+    /// angle swings back and forth between ±[`MAX_HYPERBOLIC_ANGLE`], so the
+    /// point runs up and down the hyperbola rather than off to infinity.
+    pub fn from_theta(theta: f32) -> Self {
+        let t = theta.sin() * MAX_HYPERBOLIC_ANGLE;
+
+        Self { t, sinh: t.sinh(), cosh: t.cosh(), tanh: t.tanh() }
+    }
+
+    /// The point on the unit hyperbola.
+    pub fn point(&self) -> Vec2 {
+        vec2(self.cosh, self.sinh)
+    }
+}
+
+/// Points along the right branch of the unit hyperbola `x² − y² = 1`, from
+/// hyperbolic angle `from` to `to`.
+pub fn hyperbola_points(
+    from: f32,
+    to: f32,
+    num_points: usize,
+) -> impl Iterator<Item = Vec2> {
+    (0..=num_points).map(move |i| {
+        let t = from + (to - from) * i as f32 / num_points as f32;
+        vec2(t.cosh(), t.sinh())
+    })
+}
@@ -0,0 +1,67 @@
+use nannou::prelude::*;
+
+/// The points of the classic construction proving the angle-sum identities
+/// for angles α and β, on a unit hypotenuse.
+///
+/// `p` is on the unit circle at α + β. `q` is the foot of the perpendicular
+/// from `p` to the line at α, so `oq` is cos β and `qp` is sin β. `r` and
+/// `s` are the feet of `q` and `p` on the x-axis. `t` is level with `q` and
+/// below `p`. The identities are then
+/// `sin(α + β) = rq + tp` and `cos(α + β) = or − tq`.
+#[derive(Clone, Copy, Debug)]
+pub struct Construction {
+    pub p: Vec2,
+    pub q: Vec2,
+    pub r: Vec2,
+    pub s: Vec2,
+    pub t: Vec2,
+}
+
+impl Construction {
+    pub fn new(alpha: f32, beta: f32) -> Self {
+        let (sin_sum, cos_sum) = (alpha + beta).sin_cos();
+        let (sin_a, cos_a) = alpha.sin_cos();
+
+        let p = vec2(cos_sum, sin_sum);
+        let q = vec2(cos_a, sin_a) * beta.cos();
+
+        Self {
+            p,
+            q,
+            r: vec2(q.x, 0.0),
+            s: vec2(p.x, 0.0),
+            t: vec2(p.x, q.y),
+        }
+    }
+
+    /// Each segment of the construction, with the product it's the length
+    /// of (signed, for angles beyond the first quadrant) and whether it comes
+    /// from the side `cos β` rather than `sin β`.
+    pub fn segments(&self) -> [(Vec2, Vec2, &'static str, bool); 6] {
+        [
+            (Vec2::ZERO, self.q, "cos β", true),
+            (self.q, self.p, "sin β", false),
+            (Vec2::ZERO, self.r, "cos α cos β", true),
+            (self.r, self.q, "sin α cos β", true),
+            (self.t, self.p, "cos α sin β", false),
+            (self.q, self.t, "sin α sin β", false),
+        ]
+    }
+}
+
+/// Demonstrates the angle-sum identities with the nested-triangles
+/// construction, for α = θ and β = φ from angle addition mode.
+#[derive(Debug, Default)]
+pub struct Identities {
+    active: bool,
+}
+
+impl Identities {
+    pub fn toggle(&mut self) {
+        self.active = !self.active;
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+}
@@ -0,0 +1,33 @@
+/// A developer overlay listing the model's state as it changes, rebuilt
+/// every frame, for debugging the labels' fading and what the mouse is over.
+///
+/// It's only built with the `inspector` feature, and starts shown.
+#[derive(Debug)]
+pub struct Inspector {
+    visible: bool,
+    lines: Vec<String>,
+}
+
+impl Default for Inspector {
+    fn default() -> Self {
+        Self { visible: true, lines: Vec::new() }
+    }
+}
+
+impl Inspector {
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    pub const fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn set_lines(&mut self, lines: Vec<String>) {
+        self.lines = lines;
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+}
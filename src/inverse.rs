@@ -0,0 +1,75 @@
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+/// Which inverse function's principal branch is shown on the circle.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InverseMode {
+    #[default]
+    Off,
+    Arcsin,
+    Arccos,
+    Arctan,
+}
+
+impl InverseMode {
+    pub fn cycle(&mut self) {
+        *self = match self {
+            Self::Off => Self::Arcsin,
+            Self::Arcsin => Self::Arccos,
+            Self::Arccos => Self::Arctan,
+            Self::Arctan => Self::Off,
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Off => "",
+            Self::Arcsin => "arcsin",
+            Self::Arccos => "arccos",
+            Self::Arctan => "arctan",
+        }
+    }
+
+    /// The name of the function being inverted.
+    pub const fn function_name(self) -> &'static str {
+        match self {
+            Self::Off => "",
+            Self::Arcsin => "sin",
+            Self::Arccos => "cos",
+            Self::Arctan => "tan",
+        }
+    }
+
+    /// The range of principal values, as `(start, end)` in radians.
+    pub const fn principal_range(self) -> (f32, f32) {
+        match self {
+            Self::Arccos => (0.0, PI),
+            _ => (-FRAC_PI_2, FRAC_PI_2),
+        }
+    }
+
+    /// The principal value of the inverse at the value `theta` gives, which
+    /// is what a calculator returns.
+    pub fn principal(self, theta: f32) -> f32 {
+        match self {
+            Self::Off => theta,
+            Self::Arcsin => theta.sin().asin(),
+            Self::Arccos => theta.cos().acos(),
+            Self::Arctan => theta.tan().atan(),
+        }
+    }
+
+    /// The other angle in a single revolution which gives the same value as
+    /// `principal`. Every solution is one of these two plus a multiple of 2π.
+    pub fn alternative(self, principal: f32) -> f32 {
+        let alternative = match self {
+            Self::Off => principal,
+            Self::Arcsin => PI - principal,
+            Self::Arccos => -principal,
+            Self::Arctan => principal + PI,
+        };
+
+        // keep it within a revolution of the principal range
+        let (start, _) = self.principal_range();
+        (alternative - start).rem_euclid(TAU) + start
+    }
+}
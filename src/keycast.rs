@@ -0,0 +1,53 @@
+use std::collections::VecDeque;
+
+/// How long each press is shown for, in seconds.
+const SHOW_SECS: f32 = 1.5;
+/// How long each press takes to fade out at the end, in seconds.
+const FADE_SECS: f32 = 0.4;
+/// The most presses shown at once.
+const MAX_SHOWN: usize = 4;
+
+/// The keys and clicks pressed in the last moment, shown in a corner so that
+/// viewers of a screencast can see which shortcut was used.
+#[derive(Debug, Default)]
+pub struct KeyCast {
+    active: bool,
+    /// Each press's description and how long ago it was, newest last.
+    presses: VecDeque<(String, f32)>,
+}
+
+impl KeyCast {
+    pub fn toggle(&mut self) {
+        self.active = !self.active;
+        self.presses.clear();
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn push(&mut self, press: String) {
+        if !self.active {
+            return;
+        }
+
+        if self.presses.len() == MAX_SHOWN {
+            self.presses.pop_front();
+        }
+        self.presses.push_back((press, 0.0));
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        for (_, age) in &mut self.presses {
+            *age += delta_time;
+        }
+        self.presses.retain(|&(_, age)| age < SHOW_SECS);
+    }
+
+    /// The presses being shown, newest last, with their opacities.
+    pub fn presses(&self) -> impl Iterator<Item = (&str, f32)> {
+        self.presses.iter().map(|(press, age)| {
+            (press.as_str(), ((SHOW_SECS - age) / FADE_SECS).min(1.0))
+        })
+    }
+}
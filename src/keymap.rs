@@ -0,0 +1,704 @@
+use nannou::{event::ModifiersState, prelude::*};
+use std::fs;
+
+/// The file keys can be rebound in.
+pub const KEYS_FILE: &str = "trig_visuals.keys";
+
+/// Something which can be bound to a key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    ShowWhatsNew,
+    StartTour,
+    ToggleBroadcast,
+    ToggleCalibration,
+    #[cfg(feature = "inspector")]
+    ToggleInspector,
+    ToggleStats,
+    ToggleKeyCast,
+    CycleLayout,
+    CycleMirror,
+    CycleSceneMirror,
+    TogglePanel,
+    ReloadScript,
+    Screenshot,
+    DumpScene,
+    ToggleFullscreen,
+    ToggleSnap,
+    TogglePolarGrid,
+    CyclePolarSpokes,
+    CyclePaper,
+    /// Clears the measurement if there is one, and quits otherwise (unless
+    /// presenting).
+    Quit,
+    /// Quits even in broadcast mode or fullscreen, where `Quit` doesn't.
+    QuitAlways,
+    ToggleRunning,
+    LogCsv,
+    LogJson,
+    ToggleLabelOutlines,
+    ToggleLabels,
+    ToggleRangeBars,
+    ToggleValues,
+    ToggleQuadrantArc,
+    ToggleReferenceAngle,
+    /// Measures angles from the point's current direction, or from the
+    /// x-axis again if they already are.
+    ToggleReferenceRay,
+    ToggleTheta,
+    ResetClock,
+    ToggleClock,
+    ToggleCountdownAutoPause,
+    ToggleCountdown,
+    CycleDomainShading,
+    ToggleIdentities,
+    ToggleAngleSum,
+    IncrementPhiRate,
+    DecrementPhiRate,
+    /// Steps θ forward while paused, or moves φ forward otherwise.
+    StepForward,
+    /// Steps θ back while paused, or moves φ back otherwise.
+    StepBack,
+    ToggleHyperbolic,
+    ToggleEpicycles,
+    CycleWaveform,
+    ToggleComparison,
+    /// Moves the comparison angle ψ forward.
+    ComparisonForward,
+    /// Moves the comparison angle ψ back.
+    ComparisonBack,
+    ToggleBookmark,
+    ToggleBulletTime,
+    ToggleStrobe,
+    ToggleTrace,
+    ToggleTaylor,
+    ToggleIntegral,
+    ToggleDeMoivre,
+    ToggleDerivatives,
+    CycleInverseMode,
+    ToggleComplexPlane,
+    CycleMicMode,
+    ToggleSound,
+    NextPower,
+    PreviousPower,
+    ToggleGhost,
+    ToggleHistory,
+    ToggleMeasure,
+    CycleProtractor,
+    CycleLocale,
+    CycleAngleUnit,
+    ExportAnimation,
+    ExportWorksheet,
+    ExportSessionNotes,
+    ExportReplay,
+    ExportPoster,
+    ExportFlashcards,
+    ExportValueTable,
+    DecrementCountdownTarget,
+    IncrementCountdownTarget,
+    /// Doubles the speed everything runs at.
+    IncreaseTimeScale,
+    /// Halves the speed everything runs at.
+    DecreaseTimeScale,
+    IncrementRate,
+    DecrementRate,
+    ResetTheta,
+    ToggleBefore,
+    ResetRate,
+    IncreaseScale,
+    DecreaseScale,
+    ResetScale,
+    /// Jumps θ to one of the standard angles, by its index into
+    /// `JUMP_TWELFTHS`.
+    JumpToAngle(usize),
+    ToggleChapterMenu,
+    /// Jumps to one of the script's chapters, by its index.
+    JumpToChapter(usize),
+}
+
+/// A key together with the modifiers held with it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Binding {
+    key: Key,
+    shift: bool,
+    ctrl: bool,
+    alt: bool,
+}
+
+impl Binding {
+    const fn new(key: Key, shift: bool, ctrl: bool, alt: bool) -> Self {
+        Self { key, shift, ctrl, alt }
+    }
+
+    const fn plain(key: Key) -> Self {
+        Self::new(key, false, false, false)
+    }
+
+    const fn shift(key: Key) -> Self {
+        Self::new(key, true, false, false)
+    }
+
+    const fn ctrl(key: Key) -> Self {
+        Self::new(key, false, true, false)
+    }
+
+    const fn ctrl_shift(key: Key) -> Self {
+        Self::new(key, true, true, false)
+    }
+
+    const fn alt(key: Key) -> Self {
+        Self::new(key, false, false, true)
+    }
+
+    /// Parses a binding such as `Ctrl+Shift+E`, where the key is named as in
+    /// [`KEY_NAMES`].
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut parts: Vec<&str> = text.split('+').map(str::trim).collect();
+        let key_name = parts.pop().unwrap_or_default();
+        let key = KEY_NAMES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key_name))
+            .map(|&(_, key)| key)
+            .ok_or_else(|| format!("unknown key: {key_name}"))?;
+
+        let mut binding = Self::plain(key);
+        for modifier in parts {
+            match modifier.to_ascii_lowercase().as_str() {
+                "shift" => binding.shift = true,
+                "ctrl" => binding.ctrl = true,
+                "alt" => binding.alt = true,
+                _ => return Err(format!("unknown modifier: {modifier}")),
+            }
+        }
+
+        Ok(binding)
+    }
+}
+
+/// The name of each action in the keys file, and the keys it's bound to by
+/// default.
+const DEFAULT_BINDINGS: &[(&str, Action, Binding)] = &[
+    ("show_whats_new", Action::ShowWhatsNew, Binding::shift(Key::F1)),
+    ("start_tour", Action::StartTour, Binding::plain(Key::F1)),
+    ("toggle_broadcast", Action::ToggleBroadcast, Binding::plain(Key::F2)),
+    (
+        "toggle_calibration",
+        Action::ToggleCalibration,
+        Binding::plain(Key::F6),
+    ),
+    #[cfg(feature = "inspector")]
+    ("toggle_inspector", Action::ToggleInspector, Binding::plain(Key::F7)),
+    ("toggle_stats", Action::ToggleStats, Binding::shift(Key::F3)),
+    ("toggle_key_cast", Action::ToggleKeyCast, Binding::ctrl(Key::K)),
+    ("cycle_layout", Action::CycleLayout, Binding::plain(Key::F3)),
+    ("cycle_mirror", Action::CycleMirror, Binding::ctrl(Key::F3)),
+    (
+        "cycle_scene_mirror",
+        Action::CycleSceneMirror,
+        Binding::ctrl_shift(Key::F3),
+    ),
+    ("toggle_panel", Action::TogglePanel, Binding::plain(Key::F4)),
+    ("reload_script", Action::ReloadScript, Binding::plain(Key::F5)),
+    ("screenshot", Action::Screenshot, Binding::plain(Key::F12)),
+    ("dump_scene", Action::DumpScene, Binding::ctrl(Key::F12)),
+    ("toggle_fullscreen", Action::ToggleFullscreen, Binding::plain(Key::F)),
+    ("toggle_snap", Action::ToggleSnap, Binding::ctrl(Key::G)),
+    ("toggle_polar_grid", Action::TogglePolarGrid, Binding::shift(Key::G)),
+    (
+        "cycle_polar_spokes",
+        Action::CyclePolarSpokes,
+        Binding::ctrl_shift(Key::G),
+    ),
+    ("cycle_paper", Action::CyclePaper, Binding::plain(Key::G)),
+    ("quit", Action::Quit, Binding::plain(Key::Escape)),
+    ("quit_always", Action::QuitAlways, Binding::ctrl(Key::Q)),
+    ("toggle_running", Action::ToggleRunning, Binding::plain(Key::Space)),
+    ("log_csv", Action::LogCsv, Binding::ctrl(Key::L)),
+    ("log_json", Action::LogJson, Binding::ctrl_shift(Key::L)),
+    (
+        "toggle_label_outlines",
+        Action::ToggleLabelOutlines,
+        Binding::shift(Key::L),
+    ),
+    ("toggle_labels", Action::ToggleLabels, Binding::plain(Key::L)),
+    ("toggle_range_bars", Action::ToggleRangeBars, Binding::shift(Key::V)),
+    ("toggle_values", Action::ToggleValues, Binding::plain(Key::V)),
+    (
+        "toggle_quadrant_arc",
+        Action::ToggleQuadrantArc,
+        Binding::shift(Key::T),
+    ),
+    ("toggle_theta", Action::ToggleTheta, Binding::plain(Key::T)),
+    (
+        "toggle_reference_angle",
+        Action::ToggleReferenceAngle,
+        Binding::ctrl(Key::T),
+    ),
+    (
+        "toggle_reference_ray",
+        Action::ToggleReferenceRay,
+        Binding::alt(Key::T),
+    ),
+    ("reset_clock", Action::ResetClock, Binding::shift(Key::C)),
+    ("toggle_clock", Action::ToggleClock, Binding::plain(Key::C)),
+    (
+        "toggle_countdown_auto_pause",
+        Action::ToggleCountdownAutoPause,
+        Binding::shift(Key::K),
+    ),
+    ("toggle_countdown", Action::ToggleCountdown, Binding::plain(Key::K)),
+    (
+        "cycle_domain_shading",
+        Action::CycleDomainShading,
+        Binding::plain(Key::X),
+    ),
+    ("toggle_identities", Action::ToggleIdentities, Binding::shift(Key::A)),
+    ("toggle_angle_sum", Action::ToggleAngleSum, Binding::plain(Key::A)),
+    (
+        "increment_phi_rate",
+        Action::IncrementPhiRate,
+        Binding::shift(Key::Right),
+    ),
+    (
+        "decrement_phi_rate",
+        Action::DecrementPhiRate,
+        Binding::shift(Key::Left),
+    ),
+    (
+        "comparison_forward",
+        Action::ComparisonForward,
+        Binding::ctrl(Key::Right),
+    ),
+    ("comparison_back", Action::ComparisonBack, Binding::ctrl(Key::Left)),
+    ("step_forward", Action::StepForward, Binding::plain(Key::Right)),
+    ("step_back", Action::StepBack, Binding::plain(Key::Left)),
+    ("toggle_comparison", Action::ToggleComparison, Binding::plain(Key::Q)),
+    ("cycle_waveform", Action::CycleWaveform, Binding::shift(Key::Y)),
+    ("toggle_epicycles", Action::ToggleEpicycles, Binding::plain(Key::Y)),
+    ("toggle_hyperbolic", Action::ToggleHyperbolic, Binding::plain(Key::H)),
+    ("toggle_bookmark", Action::ToggleBookmark, Binding::ctrl(Key::B)),
+    ("toggle_bullet_time", Action::ToggleBulletTime, Binding::shift(Key::B)),
+    ("toggle_strobe", Action::ToggleStrobe, Binding::plain(Key::B)),
+    ("toggle_trace", Action::ToggleTrace, Binding::plain(Key::W)),
+    ("toggle_taylor", Action::ToggleTaylor, Binding::shift(Key::W)),
+    ("toggle_integral", Action::ToggleIntegral, Binding::alt(Key::W)),
+    ("toggle_de_moivre", Action::ToggleDeMoivre, Binding::plain(Key::D)),
+    (
+        "toggle_derivatives",
+        Action::ToggleDerivatives,
+        Binding::shift(Key::D),
+    ),
+    ("cycle_inverse_mode", Action::CycleInverseMode, Binding::shift(Key::I)),
+    (
+        "toggle_complex_plane",
+        Action::ToggleComplexPlane,
+        Binding::plain(Key::I),
+    ),
+    ("cycle_mic_mode", Action::CycleMicMode, Binding::shift(Key::P)),
+    ("toggle_sound", Action::ToggleSound, Binding::plain(Key::P)),
+    ("next_power", Action::NextPower, Binding::plain(Key::Period)),
+    ("previous_power", Action::PreviousPower, Binding::plain(Key::Comma)),
+    ("toggle_ghost", Action::ToggleGhost, Binding::shift(Key::Z)),
+    ("toggle_history", Action::ToggleHistory, Binding::plain(Key::Z)),
+    ("toggle_measure", Action::ToggleMeasure, Binding::plain(Key::M)),
+    ("cycle_protractor", Action::CycleProtractor, Binding::plain(Key::O)),
+    ("cycle_locale", Action::CycleLocale, Binding::plain(Key::N)),
+    ("cycle_angle_unit", Action::CycleAngleUnit, Binding::plain(Key::U)),
+    ("export_animation", Action::ExportAnimation, Binding::ctrl(Key::R)),
+    ("export_worksheet", Action::ExportWorksheet, Binding::alt(Key::E)),
+    ("export_replay", Action::ExportReplay, Binding::alt(Key::Z)),
+    (
+        "export_session_notes",
+        Action::ExportSessionNotes,
+        Binding::ctrl_shift(Key::E),
+    ),
+    ("export_poster", Action::ExportPoster, Binding::ctrl(Key::E)),
+    ("export_flashcards", Action::ExportFlashcards, Binding::shift(Key::E)),
+    ("export_value_table", Action::ExportValueTable, Binding::plain(Key::E)),
+    (
+        "decrement_countdown_target",
+        Action::DecrementCountdownTarget,
+        Binding::plain(Key::LBracket),
+    ),
+    (
+        "increment_countdown_target",
+        Action::IncrementCountdownTarget,
+        Binding::plain(Key::RBracket),
+    ),
+    (
+        "increase_time_scale",
+        Action::IncreaseTimeScale,
+        Binding::ctrl(Key::Up),
+    ),
+    (
+        "decrease_time_scale",
+        Action::DecreaseTimeScale,
+        Binding::ctrl(Key::Down),
+    ),
+    ("increment_rate", Action::IncrementRate, Binding::plain(Key::Up)),
+    ("decrement_rate", Action::DecrementRate, Binding::plain(Key::Down)),
+    ("reset_theta", Action::ResetTheta, Binding::plain(Key::R)),
+    ("toggle_before", Action::ToggleBefore, Binding::ctrl(Key::S)),
+    ("reset_rate", Action::ResetRate, Binding::plain(Key::S)),
+    ("increase_scale", Action::IncreaseScale, Binding::plain(Key::Equals)),
+    ("decrease_scale", Action::DecreaseScale, Binding::plain(Key::Minus)),
+    ("reset_scale", Action::ResetScale, Binding::plain(Key::Key0)),
+    (
+        "jump_to_angle_1",
+        Action::JumpToAngle(0),
+        Binding::plain(Key::Numpad1),
+    ),
+    (
+        "jump_to_angle_2",
+        Action::JumpToAngle(1),
+        Binding::plain(Key::Numpad2),
+    ),
+    (
+        "jump_to_angle_3",
+        Action::JumpToAngle(2),
+        Binding::plain(Key::Numpad3),
+    ),
+    (
+        "jump_to_angle_4",
+        Action::JumpToAngle(3),
+        Binding::plain(Key::Numpad4),
+    ),
+    (
+        "jump_to_angle_5",
+        Action::JumpToAngle(4),
+        Binding::plain(Key::Numpad5),
+    ),
+    (
+        "jump_to_angle_6",
+        Action::JumpToAngle(5),
+        Binding::plain(Key::Numpad6),
+    ),
+    (
+        "jump_to_angle_7",
+        Action::JumpToAngle(6),
+        Binding::plain(Key::Numpad7),
+    ),
+    (
+        "jump_to_angle_8",
+        Action::JumpToAngle(7),
+        Binding::plain(Key::Numpad8),
+    ),
+    (
+        "jump_to_angle_9",
+        Action::JumpToAngle(8),
+        Binding::plain(Key::Numpad9),
+    ),
+    ("jump_to_angle_1", Action::JumpToAngle(0), Binding::shift(Key::Key1)),
+    ("jump_to_angle_2", Action::JumpToAngle(1), Binding::shift(Key::Key2)),
+    ("jump_to_angle_3", Action::JumpToAngle(2), Binding::shift(Key::Key3)),
+    ("jump_to_angle_4", Action::JumpToAngle(3), Binding::shift(Key::Key4)),
+    ("jump_to_angle_5", Action::JumpToAngle(4), Binding::shift(Key::Key5)),
+    ("jump_to_angle_6", Action::JumpToAngle(5), Binding::shift(Key::Key6)),
+    ("jump_to_angle_7", Action::JumpToAngle(6), Binding::shift(Key::Key7)),
+    ("jump_to_angle_8", Action::JumpToAngle(7), Binding::shift(Key::Key8)),
+    (
+        "toggle_chapter_menu",
+        Action::ToggleChapterMenu,
+        Binding::plain(Key::J),
+    ),
+    (
+        "jump_to_chapter_1",
+        Action::JumpToChapter(0),
+        Binding::plain(Key::Key1),
+    ),
+    (
+        "jump_to_chapter_2",
+        Action::JumpToChapter(1),
+        Binding::plain(Key::Key2),
+    ),
+    (
+        "jump_to_chapter_3",
+        Action::JumpToChapter(2),
+        Binding::plain(Key::Key3),
+    ),
+    (
+        "jump_to_chapter_4",
+        Action::JumpToChapter(3),
+        Binding::plain(Key::Key4),
+    ),
+    (
+        "jump_to_chapter_5",
+        Action::JumpToChapter(4),
+        Binding::plain(Key::Key5),
+    ),
+    (
+        "jump_to_chapter_6",
+        Action::JumpToChapter(5),
+        Binding::plain(Key::Key6),
+    ),
+    (
+        "jump_to_chapter_7",
+        Action::JumpToChapter(6),
+        Binding::plain(Key::Key7),
+    ),
+    (
+        "jump_to_chapter_8",
+        Action::JumpToChapter(7),
+        Binding::plain(Key::Key8),
+    ),
+    (
+        "jump_to_chapter_9",
+        Action::JumpToChapter(8),
+        Binding::plain(Key::Key9),
+    ),
+];
+
+/// The names keys can be given in the keys file, which are matched ignoring
+/// case. The comma has no symbol, as commas separate keys.
+const KEY_NAMES: &[(&str, Key)] = &[
+    ("A", Key::A),
+    ("B", Key::B),
+    ("C", Key::C),
+    ("D", Key::D),
+    ("E", Key::E),
+    ("F", Key::F),
+    ("G", Key::G),
+    ("H", Key::H),
+    ("I", Key::I),
+    ("J", Key::J),
+    ("K", Key::K),
+    ("L", Key::L),
+    ("M", Key::M),
+    ("N", Key::N),
+    ("O", Key::O),
+    ("P", Key::P),
+    ("Q", Key::Q),
+    ("R", Key::R),
+    ("S", Key::S),
+    ("T", Key::T),
+    ("U", Key::U),
+    ("V", Key::V),
+    ("W", Key::W),
+    ("X", Key::X),
+    ("Y", Key::Y),
+    ("Z", Key::Z),
+    ("0", Key::Key0),
+    ("1", Key::Key1),
+    ("2", Key::Key2),
+    ("3", Key::Key3),
+    ("4", Key::Key4),
+    ("5", Key::Key5),
+    ("6", Key::Key6),
+    ("7", Key::Key7),
+    ("8", Key::Key8),
+    ("9", Key::Key9),
+    ("F1", Key::F1),
+    ("F2", Key::F2),
+    ("F3", Key::F3),
+    ("F4", Key::F4),
+    ("F5", Key::F5),
+    ("F6", Key::F6),
+    ("F7", Key::F7),
+    ("F8", Key::F8),
+    ("F9", Key::F9),
+    ("F10", Key::F10),
+    ("F11", Key::F11),
+    ("F12", Key::F12),
+    ("Numpad0", Key::Numpad0),
+    ("Numpad1", Key::Numpad1),
+    ("Numpad2", Key::Numpad2),
+    ("Numpad3", Key::Numpad3),
+    ("Numpad4", Key::Numpad4),
+    ("Numpad5", Key::Numpad5),
+    ("Numpad6", Key::Numpad6),
+    ("Numpad7", Key::Numpad7),
+    ("Numpad8", Key::Numpad8),
+    ("Numpad9", Key::Numpad9),
+    ("Space", Key::Space),
+    ("Escape", Key::Escape),
+    ("Esc", Key::Escape),
+    ("Tab", Key::Tab),
+    ("Enter", Key::Return),
+    ("Return", Key::Return),
+    ("Backspace", Key::Back),
+    ("Delete", Key::Delete),
+    ("Insert", Key::Insert),
+    ("Home", Key::Home),
+    ("End", Key::End),
+    ("PageUp", Key::PageUp),
+    ("PageDown", Key::PageDown),
+    ("Left", Key::Left),
+    ("Right", Key::Right),
+    ("Up", Key::Up),
+    ("Down", Key::Down),
+    ("[", Key::LBracket),
+    ("LBracket", Key::LBracket),
+    ("]", Key::RBracket),
+    ("RBracket", Key::RBracket),
+    ("=", Key::Equals),
+    ("Equals", Key::Equals),
+    ("-", Key::Minus),
+    ("Minus", Key::Minus),
+    (".", Key::Period),
+    ("Period", Key::Period),
+    ("Comma", Key::Comma),
+    (";", Key::Semicolon),
+    ("Semicolon", Key::Semicolon),
+    ("'", Key::Apostrophe),
+    ("Apostrophe", Key::Apostrophe),
+    ("/", Key::Slash),
+    ("Slash", Key::Slash),
+    ("\\", Key::Backslash),
+    ("Backslash", Key::Backslash),
+    ("`", Key::Grave),
+    ("Grave", Key::Grave),
+];
+
+/// The name of `action` in the keys file.
+pub fn action_name(action: Action) -> Option<&'static str> {
+    DEFAULT_BINDINGS
+        .iter()
+        .find_map(|&(name, a, _)| (a == action).then_some(name))
+}
+
+/// Describes `key` pressed with `mods`, such as `Ctrl + Shift + E`, or
+/// returns `None` for keys without a name, such as the modifiers themselves.
+pub fn describe_keys(key: Key, mods: ModifiersState) -> Option<String> {
+    let (name, _) = KEY_NAMES.iter().find(|&&(_, k)| k == key)?;
+    let held = [
+        (mods.ctrl(), "Ctrl"),
+        (mods.alt(), "Alt"),
+        (mods.shift(), "Shift"),
+    ];
+
+    Some(
+        held.into_iter()
+            .filter_map(|(held, modifier)| held.then_some(modifier))
+            .chain([*name])
+            .collect::<Vec<_>>()
+            .join(" + "),
+    )
+}
+
+/// Which action each key is bound to.
+#[derive(Debug)]
+pub struct KeyMap {
+    bindings: Vec<(Binding, Action)>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self {
+            bindings: DEFAULT_BINDINGS
+                .iter()
+                .map(|&(_, action, binding)| (binding, action))
+                .collect(),
+        }
+    }
+}
+
+impl KeyMap {
+    /// Loads the default bindings, replacing any which are rebound in
+    /// [`KEYS_FILE`].
+    pub fn load() -> Self {
+        let mut map = Self::default();
+        let Ok(contents) = fs::read_to_string(KEYS_FILE)
+        else {
+            return map;
+        };
+
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Err(e) = map.rebind(line) {
+                eprintln!("{KEYS_FILE}:{}: {e}", i + 1);
+            }
+        }
+
+        map
+    }
+
+    /// The action bound to `key` with `mods` held, or to `key` alone if
+    /// nothing is bound to that combination.
+    pub fn action(&self, key: Key, mods: ModifiersState) -> Option<Action> {
+        let pressed = Binding::new(key, mods.shift(), mods.ctrl(), mods.alt());
+
+        self.bound_to(pressed)
+            .or_else(|| self.bound_to(Binding::plain(key)))
+    }
+
+    fn bound_to(&self, binding: Binding) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find_map(|&(b, action)| (b == binding).then_some(action))
+    }
+
+    /// Applies a line such as `toggle_running = P, Ctrl+Space`, which binds
+    /// the action to those keys instead of its defaults, taking them from
+    /// any other action.
+    fn rebind(&mut self, line: &str) -> Result<(), String> {
+        let (name, keys) = line
+            .split_once('=')
+            .ok_or_else(|| String::from("expected `action = keys`"))?;
+        let name = name.trim();
+        let action = DEFAULT_BINDINGS
+            .iter()
+            .find_map(|&(n, action, _)| (n == name).then_some(action))
+            .ok_or_else(|| format!("unknown action: {name}"))?;
+        let keys = keys
+            .split(',')
+            .map(|key| Binding::parse(key.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.bindings
+            .retain(|(binding, a)| *a != action && !keys.contains(binding));
+        self.bindings
+            .extend(keys.into_iter().map(|key| (key, action)));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binding_parses_modifiers_in_any_case() {
+        assert_eq!(Binding::parse("E"), Ok(Binding::plain(Key::E)));
+        assert_eq!(
+            Binding::parse("Ctrl+Shift+E"),
+            Ok(Binding::ctrl_shift(Key::E))
+        );
+        assert_eq!(Binding::parse("alt + f3"), Ok(Binding::alt(Key::F3)));
+    }
+
+    #[test]
+    fn binding_rejects_unknown_keys_and_modifiers() {
+        assert!(Binding::parse("Ctrl+Nope").is_err());
+        assert!(Binding::parse("Hyper+E").is_err());
+        assert!(Binding::parse("").is_err());
+    }
+
+    #[test]
+    fn rebind_replaces_the_defaults() {
+        let mut map = KeyMap::default();
+        map.rebind("toggle_running = P, Ctrl+Space").unwrap();
+
+        assert_eq!(
+            map.bound_to(Binding::plain(Key::P)),
+            Some(Action::ToggleRunning)
+        );
+        assert_eq!(
+            map.bound_to(Binding::ctrl(Key::Space)),
+            Some(Action::ToggleRunning)
+        );
+        assert_eq!(map.bound_to(Binding::plain(Key::Space)), None);
+    }
+
+    #[test]
+    fn rebind_rejects_malformed_lines() {
+        let mut map = KeyMap::default();
+
+        assert!(map.rebind("toggle_running P").is_err());
+        assert!(map.rebind("nonsense = P").is_err());
+        assert!(map.rebind("toggle_running = Nope").is_err());
+    }
+}
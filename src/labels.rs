@@ -4,11 +4,19 @@ use crate::consts::*;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering::Relaxed;
+use trig_visuals::Function;
 
 fn label_bounds() -> Vec2 {
     vec2(40.0, 30.0)
 }
 
+/// How long a label takes to move to the other side of its segment, in
+/// seconds.
+const FLIP_TIME_SECS: f32 = 0.25;
+/// How close to zero a value has to get before a label stops following its
+/// sign, so that it doesn't flicker back and forth.
+const FLIP_THRESHOLD: f32 = 0.05;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Label {
     Sin,
@@ -22,6 +30,58 @@ pub enum Label {
 }
 
 impl Label {
+    pub const ALL: [Self; 8] = [
+        Self::Sin,
+        Self::Cos,
+        Self::Tan,
+        Self::Cot,
+        Self::Sec,
+        Self::Csc,
+        Self::Theta,
+        Self::Unit,
+    ];
+
+    /// The label's name in the state file.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Sin => "sin",
+            Self::Cos => "cos",
+            Self::Tan => "tan",
+            Self::Cot => "cot",
+            Self::Sec => "sec",
+            Self::Csc => "csc",
+            Self::Theta => "theta",
+            Self::Unit => "unit",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "sin" => Some(Self::Sin),
+            "cos" => Some(Self::Cos),
+            "tan" => Some(Self::Tan),
+            "cot" => Some(Self::Cot),
+            "sec" => Some(Self::Sec),
+            "csc" => Some(Self::Csc),
+            "theta" => Some(Self::Theta),
+            "unit" => Some(Self::Unit),
+            _ => None,
+        }
+    }
+
+    /// The function whose line the label is beside, if it's one of the six.
+    pub const fn function(self) -> Option<Function> {
+        match self {
+            Self::Sin => Some(Function::Sin),
+            Self::Cos => Some(Function::Cos),
+            Self::Tan => Some(Function::Tan),
+            Self::Cot => Some(Function::Cot),
+            Self::Sec => Some(Function::Sec),
+            Self::Csc => Some(Function::Csc),
+            Self::Theta | Self::Unit => None,
+        }
+    }
+
     pub const fn should_fade(self, other: Self) -> bool {
         match self {
             Self::Sin => matches!(other, Self::Tan | Self::Csc),
@@ -34,11 +94,62 @@ impl Label {
     }
 }
 
+/// The curve a label's opacity follows as it fades.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FadeEasing {
+    Linear,
+    /// Eases in and out gently.
+    #[default]
+    Smoothstep,
+    /// Eases in and out sharply, so most of the fade happens midway.
+    Exponential,
+}
+
+impl FadeEasing {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "linear" => Some(Self::Linear),
+            "smoothstep" => Some(Self::Smoothstep),
+            "exponential" => Some(Self::Exponential),
+            _ => None,
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Linear => "linear",
+            Self::Smoothstep => "smoothstep",
+            Self::Exponential => "exponential",
+        }
+    }
+
+    /// Eases `t` from `0` to `1`.
+    fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => t,
+            Self::Smoothstep => t * t * (3.0 - 2.0 * t),
+            // exactly 0 and 1 at the ends, which the curve only approaches
+            Self::Exponential if t <= 0.0 || t >= 1.0 => t,
+            Self::Exponential if t < 0.5 => 2.0_f32.powf(20.0 * t - 10.0) * 0.5,
+            Self::Exponential => 1.0 - 2.0_f32.powf(10.0 - 20.0 * t) * 0.5,
+        }
+    }
+}
+
 #[derive(Debug)]
 struct LabelData {
     pub rect: Rect,
     pub should_fade: AtomicBool,
     pub opacity: f32,
+    /// How far the label is through fading back in, from `0` (faded) to `1`,
+    /// before easing.
+    pub fade: f32,
+    /// Which side of its segment the label sits on, easing between `-1` and
+    /// `1`.
+    pub side: f32,
+    pub target_side: f32,
 }
 
 impl Default for LabelData {
@@ -47,16 +158,28 @@ impl Default for LabelData {
             rect: Rect::from_xy_wh(Vec2::ZERO, label_bounds()),
             should_fade: AtomicBool::new(false),
             opacity: 1.0,
+            fade: 1.0,
+            side: 1.0,
+            target_side: 1.0,
         }
     }
 }
 
+impl LabelData {
+    fn on_side(side: f32) -> Self {
+        Self { side, target_side: side, ..Self::default() }
+    }
+}
+
 impl Clone for LabelData {
     fn clone(&self) -> Self {
         Self {
             rect: self.rect,
             should_fade: AtomicBool::new(self.should_fade.load(Relaxed)),
             opacity: self.opacity,
+            fade: self.fade,
+            side: self.side,
+            target_side: self.target_side,
         }
     }
 }
@@ -69,17 +192,24 @@ pub struct Labels {
     fade_out_secs: f32,
     fade_in_secs: f32,
     fade_intensity: f32,
+    fade_easing: FadeEasing,
 }
 
 impl Labels {
-    pub fn new() -> Self {
+    /// Creates the labels, which fade in over `fade_in_secs` and out over
+    /// `fade_out_secs` along the `fade_easing` curve.
+    pub fn new(
+        fade_in_secs: f32,
+        fade_out_secs: f32,
+        fade_easing: FadeEasing,
+    ) -> Self {
         let label_map = [
             (Label::Sin, LabelData::default()),
             (Label::Cos, LabelData::default()),
             (Label::Tan, LabelData::default()),
             (Label::Cot, LabelData::default()),
             (Label::Sec, LabelData::default()),
-            (Label::Csc, LabelData::default()),
+            (Label::Csc, LabelData::on_side(-1.0)),
             (Label::Theta, LabelData::default()),
             (Label::Unit, LabelData::default()),
         ]
@@ -89,9 +219,10 @@ impl Labels {
         Self {
             label_map,
 
-            fade_in_secs: FADE_TIME_SECS * 3.0,
-            fade_out_secs: FADE_TIME_SECS,
+            fade_in_secs,
+            fade_out_secs,
             fade_intensity: FADE_INTENSITY,
+            fade_easing,
         }
     }
 
@@ -99,18 +230,44 @@ impl Labels {
         // self.update_positions();
         self.update_intersecting();
         self.update_fade(delta_time);
+        self.update_sides(delta_time);
     }
 
     pub fn get_opacity(&self, label: Label) -> f32 {
         self.label_map.get(&label).map_or(1.0, |lbl| lbl.opacity)
     }
 
+    /// How far `label` is through fading back in, before easing, and whether
+    /// it's fading out.
+    pub fn fade_state(&self, label: Label) -> (f32, bool) {
+        self.label_map.get(&label).map_or((1.0, false), |lbl| {
+            (lbl.fade, lbl.should_fade.load(Relaxed))
+        })
+    }
+
     pub fn update_position(&mut self, label: Label, pos: Vec2) {
         self.label_map.entry(label).and_modify(|data| {
             data.rect = Rect::from_xy_wh(pos, label_bounds());
         });
     }
 
+    /// Which side of its segment `label` sits on, from `-1` to `1`.
+    pub fn side(&self, label: Label) -> f32 {
+        self.label_map.get(&label).map_or(1.0, |lbl| lbl.side)
+    }
+
+    /// Moves `label` to the side of its segment given by the sign of `value`,
+    /// unless `value` is too close to zero to tell.
+    pub fn flip_to(&mut self, label: Label, value: f32) {
+        if value.abs() < FLIP_THRESHOLD {
+            return;
+        }
+
+        self.label_map.entry(label).and_modify(|data| {
+            data.target_side = value.signum();
+        });
+    }
+
     pub fn get_position(&self, label: Label) -> Vec2 {
         self.label_map
             .get(&label)
@@ -154,14 +311,20 @@ impl Labels {
     fn update_fade(&mut self, dt: f32) {
         let mut set_opacity = |label| {
             if let Some(data) = self.label_map.get_mut(&label) {
-                data.opacity = (data.opacity
+                // the fade is stepped linearly, so that it takes the same
+                // time at any frame rate, and eased afterwards
+                data.fade = (data.fade
                     + if data.should_fade.load(Relaxed) {
                         -self.fade_out_secs.recip() * dt
                     }
                     else {
                         self.fade_in_secs.recip() * dt
                     })
-                .clamp(1.0 - FADE_INTENSITY, 1.0);
+                .clamp(0.0, 1.0);
+
+                data.opacity = 1.0
+                    - self.fade_intensity
+                        * (1.0 - self.fade_easing.apply(data.fade));
             }
         };
 
@@ -171,4 +334,12 @@ impl Labels {
         set_opacity(Label::Theta);
         set_opacity(Label::Unit);
     }
+    fn update_sides(&mut self, dt: f32) {
+        let step = 2.0 * dt / FLIP_TIME_SECS;
+
+        for data in self.label_map.values_mut() {
+            let diff = data.target_side - data.side;
+            data.side += diff.clamp(-step, step);
+        }
+    }
 }
@@ -0,0 +1,310 @@
+use crate::value_groups::ValueLine;
+use nannou::prelude::*;
+
+/// The width of the value panel when it's beside the circle.
+const SIDE_PANEL_WIDTH: f32 = 240.0;
+/// The height of the value panel when it's below the circle.
+const BOTTOM_PANEL_HEIGHT: f32 = 180.0;
+/// The radius of a fitted circle as a fraction of the smaller side of its
+/// region, leaving room for the labels and readouts around it.
+const FITTED_RADIUS: f32 = 0.35;
+/// The height of a row of the value panel.
+const VALUE_ROW_HEIGHT: f32 = 50.0;
+/// The height of a group heading in the value panel.
+const VALUE_HEADING_HEIGHT: f32 = 30.0;
+
+/// Where the value panel sits relative to the circle.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Arrangement {
+    #[default]
+    Right,
+    Left,
+    Bottom,
+}
+
+impl Arrangement {
+    pub fn cycle(&mut self) {
+        *self = match self {
+            Self::Right => Self::Left,
+            Self::Left => Self::Bottom,
+            Self::Bottom => Self::Right,
+        }
+    }
+}
+
+/// Which axes the circle and its figure are mirrored in, for rear projection
+/// or to match figures drawn with other conventions. Text stays readable.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Mirror {
+    #[default]
+    None,
+    /// Left and right are swapped, so θ runs clockwise.
+    Horizontal,
+    /// Up and down are swapped, so θ runs clockwise.
+    Vertical,
+    /// Both are swapped, which is the same as turning the figure by π.
+    Both,
+}
+
+impl Mirror {
+    pub fn cycle(&mut self) {
+        *self = match self {
+            Self::None => Self::Horizontal,
+            Self::Horizontal => Self::Vertical,
+            Self::Vertical => Self::Both,
+            Self::Both => Self::None,
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "none" => Some(Self::None),
+            "horizontal" => Some(Self::Horizontal),
+            "vertical" => Some(Self::Vertical),
+            "both" => Some(Self::Both),
+            _ => None,
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+            Self::Both => "both",
+        }
+    }
+
+    /// The scale which mirrors the scene, `-1` in each mirrored axis.
+    pub fn scale(self) -> Vec2 {
+        match self {
+            Self::None => vec2(1.0, 1.0),
+            Self::Horizontal => vec2(-1.0, 1.0),
+            Self::Vertical => vec2(1.0, -1.0),
+            Self::Both => vec2(-1.0, -1.0),
+        }
+    }
+
+    /// Mirrors `point`, or undoes its mirroring, as mirroring twice is the
+    /// same as not mirroring.
+    pub fn apply(self, point: Vec2) -> Vec2 {
+        point * self.scale()
+    }
+}
+
+/// The regions of the window, computed from its size each frame.
+///
+/// Positions returned by the methods here are relative to the centre of the
+/// circle, which is where the scene is drawn from.
+#[derive(Clone, Copy, Debug)]
+pub struct ScreenLayout {
+    pub arrangement: Arrangement,
+    pub mirror: Mirror,
+    window: Rect,
+    circle: Rect,
+    values: Rect,
+}
+
+impl Default for ScreenLayout {
+    fn default() -> Self {
+        Self::new(
+            Rect::from_w_h(800.0, 800.0),
+            Arrangement::default(),
+            Mirror::default(),
+        )
+    }
+}
+
+impl ScreenLayout {
+    pub fn new(window: Rect, arrangement: Arrangement, mirror: Mirror) -> Self {
+        let (circle, values) = match arrangement {
+            Arrangement::Right => {
+                let split = window.right() - SIDE_PANEL_WIDTH;
+                (
+                    Rect::from_corners(
+                        window.bottom_left(),
+                        pt2(split, window.top()),
+                    ),
+                    Rect::from_corners(
+                        pt2(split, window.bottom()),
+                        window.top_right(),
+                    ),
+                )
+            }
+            Arrangement::Left => {
+                let split = window.left() + SIDE_PANEL_WIDTH;
+                (
+                    Rect::from_corners(
+                        pt2(split, window.bottom()),
+                        window.top_right(),
+                    ),
+                    Rect::from_corners(
+                        window.bottom_left(),
+                        pt2(split, window.top()),
+                    ),
+                )
+            }
+            Arrangement::Bottom => {
+                let split = window.bottom() + BOTTOM_PANEL_HEIGHT;
+                (
+                    Rect::from_corners(
+                        pt2(window.left(), split),
+                        window.top_right(),
+                    ),
+                    Rect::from_corners(
+                        window.bottom_left(),
+                        pt2(window.right(), split),
+                    ),
+                )
+            }
+        };
+
+        Self { arrangement, mirror, window, circle, values }
+    }
+
+    /// The centre of the circle in window coordinates.
+    pub fn origin(&self) -> Vec2 {
+        self.circle.xy()
+    }
+
+    fn in_scene(&self, point: Vec2) -> Vec2 {
+        point - self.origin()
+    }
+
+    /// The centre of the window.
+    pub fn window_center(&self) -> Vec2 {
+        self.in_scene(self.window.xy())
+    }
+
+    /// The window's bounds.
+    pub fn window(&self) -> Rect {
+        self.window.shift(-self.origin())
+    }
+
+    /// The region the circle is drawn in.
+    pub fn circle(&self) -> Rect {
+        self.circle.shift(-self.origin())
+    }
+
+    /// The radius which fits the circle in its region.
+    pub fn fitted_radius(&self) -> f32 {
+        self.circle.w().min(self.circle.h()) * FITTED_RADIUS
+    }
+
+    /// The region of the value panel.
+    pub fn values(&self) -> Rect {
+        self.values.shift(-self.origin())
+    }
+
+    /// The position of each of `lines` in the value panel. Beside the circle
+    /// they're stacked down the middle of the panel; below it, the ungrouped
+    /// lines share the first column and each group has a column of its own.
+    pub fn value_slots(&self, lines: &[ValueLine]) -> Vec<Vec2> {
+        let panel = self.values;
+
+        if self.arrangement == Arrangement::Bottom {
+            let col_width = panel.w() / 4.0;
+            let mut tops = [panel.top() - 10.0; 4];
+
+            return lines
+                .iter()
+                .map(|&line| {
+                    let col = line.group().map_or(0, |group| group.index() + 1);
+                    let y = line_y(line, &mut tops[col]);
+                    self.in_scene(pt2(
+                        panel.left() + 130.0 + col as f32 * col_width,
+                        y,
+                    ))
+                })
+                .collect();
+        }
+
+        let height: f32 = lines.iter().map(|&line| line_height(line)).sum();
+        let mut top = panel.y() + height * 0.5;
+
+        lines
+            .iter()
+            .map(|&line| {
+                let y = line_y(line, &mut top);
+                self.in_scene(pt2(panel.left() + 150.0, y))
+            })
+            .collect()
+    }
+
+    /// The position of the first line of a list shown above the value panel,
+    /// or in the opposite corner of the circle's region from the status
+    /// readouts when the panel is below.
+    pub fn list_top(&self) -> Vec2 {
+        if self.arrangement == Arrangement::Bottom {
+            self.in_scene(pt2(
+                self.circle.right() - 110.0,
+                self.circle.top() - 15.0,
+            ))
+        }
+        else {
+            self.in_scene(pt2(
+                self.values.left() + 150.0,
+                self.values.top() - 15.0,
+            ))
+        }
+    }
+
+    /// The position of the `line`th readout in the top corner of the circle's
+    /// region.
+    pub fn status_slot(&self, line: usize) -> Vec2 {
+        self.in_scene(pt2(
+            self.circle.left() + 110.0,
+            self.circle.top() - 40.0 - line as f32 * 60.0,
+        ))
+    }
+
+    /// The position of the readout in the bottom corner of the circle's
+    /// region.
+    pub fn status_bottom(&self) -> Vec2 {
+        self.in_scene(pt2(
+            self.circle.left() + 110.0,
+            self.circle.bottom() + 70.0,
+        ))
+    }
+
+    /// The position of the `line`th legend up from the other bottom corner
+    /// of the circle's region.
+    pub fn legend_slot(&self, line: usize) -> Vec2 {
+        self.in_scene(pt2(
+            self.circle.right() - 110.0,
+            self.circle.bottom() + 70.0 + line as f32 * 140.0,
+        ))
+    }
+
+    /// The position of the script's caption, beneath the circle.
+    pub fn caption(&self) -> Vec2 {
+        self.in_scene(pt2(self.circle.x(), self.circle.bottom() + 30.0))
+    }
+}
+
+/// The clickable area of the line of the value panel at `slot`.
+pub fn value_rect(slot: Vec2) -> Rect {
+    Rect::from_xy_wh(vec2(slot.x - 40.0, slot.y), vec2(140.0, VALUE_ROW_HEIGHT))
+}
+
+fn line_height(line: ValueLine) -> f32 {
+    match line {
+        ValueLine::Heading(_) => VALUE_HEADING_HEIGHT,
+        // the rate takes two lines
+        ValueLine::Rate => VALUE_ROW_HEIGHT + 10.0,
+        _ => VALUE_ROW_HEIGHT,
+    }
+}
+
+/// The height of `line`'s text, given the top of the space it takes up,
+/// which is moved down past it.
+fn line_y(line: ValueLine, top: &mut f32) -> f32 {
+    let height = line_height(line);
+    // text is centred vertically, so the rate's second line would lift its
+    // first above the others without moving it down by the extra height
+    let y = *top
+        - height.min(VALUE_ROW_HEIGHT) * 0.5
+        - (height - VALUE_ROW_HEIGHT).max(0.0);
+    *top -= height;
+    y
+}
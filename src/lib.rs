@@ -0,0 +1,22 @@
+//! The unit circle and its six trigonometric functions, drawn with nannou.
+//!
+//! [`TrigCircle`] draws the circle into any sketch's [`Draw`]:
+//!
+//! ```no_run
+//! # use nannou::prelude::*;
+//! # fn view(app: &App, frame: Frame) {
+//! let draw = app.draw();
+//! let theta = app.time * 0.5;
+//!
+//! trig_visuals::TrigCircle::new(theta).draw(&draw, Vec2::ZERO, 150.0);
+//! # }
+//! ```
+//!
+//! [`Draw`]: nannou::Draw
+
+pub mod circle;
+pub mod consts;
+pub mod view;
+
+pub use circle::{Function, TrigCircle, TrigValues, Visible};
+pub use view::{Placement, View};
@@ -0,0 +1,61 @@
+/// Number formatting conventions for displayed values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Locale {
+    /// `1234.56`, as readouts have always been shown.
+    #[default]
+    DecimalPoint,
+    /// `1 234,56`, with a thin space between thousands.
+    DecimalComma,
+}
+
+impl Locale {
+    pub fn cycle(&mut self) {
+        *self = match self {
+            Self::DecimalPoint => Self::DecimalComma,
+            Self::DecimalComma => Self::DecimalPoint,
+        }
+    }
+
+    pub const fn decimal_separator(self) -> char {
+        match self {
+            Self::DecimalPoint => '.',
+            Self::DecimalComma => ',',
+        }
+    }
+
+    /// The separator between groups of thousands, if they're separated.
+    pub const fn thousands_separator(self) -> Option<char> {
+        match self {
+            Self::DecimalPoint => None,
+            Self::DecimalComma => Some('\u{2009}'),
+        }
+    }
+
+    /// Formats `value` to `decimals` decimal places with this locale's
+    /// separators.
+    pub fn format(self, value: f32, decimals: usize) -> String {
+        let formatted = format!("{value:.decimals$}");
+        let (sign, digits) = formatted
+            .strip_prefix('-')
+            .map_or(("", formatted.as_str()), |digits| ("-", digits));
+        let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+
+        let mut out = String::from(sign);
+
+        for (i, ch) in int.chars().enumerate() {
+            if let Some(separator) = self.thousands_separator() {
+                if i > 0 && (int.len() - i) % 3 == 0 {
+                    out.push(separator);
+                }
+            }
+            out.push(ch);
+        }
+
+        if !frac.is_empty() {
+            out.push(self.decimal_separator());
+            out.push_str(frac);
+        }
+
+        out
+    }
+}
@@ -0,0 +1,121 @@
+/// The sync word which ends each 80-bit LTC frame, in the order received.
+const SYNC_WORD: u128 = 0b0011_1111_1111_1101;
+const FRAME_BITS: u32 = 80;
+
+/// An SMPTE timecode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timecode {
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+    pub frames: u32,
+    /// The frame rate, estimated from the highest frame number seen.
+    pub fps: u32,
+}
+
+impl Timecode {
+    /// The time in seconds since `00:00:00:00`.
+    pub fn to_secs(self) -> f32 {
+        (self.hours * 3600 + self.minutes * 60 + self.seconds) as f32
+            + self.frames as f32 / self.fps.max(1) as f32
+    }
+}
+
+impl std::fmt::Display for Timecode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}:{:02}",
+            self.hours, self.minutes, self.seconds, self.frames
+        )
+    }
+}
+
+/// Decodes linear timecode (LTC) from audio, which is biphase mark coded:
+/// every bit starts with a transition, and a 1 has another halfway through.
+#[derive(Debug)]
+pub struct LtcDecoder {
+    last_sample: f32,
+    samples_since_edge: u32,
+    /// The running estimate of the length of one bit in samples.
+    bit_len: f32,
+    /// Whether the first half of a 1 bit has been seen.
+    half_bit: bool,
+    /// The most recent bits, with the latest in the lowest bit.
+    bits: u128,
+    max_frame: u32,
+}
+
+impl Default for LtcDecoder {
+    fn default() -> Self {
+        Self {
+            last_sample: 0.0,
+            samples_since_edge: 0,
+            // 30 fps at 48 kHz
+            bit_len: 20.0,
+            half_bit: false,
+            bits: 0,
+            max_frame: 29,
+        }
+    }
+}
+
+impl LtcDecoder {
+    /// Processes one audio sample, returning a timecode whenever a complete
+    /// frame has been received.
+    pub fn process(&mut self, sample: f32) -> Option<Timecode> {
+        self.samples_since_edge += 1;
+
+        let is_edge = (sample >= 0.0) != (self.last_sample >= 0.0);
+        self.last_sample = sample;
+        if !is_edge {
+            return None;
+        }
+
+        let interval = self.samples_since_edge as f32;
+        self.samples_since_edge = 0;
+
+        if interval > self.bit_len * 0.75 {
+            self.bit_len = self.bit_len * 0.9 + interval * 0.1;
+            self.half_bit = false;
+            self.push_bit(0)
+        }
+        else if self.half_bit {
+            self.bit_len = self.bit_len * 0.9 + interval * 2.0 * 0.1;
+            self.half_bit = false;
+            self.push_bit(1)
+        }
+        else {
+            self.half_bit = true;
+            None
+        }
+    }
+
+    fn push_bit(&mut self, bit: u128) -> Option<Timecode> {
+        self.bits = ((self.bits << 1) | bit) & ((1 << FRAME_BITS) - 1);
+
+        if self.bits & 0xFFFF != SYNC_WORD {
+            return None;
+        }
+
+        // bits are sent least significant first, with the first bit of the
+        // frame now the highest in the register
+        let field = |start: u32, len: u32| {
+            (0..len).fold(0, |acc, i| {
+                let bit = (self.bits >> (FRAME_BITS - 1 - (start + i))) & 1;
+                acc | ((bit as u32) << i)
+            })
+        };
+
+        let frames = field(0, 4) + field(8, 2) * 10;
+        self.max_frame = self.max_frame.max(frames);
+
+        Some(Timecode {
+            hours: field(48, 4) + field(56, 2) * 10,
+            minutes: field(32, 4) + field(40, 3) * 10,
+            seconds: field(16, 4) + field(24, 3) * 10,
+            frames,
+            fps: self.max_frame + 1,
+        })
+    }
+}
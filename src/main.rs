@@ -1,22 +1,129 @@
 use nannou::prelude::*;
 
-mod consts;
+mod angle_sum;
+mod animation;
+mod bullet_time;
+mod calibration;
+mod changelog;
+mod chapters;
+mod cli;
+mod clock;
+mod compare;
+mod comparison;
+mod crash;
+mod de_moivre;
+mod display;
+mod domain;
+mod epicycles;
+mod events;
+mod export;
+mod flashcards;
+mod focus;
+mod history;
+mod hyperbolic;
+mod identities;
+#[cfg(feature = "inspector")]
+mod inspector;
+mod inverse;
+mod keycast;
+mod keymap;
 mod labels;
+mod layout;
+mod locale;
+mod ltc;
+mod measure;
+mod mic;
 mod model;
+mod narration;
+mod notes;
+mod offscreen;
+mod overlay;
+mod panel;
+mod paper;
+mod pins;
+mod poster;
+mod protractor;
+mod ramp;
+mod recorder;
+mod replay;
+mod scene_dump;
+mod script;
+mod snap;
+mod sound;
+mod state;
+mod stats;
+mod strobe;
+mod taylor;
+mod touch;
+mod tour;
+mod trace;
+mod value_groups;
+mod watermark;
+mod worksheet;
+use cli::InitialState;
 use model::Model;
+use std::sync::OnceLock;
+use std::time::Instant;
+use trig_visuals::consts;
 
 pub const REGULAR_FONT: &[u8] = include_bytes!("../fonts/Times New Roman.ttf");
 pub const ITALIC_FONT: &[u8] =
     include_bytes!("../fonts/Times New Roman Italic.ttf");
 
+/// The state to launch in, parsed from the command line before the app
+/// starts.
+static INITIAL_STATE: OnceLock<InitialState> = OnceLock::new();
+
 fn main() {
-    nannou::app(Model::new).update(update).run();
+    let mut args = std::env::args().skip(1).peekable();
+    if args.peek().map(String::as_str) == Some("render") {
+        if let Err(e) = cli::render(args.skip(1)) {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    match cli::initial_state(args) {
+        Ok(initial) if initial.help => {
+            println!("{}", cli::USAGE);
+            return;
+        }
+        Ok(initial) => _ = INITIAL_STATE.set(initial),
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    }
+
+    crash::install_hook();
+    nannou::app(model).update(update).exit(exit).run();
+}
+
+fn model(app: &App) -> Model {
+    Model::new(app, INITIAL_STATE.get().cloned().unwrap_or_default())
 }
 
 fn update(app: &App, model: &mut Model, update: Update) {
+    let start = Instant::now();
     model.update(
         update.since_last.as_secs_f32(),
+        app.window_rect(),
         app.mouse.position(),
         app.mouse.buttons.left().is_down(),
     );
+    model.record_update_time(
+        update.since_last.as_secs_f32(),
+        start.elapsed().as_secs_f32(),
+    );
+    model.update_panel(update.since_start);
+    model.render_offscreen(app);
+    model.finish_animation(app);
+}
+
+/// Waits for any screenshots which are still being written.
+fn exit(app: &App, model: Model) {
+    if !model.await_captures(app) {
+        eprintln!("timed out waiting for screenshots to be written");
+    }
 }
@@ -0,0 +1,61 @@
+use std::f32::consts::TAU;
+
+/// Angular interval between two points picked on the circle.
+#[derive(Debug, Default)]
+pub struct Measure {
+    active: bool,
+    start: Option<f32>,
+    end: Option<f32>,
+}
+
+impl Measure {
+    pub fn toggle(&mut self) {
+        self.active = !self.active;
+
+        if !self.active {
+            self.clear();
+        }
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Adds a point at `angle` radians. Picking a third point starts a new
+    /// interval.
+    pub fn pick(&mut self, angle: f32) {
+        let angle = angle.rem_euclid(TAU);
+
+        match (self.start, self.end) {
+            (Some(_), None) => self.end = Some(angle),
+            _ => {
+                self.start = Some(angle);
+                self.end = None;
+            }
+        }
+    }
+
+    /// Clears any picked points, returning whether there were any.
+    pub fn clear(&mut self) -> bool {
+        let had_points = self.start.is_some();
+        self.start = None;
+        self.end = None;
+        had_points
+    }
+
+    pub const fn start(&self) -> Option<f32> {
+        self.start
+    }
+
+    /// The start angle and anticlockwise angular span of the interval, if
+    /// both points have been picked.
+    pub fn interval(&self) -> Option<(f32, f32)> {
+        let (start, end) = (self.start?, self.end?);
+        Some((start, (end - start).rem_euclid(TAU)))
+    }
+}
+
+/// The length of the chord spanning `angle` radians on a unit circle.
+pub fn chord_length(angle: f32) -> f32 {
+    2.0 * (angle * 0.5).sin()
+}
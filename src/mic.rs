@@ -0,0 +1,171 @@
+use crate::ltc::{LtcDecoder, Timecode};
+use nannou_audio as audio;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// Input quieter than this (as an RMS level) is treated as silence.
+const SILENCE_THRESHOLD: f32 = 0.01;
+/// How quickly the smoothed reading follows the input, per second.
+const SMOOTHING: f32 = 8.0;
+
+/// What the microphone input controls.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MicMode {
+    #[default]
+    Off,
+    /// The dominant frequency sets the rotation rate.
+    Rate,
+    /// The loudness sets the size of the circle.
+    Amplitude,
+    /// θ follows linear timecode (LTC) on the input.
+    Timecode,
+}
+
+impl MicMode {
+    pub fn cycle(&mut self) {
+        *self = match self {
+            Self::Off => Self::Rate,
+            Self::Rate => Self::Amplitude,
+            Self::Amplitude => Self::Timecode,
+            Self::Timecode => Self::Off,
+        }
+    }
+}
+
+/// A measurement of the microphone input.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Reading {
+    /// The RMS level of the input.
+    pub level: f32,
+    /// The estimated dominant frequency in Hz, or `0` when silent.
+    pub hz: f32,
+    /// The most recently decoded timecode.
+    pub timecode: Option<Timecode>,
+}
+
+/// The state of the analysis on the audio thread.
+struct Analyser {
+    reading: Arc<Mutex<Reading>>,
+    last_sample: f32,
+    ltc: LtcDecoder,
+}
+
+fn capture(analyser: &mut Analyser, buffer: &audio::Buffer) {
+    let mut sum_squares = 0.0;
+    let mut crossings = 0;
+    let mut num_frames = 0;
+    let mut timecode = None;
+
+    // only the first channel is analysed
+    for sample in buffer.frames().filter_map(|frame| frame.first().copied()) {
+        sum_squares += sample * sample;
+        if analyser.last_sample < 0.0 && sample >= 0.0 {
+            crossings += 1;
+        }
+        analyser.last_sample = sample;
+        num_frames += 1;
+
+        if let Some(tc) = analyser.ltc.process(sample) {
+            timecode = Some(tc);
+        }
+    }
+
+    if num_frames == 0 {
+        return;
+    }
+
+    let level = (sum_squares / num_frames as f32).sqrt();
+    // counting upward zero crossings is a rough but cheap pitch estimate,
+    // which works well enough for whistling or a held note
+    let hz = if level > SILENCE_THRESHOLD {
+        crossings as f32 * buffer.sample_rate() as f32 / num_frames as f32
+    }
+    else {
+        0.0
+    };
+
+    if let Ok(mut reading) = analyser.reading.lock() {
+        reading.level = level;
+        reading.hz = hz;
+        reading.timecode = timecode.or(reading.timecode);
+    }
+}
+
+/// Listens to the microphone so that it can drive the visualisation.
+#[derive(Default)]
+pub struct Microphone {
+    pub mode: MicMode,
+    stream: Option<audio::Stream<Analyser>>,
+    latest: Arc<Mutex<Reading>>,
+    smoothed: Reading,
+}
+
+impl fmt::Debug for Microphone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Microphone")
+            .field("mode", &self.mode)
+            .field("has_stream", &self.stream.is_some())
+            .field("smoothed", &self.smoothed)
+            .finish()
+    }
+}
+
+impl Microphone {
+    /// Cycles what the microphone controls, opening the audio input the
+    /// first time it's used.
+    pub fn cycle_mode(&mut self) {
+        self.mode.cycle();
+
+        if self.mode != MicMode::Off && self.stream.is_none() {
+            match self.start_stream() {
+                Ok(stream) => self.stream = Some(stream),
+                Err(e) => {
+                    eprintln!("failed to open audio input: {e}");
+                    self.mode = MicMode::Off;
+                }
+            }
+        }
+    }
+
+    /// Smooths the latest reading, returning it if the microphone is in use.
+    pub fn update(&mut self, delta_time: f32) -> Option<Reading> {
+        if self.mode == MicMode::Off {
+            return None;
+        }
+
+        let latest = self.latest.lock().map(|r| *r).unwrap_or_default();
+        let t = (SMOOTHING * delta_time).min(1.0);
+
+        self.smoothed.level += (latest.level - self.smoothed.level) * t;
+        // the frequency holds its last value through silence
+        if latest.hz > 0.0 {
+            self.smoothed.hz += (latest.hz - self.smoothed.hz) * t;
+        }
+        // timecode is used as-is to stay frame-accurate
+        self.smoothed.timecode = latest.timecode;
+
+        Some(self.smoothed)
+    }
+
+    /// The most recently decoded timecode.
+    pub fn timecode(&self) -> Option<Timecode> {
+        self.smoothed.timecode
+    }
+
+    fn start_stream(&self) -> Result<audio::Stream<Analyser>, String> {
+        let analyser = Analyser {
+            reading: Arc::clone(&self.latest),
+            last_sample: 0.0,
+            ltc: LtcDecoder::default(),
+        };
+
+        let stream = audio::Host::new()
+            .new_input_stream(analyser)
+            .capture(capture)
+            .build()
+            .map_err(|e| e.to_string())?;
+        stream.play().map_err(|e| e.to_string())?;
+
+        Ok(stream)
+    }
+}
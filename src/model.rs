@@ -1,6 +1,18 @@
 #![allow(clippy::suboptimal_flops)]
-use crate::{consts::*, labels::*, ITALIC_FONT, REGULAR_FONT};
+use crate::{
+    angle_sum::*, animation::*, bullet_time::*, calibration::*, changelog::*,
+    chapters::*, cli::InitialState, clock::*, compare::*, comparison::*,
+    consts::*, crash::*, de_moivre::*, display::*, domain::*, epicycles::*,
+    events::*, export::*, flashcards::*, focus::*, history::*, hyperbolic::*,
+    identities::*, inverse::*, keycast::*, keymap::*, labels::*, layout::*,
+    locale::*, measure::*, mic::*, narration::*, notes::*, offscreen::*,
+    overlay::*, panel::*, paper::*, pins::*, poster::*, protractor::*, ramp::*,
+    recorder::*, replay::*, scene_dump::*, script::*, snap::*, sound::*,
+    state::*, stats::*, strobe::*, taylor::*, touch::*, tour::*, trace::*,
+    value_groups::*, watermark::*, worksheet::*, ITALIC_FONT, REGULAR_FONT,
+};
 use nannou::{
+    event::ModifiersState,
     prelude::*,
     text::{
         Align::End,
@@ -8,11 +20,12 @@ use nannou::{
         Justify::{self, Center, Left},
         Layout,
     },
+    wgpu,
 };
-use std::f32::{
-    consts::{PI, TAU},
-    MAX as INF,
-};
+use std::f32::consts::{PI, TAU};
+use std::sync::OnceLock;
+use std::time::Instant;
+use trig_visuals::{Function, Placement, TrigValues, View, Visible};
 use FontStyle::{Italic, Regular};
 
 #[derive(Clone, Copy)]
@@ -28,6 +41,67 @@ impl FontStyle {
             Italic => ITALIC_FONT,
         }
     }
+
+    /// The font for this style, which is only parsed the first time it's
+    /// used.
+    fn font(self) -> Option<Font> {
+        static REGULAR: OnceLock<Option<Font>> = OnceLock::new();
+        static ITALIC: OnceLock<Option<Font>> = OnceLock::new();
+
+        let font = match self {
+            Regular => &REGULAR,
+            Italic => &ITALIC,
+        };
+
+        font.get_or_init(|| Font::from_bytes(self.font_data()).ok())
+            .clone()
+    }
+}
+
+/// Whether `pos` is over `label`'s row of the value panel.
+fn value_rect_contains(
+    rects: &[(ValueLine, Rect)],
+    label: Label,
+    pos: Vec2,
+) -> bool {
+    rects.iter().any(|&(line, rect)| {
+        line == ValueLine::Value(label) && rect.contains(pos)
+    })
+}
+
+/// The colour of the θ arc at `angle` when it's coloured by quadrant: each
+/// quadrant's colour, blending into the next over a few degrees either side
+/// of each axis.
+fn quadrant_color(angle: f32) -> Rgb {
+    const BLEND: f32 = PI / 18.0;
+
+    let quarters = angle.rem_euclid(TAU) / (PI * 0.5);
+    let axis = quarters.round();
+    // 0 on the near side of the axis, up to 1 on the far side
+    let t = ((quarters - axis) * PI * 0.5 / BLEND).clamp(-1.0, 1.0) * 0.5 + 0.5;
+
+    let after = QUADRANT_COLORS[axis as usize % 4];
+    let before = QUADRANT_COLORS[(axis as usize + 3) % 4];
+
+    Rgb::new(
+        before.red + (after.red - before.red) * t,
+        before.green + (after.green - before.green) * t,
+        before.blue + (after.blue - before.blue) * t,
+    )
+}
+
+/// Draws an arrow from `start` to `end`, with a head sized to the stroke.
+fn draw_vector(draw: &Draw, start: Vec2, end: Vec2, color: Rgba) {
+    // the head would overshoot a shorter arrow
+    let head = (STROKE_WEIGHT * 4.0).min(start.distance(end));
+
+    draw.arrow()
+        .start(start)
+        .end(end)
+        .weight(STROKE_WEIGHT - 1.0)
+        .head_length(head)
+        .head_width(head * 0.5)
+        .color(color);
 }
 
 fn font_layout(
@@ -38,7 +112,7 @@ fn font_layout(
     Layout {
         justify,
         font_size,
-        font: Font::from_bytes(font_style.font_data()).ok(),
+        font: font_style.font(),
         line_spacing: 3.0,
         // TODO: this will fix the spacing issue with the "rate" value text, but
         // shifts everything upwards
@@ -47,42 +121,48 @@ fn font_layout(
     }
 }
 
-#[derive(Clone, Copy, Default, Debug)]
-struct TrigValues {
-    /// Sine function
-    pub sin: f32,
-    /// Cosine function
-    pub cos: f32,
-    /// Tangent function
-    pub tan: f32,
-    /// Cotangent function
-    pub cot: f32,
-    /// Secant function
-    pub sec: f32,
-    /// Cosecant function
-    pub csc: f32,
+// --- *** --- //
+
+/// The watermark in its corner of the window, which is the one overlay the
+/// app registers itself.
+struct WatermarkOverlay {
+    watermark: Watermark,
+    /// The watermark's image, with its opacity baked into the alpha.
+    texture: Option<wgpu::Texture>,
 }
 
-impl TrigValues {
-    pub fn clamp_inf(&mut self) {
-        self.tan = self.tan.clamp(-INF, INF);
-        self.cot = self.cot.clamp(-INF, INF);
-        self.sec = self.sec.clamp(-INF, INF);
-        self.csc = self.csc.clamp(-INF, INF);
+impl Overlay for WatermarkOverlay {
+    fn name(&self) -> &str {
+        "watermark"
     }
-}
 
-impl std::ops::Mul<f32> for TrigValues {
-    type Output = Self;
+    fn draw(&self, draw: &Draw, ctx: &OverlayContext) {
+        const FONT_SIZE: u32 = 16;
+
+        let window = ctx.window;
+        let image_size = self.texture.as_ref().map_or([0.0, 0.0], |texture| {
+            let [w, h] = texture.size();
+            [w as f32 / h as f32 * WATERMARK_HEIGHT, WATERMARK_HEIGHT]
+        });
+        let place = self.watermark.place(
+            [window.bottom_left().into(), window.top_right().into()],
+            image_size,
+            FONT_SIZE as f32,
+        );
+
+        if let Some(texture) = &self.texture {
+            let [w, h] = image_size;
+            draw.texture(texture).xy(place.image.into()).w_h(w, h);
+        }
+
+        if let Some(text) = &self.watermark.text {
+            let color = if ctx.is_dark { 1.0 } else { 0.0 };
 
-    fn mul(self, rhs: f32) -> Self::Output {
-        Self {
-            sin: self.sin * rhs,
-            cos: self.cos * rhs,
-            tan: self.tan * rhs,
-            cot: self.cot * rhs,
-            sec: self.sec * rhs,
-            csc: self.csc * rhs,
+            draw.text(text)
+                .xy(place.text.into())
+                .w(window.w())
+                .layout(&font_layout(FONT_SIZE, Regular, Center))
+                .color(Rgba::new(color, color, color, self.watermark.opacity));
         }
     }
 }
@@ -90,7 +170,7 @@ impl std::ops::Mul<f32> for TrigValues {
 // --- *** --- //
 
 #[derive(Debug, Default, Clone, Copy)]
-enum Theme {
+pub enum Theme {
     #[default]
     Dark,
     Light,
@@ -111,26 +191,24 @@ impl Theme {
     }
 }
 
-#[allow(clippy::struct_excessive_bools)]
-#[derive(Debug)]
-struct Visible {
-    sin: bool,
-    cos: bool,
-    tan: bool,
-    cot: bool,
-    sec: bool,
-    csc: bool,
+/// The unit angles and rates are shown in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AngleUnit {
+    #[default]
+    Radians,
+    Degrees,
+    Turns,
+    /// Fractions of π, such as `5π/4 = 1¼π`, wherever there's one close by.
+    PiMultiples,
 }
 
-impl Default for Visible {
-    fn default() -> Self {
-        Self {
-            sin: true,
-            cos: true,
-            tan: true,
-            cot: true,
-            sec: true,
-            csc: true,
+impl AngleUnit {
+    pub fn cycle(&mut self) {
+        *self = match self {
+            Self::Radians => Self::Degrees,
+            Self::Degrees => Self::Turns,
+            Self::Turns => Self::PiMultiples,
+            Self::PiMultiples => Self::Radians,
         }
     }
 }
@@ -142,469 +220,5120 @@ pub struct Model {
     rate: f32,
 
     trig_values: TrigValues,
-    trig_values_scaled: TrigValues,
+    mode: Mode,
+    hyperbolic: HyperbolicValues,
+    epicycles: Epicycles,
 
     is_running: bool,
     draw_labels: bool,
+    /// Whether labels are outlined in the background colour.
+    label_outlines: bool,
     draw_values: bool,
     draw_theta: bool,
+    /// Whether the θ arc is coloured by quadrant.
+    quadrant_arc: bool,
+    /// Whether the reference angle, between θ and the x-axis, is drawn.
+    draw_reference_angle: bool,
+    /// The direction θ₀ that angles are measured from, if it isn't the
+    /// x-axis.
+    reference_ray: Option<f32>,
+    draw_clock: bool,
+    draw_countdown: bool,
+    draw_history: bool,
+    draw_range_bars: bool,
+    complex_plane: bool,
+    /// Whether the point's velocity and acceleration are drawn.
+    draw_derivatives: bool,
+    broadcast: bool,
 
     theme: Theme,
+    locale: Locale,
+    angle_unit: AngleUnit,
+    display: DisplaySettings,
 
     selected_label: Option<Label>,
 
     radius: f32,
 
     mouse_state: bool,
-    value_rects: Vec<Rect>,
+    /// While the node is being dragged, whether the circle was running
+    /// before it was grabbed.
+    node_drag: Option<bool>,
+    /// The lines of the value panel and their positions, from top to
+    /// bottom.
+    value_slots: Vec<(ValueLine, Vec2)>,
+    /// The clickable areas of the interactive lines of the value panel.
+    value_rects: Vec<(ValueLine, Rect)>,
+    value_groups: ValueGroups,
+    /// Values dragged out of the panel to sit beside their lines.
+    pins: Pins,
     visible: Visible,
 
+    layout: ScreenLayout,
+    /// How the whole window is mirrored, for rear projection, on top of the
+    /// figure's own mirroring.
+    scene_mirror: Mirror,
     labels: Labels,
+    clock: Clock,
+    countdown: Countdown,
+    measure: Measure,
+    protractor: Protractor,
+    de_moivre: DeMoivre,
+    angle_sum: AngleSum,
+    comparison: Comparison,
+    /// How fast everything runs, as a multiple of real time.
+    time_scale: f32,
+    /// A scheduled change in the rate, if one is under way.
+    ramp: Option<RateRamp>,
+    calibration: Calibration,
+    identities: Identities,
+    inverse: InverseMode,
+    strobe: Strobe,
+    snap: Snap,
+    bullet_time: BulletTime,
+    touches: Touches,
+    stats: Stats,
+    /// The order the lines are drawn in, from the bottom up.
+    draw_order: [Label; 7],
+    /// The top, right, bottom and left of the window kept clear, as
+    /// fractions of its size.
+    safe_area: [f32; 4],
+    /// Whether the safe area is being adjusted in the control panel, while
+    /// which it's outlined.
+    adjusting_safe_area: bool,
+    keymap: KeyMap,
+    trace: Trace,
+    keycast: KeyCast,
+    #[cfg(feature = "inspector")]
+    inspector: crate::inspector::Inspector,
+    taylor: Taylor,
+    domain: DomainShading,
+    sound: Sound,
+    mic: Microphone,
+    history: History,
+    ghost: Option<Ghost>,
+    /// The flashcard export in progress, and the scene to return to after.
+    flashcards: Option<(FlashcardExport, Snapshot)>,
+    animation: Option<(AnimationExport, Snapshot)>,
+    recorder: Option<Recorder>,
+    before: Option<Before>,
+    /// Renders exports at `export_resolution`, if it's set.
+    offscreen: Option<OffscreenRenderer>,
+    /// A screenshot to render offscreen on the next update.
+    pending_screenshot: Option<std::path::PathBuf>,
+    /// While fullscreen, the radius to return to when leaving it.
+    windowed_radius: Option<f32>,
+    /// Whether the radius is fitted to the window whenever it's resized,
+    /// until it's changed by hand.
+    fit_radius: bool,
+    /// The frames of an animation which has finished rendering, waiting to
+    /// be encoded.
+    finished_animation: Option<std::path::PathBuf>,
+    tour: Tour,
+    whats_new: bool,
+    /// The page of the "what's new" panel shown, when its entries don't fit
+    /// in the window at once.
+    whats_new_page: usize,
+    /// Whether the prompt asking to confirm quitting is shown.
+    quit_prompt: bool,
+    jump_pulse: f32,
+    /// The time since the crash snapshot was last recorded.
+    since_snapshot: f32,
+    focus: Focus,
+    events: Events,
+    overlays: Overlays,
+    script: Option<Script>,
+    /// Text set by the script, shown beneath the circle.
+    caption: Option<String>,
+    chapters: Chapters,
+    narrator: Narrator,
+    watermark: Watermark,
+    panel: ControlPanel,
+    paper: Paper,
+    draw_polar_grid: bool,
+    polar_spokes: SpokeSpacing,
+    background_texture: Option<wgpu::Texture>,
+    bookmarks: Vec<f32>,
+    session_start: std::time::SystemTime,
+
+    state: AppState,
 }
 
 impl Model {
-    pub fn new(app: &App) -> Self {
-        _ = app
+    pub fn new(app: &App, initial: InitialState) -> Self {
+        // escape is used to clear measurements, so quitting is handled in
+        // `key_pressed`
+        app.set_exit_on_escape(false);
+
+        let window_id = app
             .new_window()
             .size(800, 800)
             .view(view)
             .key_pressed(key_pressed)
+            .raw_event(raw_window_event)
+            .touch(touch)
             .build()
             .unwrap();
+        let panel = ControlPanel::new(&app.window(window_id).unwrap());
+
+        // the tour is shown on the first run, i.e. when there's no state file,
+        // and the changelog after an upgrade
+        let loaded = AppState::load();
+        let upgraded = loaded
+            .as_ref()
+            .is_some_and(|s| s.last_version.as_deref() != Some(VERSION));
+        let mut state = loaded.unwrap_or_default();
+
+        let mut tour = Tour::default();
+        if !state.tour_seen {
+            tour.start();
+        }
+
+        if state.last_version.as_deref() != Some(VERSION) {
+            state.last_version = Some(String::from(VERSION));
+
+            if let Err(e) = state.save() {
+                eprintln!("failed to save state: {e}");
+            }
+        }
+
+        let watermark = Watermark::from_state(&state);
+        let mut overlays = Overlays::registered();
+        let watermark_texture = watermark.image.as_ref().and_then(|path| {
+            let mut image = nannou::image::open(path)
+                .map_err(|e| eprintln!("failed to load watermark image: {e}"))
+                .ok()?
+                .to_rgba8();
+            // textures can't be tinted when drawn, so the opacity is baked
+            // into the image's alpha
+            for pixel in image.pixels_mut() {
+                pixel.0[3] = (f32::from(pixel.0[3]) * watermark.opacity) as u8;
+            }
+
+            Some(wgpu::Texture::from_image(
+                app,
+                &nannou::image::DynamicImage::ImageRgba8(image),
+            ))
+        });
+        if !watermark.is_empty() {
+            overlays.register(WatermarkOverlay {
+                watermark: watermark.clone(),
+                texture: watermark_texture,
+            });
+        }
+
+        let background_texture =
+            state.background_image.as_ref().and_then(|path| {
+                wgpu::Texture::from_path(app, path)
+                    .map_err(|e| {
+                        eprintln!("failed to load background image: {e}");
+                    })
+                    .ok()
+            });
+
+        let offscreen = state.export_resolution.and_then(|size| {
+            OffscreenRenderer::new(&app.window(window_id).unwrap(), size)
+                .map_err(|e| {
+                    eprintln!(
+                        "ignoring export_resolution: {e}, so images will be \
+                         exported at the window's size"
+                    );
+                })
+                .ok()
+        });
 
-        Self {
+        let mut model = Self {
             theta: 0.0,
             rate: DEFAULT_RATE,
 
             trig_values: TrigValues::default(),
-            trig_values_scaled: TrigValues::default(),
+            mode: Mode::default(),
+            hyperbolic: HyperbolicValues::default(),
+            epicycles: Epicycles::new(state.epicycles.clone()),
 
             is_running: true,
             draw_labels: true,
+            label_outlines: false,
             draw_values: true,
             draw_theta: true,
+            quadrant_arc: false,
+            draw_reference_angle: false,
+            reference_ray: None,
+            draw_clock: false,
+            draw_countdown: false,
+            draw_history: false,
+            draw_range_bars: false,
+            complex_plane: false,
+            draw_derivatives: false,
+            broadcast: false,
 
             theme: Theme::default(),
+            locale: Locale::default(),
+            angle_unit: AngleUnit::default(),
+            display: DisplaySettings::default(),
 
             selected_label: None,
 
             radius: UNIT_RADIUS,
 
             mouse_state: false,
-            value_rects: (0..6)
-                .map(|i| {
-                    let size = vec2(140.0, 50.0);
-                    let i = if i < 3 { i as f32 } else { i as f32 + 1.0 };
-                    Rect::from_xy_wh(vec2(390.0, i * 50.0 - 150.0), size)
-                })
-                .collect(),
+            node_drag: None,
+            value_slots: Vec::new(),
+            value_rects: Vec::new(),
+            value_groups: ValueGroups::default(),
+            pins: Pins::default(),
             visible: Visible::default(),
 
-            labels: Labels::new(),
+            layout: ScreenLayout::default(),
+            scene_mirror: state.scene_mirror,
+            labels: Labels::new(
+                state.label_fade_in_secs,
+                state.label_fade_out_secs,
+                state.label_fade_easing,
+            ),
+            clock: Clock::default(),
+            countdown: Countdown::default(),
+            measure: Measure::default(),
+            protractor: Protractor::default(),
+            de_moivre: DeMoivre::default(),
+            angle_sum: AngleSum::default(),
+            comparison: Comparison::default(),
+            time_scale: 1.0,
+            ramp: None,
+            calibration: Calibration::new(state.brightness, state.contrast),
+            identities: Identities::default(),
+            inverse: InverseMode::default(),
+            strobe: Strobe::default(),
+            snap: Snap::default(),
+            bullet_time: BulletTime::new(
+                state.bullet_time_degrees.to_radians(),
+                state.bullet_time_factor,
+            ),
+            touches: Touches::default(),
+            stats: Stats::default(),
+            draw_order: state.draw_order,
+            safe_area: state.safe_area,
+            adjusting_safe_area: false,
+            keymap: KeyMap::load(),
+            trace: Trace::default(),
+            keycast: KeyCast::default(),
+            #[cfg(feature = "inspector")]
+            inspector: crate::inspector::Inspector::default(),
+            taylor: Taylor::default(),
+            domain: DomainShading::default(),
+            sound: Sound::default(),
+            mic: Microphone::default(),
+            history: History::default(),
+            ghost: None,
+            flashcards: None,
+            animation: None,
+            recorder: None,
+            before: None,
+            offscreen,
+            pending_screenshot: None,
+            windowed_radius: None,
+            fit_radius: false,
+            finished_animation: None,
+            tour,
+            whats_new: upgraded,
+            whats_new_page: 0,
+            quit_prompt: false,
+            jump_pulse: 0.0,
+            // so the first frame is recorded
+            since_snapshot: SNAPSHOT_INTERVAL,
+            focus: Focus::default(),
+            events: Events::default(),
+            overlays,
+            script: Script::load(),
+            caption: None,
+            chapters: Chapters::default(),
+            narrator: Narrator::new(state.narration_command.as_deref()),
+            watermark,
+            panel,
+            paper: state.paper,
+            draw_polar_grid: state.polar_grid,
+            polar_spokes: state.polar_spokes,
+            background_texture,
+            bookmarks: Vec::new(),
+            session_start: std::time::SystemTime::now(),
+
+            state,
+        };
+
+        model.layout.mirror = model.state.mirror;
+
+        if let Some(snapshot) = take_snapshot() {
+            eprintln!("restoring the session from before the last crash");
+            model.restore(snapshot);
+        }
+        if initial.fullscreen {
+            model.set_fullscreen(app, true);
+        }
+        model.apply_initial_state(initial);
+
+        model
+    }
+
+    /// Applies the options given on the command line.
+    fn apply_initial_state(&mut self, initial: InitialState) {
+        if let Some(theta) = initial.theta {
+            self.theta = theta.rem_euclid(TAU);
+        }
+        if let Some(rate) = initial.rate {
+            self.rate = rate.max(0.0);
+        }
+        self.ramp = initial.ramp;
+        for label in initial.hidden {
+            match label {
+                Label::Sin => self.visible.sin = false,
+                Label::Cos => self.visible.cos = false,
+                Label::Tan => self.visible.tan = false,
+                Label::Cot => self.visible.cot = false,
+                Label::Sec => self.visible.sec = false,
+                Label::Csc => self.visible.csc = false,
+                Label::Theta | Label::Unit => {}
+            }
+        }
+        if initial.light {
+            self.theme = Theme::Light;
+        }
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            theta: self.theta,
+            rate: self.rate,
+            radius: self.radius,
+            is_running: self.is_running,
+            draw_labels: self.draw_labels,
+            draw_values: self.draw_values,
+            draw_theta: self.draw_theta,
+            elapsed_secs: self.clock.elapsed_secs(),
+            countdown_target: self.countdown.target,
+            visible: self.visible,
+            theme: self.theme,
+            locale: self.locale,
+            angle_unit: self.angle_unit,
+            bookmarks: self.bookmarks.clone(),
         }
     }
 
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.theta = snapshot.theta;
+        self.rate = snapshot.rate;
+        self.radius = snapshot.radius;
+        self.is_running = snapshot.is_running;
+        self.draw_labels = snapshot.draw_labels;
+        self.draw_values = snapshot.draw_values;
+        self.draw_theta = snapshot.draw_theta;
+        self.clock = Clock::with_elapsed(snapshot.elapsed_secs);
+        self.countdown.target = snapshot.countdown_target;
+        self.visible = snapshot.visible;
+        self.theme = snapshot.theme;
+        self.locale = snapshot.locale;
+        self.angle_unit = snapshot.angle_unit;
+        self.bookmarks = snapshot.bookmarks;
+    }
+
     // Update methods
 
     pub fn update(
         &mut self,
         delta_time: f32,
+        window: Rect,
         mouse_pos: Vec2,
         mouse_down: bool,
     ) {
+        // in real time, so presses stay readable whatever the time scale
+        self.keycast.update(delta_time);
+        let real_delta_time = delta_time;
+        let delta_time = delta_time * self.time_scale;
+        self.update_layout(window);
+        // undo the mirroring, scaling and translation applied in `view`
+        let mouse_pos = self.scene_mirror.apply(mouse_pos) / self.view_scale()
+            - self.layout.origin();
+        // and the mirroring, for what's picked on the figure
+        let figure_pos = self.layout.mirror.apply(mouse_pos);
+        // clicks on the control panel aren't passed through to the scene
+        let mouse_down = mouse_down && !self.panel.wants_pointer();
+        let mouse_clicked = mouse_down && !self.mouse_state;
+        if mouse_clicked {
+            self.keycast.push(String::from("Click"));
+        }
+        self.update_flashcards();
+        self.update_animation();
+        self.update_node_drag(figure_pos, mouse_down, mouse_clicked);
+        // a click which grabs the node isn't passed on
+        let mouse_clicked = mouse_clicked && self.node_drag.is_none();
+        self.update_mic(delta_time);
         self.update_theta(delta_time);
+        self.snap.update(delta_time);
+        self.strobe.update(
+            delta_time,
+            self.theta,
+            if self.is_running { self.rate } else { 0.0 },
+        );
+        self.update_history(delta_time, mouse_pos, mouse_down, mouse_clicked);
+        self.events.update(
+            self.theta,
+            self.is_running && !self.history.is_scrubbing(),
+            self.draw_countdown.then_some(self.countdown.target),
+        );
+        if self.countdown.auto_pause
+            && self.events.current().contains(&Event::CountdownReached)
+        {
+            self.theta = self.countdown.target;
+            self.is_running = false;
+        }
+        self.update_script(delta_time);
         self.compute_trig_values();
+        // in real time, so subtitles line up with a screen recording
+        self.update_recorder(real_delta_time);
+        self.trace.update(self.theta);
         self.update_label_positions();
+        self.update_measure(figure_pos, mouse_clicked);
+        self.update_protractor(figure_pos, mouse_down, mouse_clicked);
         self.update_value_rects(mouse_pos, mouse_down);
+        self.focus.retain(&self.focusable());
         self.labels.update(delta_time);
+        self.clock.update(
+            delta_time,
+            self.is_running && !self.history.is_scrubbing(),
+        );
+        self.sound.update(pitch_hz(self.rate), self.is_running);
+        self.angle_sum.update(delta_time, self.is_running);
+        self.jump_pulse = (self.jump_pulse - delta_time).max(0.0);
+        // the ghost pauses along with the run it's compared against
+        if let Some(ghost) = self.ghost.as_mut().filter(|_| self.is_running) {
+            ghost.update(delta_time);
+        }
+        let ctx = self.overlay_context();
+        self.overlays
+            .update(&ctx, self.events.current(), delta_time);
+        self.since_snapshot += real_delta_time;
+        if self.since_snapshot >= SNAPSHOT_INTERVAL {
+            self.since_snapshot = 0.0;
+            self.snapshot().record();
+        }
+        #[cfg(feature = "inspector")]
+        self.update_inspector(mouse_pos, figure_pos);
+    }
+
+    /// Lists the state shown by the inspector overlay, as of this frame.
+    /// `figure_pos` is the mouse's position on the figure, which differs from
+    /// `mouse_pos` if the figure is mirrored.
+    #[cfg(feature = "inspector")]
+    fn update_inspector(&mut self, mouse_pos: Vec2, figure_pos: Vec2) {
+        if !self.inspector.is_visible() {
+            return;
+        }
+
+        let flag = |on: bool, name: &str| {
+            if on {
+                name.to_string()
+            }
+            else {
+                "-".repeat(name.len())
+            }
+        };
+        let Visible { sin, cos, tan, cot, sec, csc } = self.visible;
+        let node = self.node();
+        let hovered = self
+            .value_rects
+            .iter()
+            .find(|(_, rect)| rect.contains(mouse_pos))
+            .map_or_else(
+                || String::from("none"),
+                |(line, _)| format!("{line:?}"),
+            );
+
+        let mut lines = vec![
+            format!("theta {:.4}  rate {:.4}", self.theta, self.rate),
+            format!(
+                "running {}  time scale {}  mode {:?}",
+                self.is_running, self.time_scale, self.mode
+            ),
+            format!(
+                "visible {} {} {} {} {} {}",
+                flag(sin, "sin"),
+                flag(cos, "cos"),
+                flag(tan, "tan"),
+                flag(cot, "cot"),
+                flag(sec, "sec"),
+                flag(csc, "csc"),
+            ),
+            format!(
+                "mouse ({:.1}, {:.1})  node {:.1} away{}",
+                mouse_pos.x,
+                mouse_pos.y,
+                figure_pos.distance(node),
+                if self.node_drag.is_some() {
+                    ", dragging"
+                }
+                else {
+                    ""
+                },
+            ),
+            format!("value row {hovered}"),
+        ];
+        for label in Label::ALL {
+            let (fade, fading_out) = self.labels.fade_state(label);
+            let pos = self.labels.get_position(label);
+
+            lines.push(format!(
+                "{:<5} opacity {:.3}  fade {:.3}{}  side {:+.2}  at ({:.0}, {:.0})",
+                label.name(),
+                self.labels.get_opacity(label),
+                fade,
+                if fading_out { " out" } else { "    " },
+                self.labels.side(label),
+                pos.x,
+                pos.y,
+            ));
+        }
+
+        self.inspector.set_lines(lines);
+    }
+
+    fn update_layout(&mut self, window: Rect) {
+        let [top, right, bottom, left] = self.safe_area;
+        let window = window
+            .pad_top(window.h() * top)
+            .pad_right(window.w() * right)
+            .pad_bottom(window.h() * bottom)
+            .pad_left(window.w() * left);
+
+        let resized = window.wh() != self.layout.window().wh();
+        self.layout = ScreenLayout::new(
+            window,
+            self.layout.arrangement,
+            self.layout.mirror,
+        );
+
+        if self.fit_radius && resized {
+            self.radius = self.layout.fitted_radius();
+        }
+
+        let lines = self.value_groups.lines(self.complex_plane);
+        let slots = self.layout.value_slots(&lines);
+        self.value_slots = lines.into_iter().zip(slots).collect();
+        self.value_rects = self
+            .value_slots
+            .iter()
+            .filter(|(line, _)| line.is_interactive())
+            .map(|&(line, slot)| (line, value_rect(slot)))
+            .collect();
     }
 
-    fn update_value_rects(&mut self, mut mouse_pos: Vec2, mouse_down: bool) {
-        // need to accommodate for translation
-        mouse_pos.x += 120.0;
+    fn update_value_rects(&mut self, mouse_pos: Vec2, mouse_down: bool) {
         if mouse_down && self.mouse_state {
+            self.pins.drag_to(mouse_pos);
             return;
         }
         else if !mouse_down {
             if self.mouse_state {
                 self.mouse_state = false;
+                if let Some(label) = self.pins.end_drag() {
+                    self.drop_value(label, mouse_pos);
+                }
             }
             return;
         }
 
-        let idx = self
+        let line = self
             .value_rects
             .iter()
-            .enumerate()
-            .find_map(|(i, &rect)| rect.contains(mouse_pos).then_some(i));
-
-        if let Some(i) = idx {
-            match i {
-                5 => self.visible.sin = !self.visible.sin,
-                4 => self.visible.cos = !self.visible.cos,
-                3 => self.visible.tan = !self.visible.tan,
-                2 => self.visible.cot = !self.visible.cot,
-                1 => self.visible.sec = !self.visible.sec,
-                0 => self.visible.csc = !self.visible.csc,
-                _ => {}
+            .find_map(|&(line, rect)| rect.contains(mouse_pos).then_some(line));
+        let pinned = self
+            .pins
+            .iter()
+            .find(|&&pin| self.pin_rect(pin).contains(mouse_pos))
+            .map(|pin| pin.label);
+
+        // values are toggled when they're released, as they can also be
+        // dragged out to pin them
+        match line {
+            Some(ValueLine::Value(label)) => {
+                self.pins.start_drag(label, mouse_pos);
+            }
+            Some(line) => self.activate_value_line(line),
+            None => {
+                if let Some(label) = pinned {
+                    self.pins.unpin(label);
+                }
             }
         }
 
         self.mouse_state = true;
     }
 
-    fn update_label_positions(&mut self) {
-        if self.visible.sin {
-            // sin
-            self.labels.update_position(
-                Label::Sin,
-                vec2(
-                    self.trig_values_scaled.cos + 22.0,
-                    self.trig_values_scaled.sin * 0.5,
-                ),
-            );
+    /// Handles a value from the panel being released at `pos`: it's toggled
+    /// if it's still over its row, or pinned there if it's over the circle.
+    fn drop_value(&mut self, label: Label, pos: Vec2) {
+        if value_rect_contains(&self.value_rects, label, pos) {
+            self.toggle_visible(label);
         }
-        else {
-            self.labels
-                .update_position(Label::Sin, vec2(1000.0, 1000.0));
+        else if self.layout.circle().contains(pos) {
+            self.pins.pin(label, pos - self.label_position(label));
         }
+    }
 
-        // cos
-        if self.visible.cos {
-            self.labels.update_position(
-                Label::Cos,
-                vec2(self.trig_values_scaled.cos * 0.5, 15.0),
-            );
-        }
-        else {
-            self.labels
-                .update_position(Label::Cos, vec2(1000.0, 1000.0));
-        }
+    /// Where `label` is drawn, with the figure's mirroring, for what's drawn
+    /// beside it.
+    fn label_position(&self, label: Label) -> Vec2 {
+        self.layout.mirror.apply(self.labels.get_position(label))
+    }
 
-        // tan
-        if self.visible.tan {
-            self.labels.update_position(
-                Label::Tan,
-                vec2(self.radius + 23.0, self.trig_values_scaled.tan * 0.5),
-            );
-        }
-        else {
-            self.labels
-                .update_position(Label::Tan, vec2(1000.0, 1000.0));
-        }
+    /// The area of a pinned value's tag.
+    fn pin_rect(&self, pin: Pin) -> Rect {
+        Rect::from_xy_wh(
+            self.label_position(pin.label) + pin.offset,
+            tag_size(),
+        )
+    }
 
-        // cot
-        if self.visible.cot {
-            let cot_x_dir = if self.theta >= PI { -1.0 } else { 1.0 };
-            self.labels.update_position(
-                Label::Cot,
-                vec2(
-                    self.trig_values_scaled.cos * 0.5
-                        + (cot_x_dir * self.trig_values.cos * 20.0),
-                    (self.trig_values_scaled.sin + self.trig_values_scaled.csc)
-                        * 0.5
-                        + 12.0
-                        + (self.trig_values.sin.abs() * 8.0),
-                ),
-            );
-        }
-        else {
-            self.labels
-                .update_position(Label::Cot, vec2(1000.0, 1000.0));
+    /// Toggles the function shown on `line` of the value panel, or collapses
+    /// or expands its group if it's a heading.
+    fn activate_value_line(&mut self, line: ValueLine) {
+        match line {
+            ValueLine::Value(label) => self.toggle_visible(label),
+            ValueLine::Heading(group) => self.value_groups.toggle(group),
+            _ => {}
         }
+    }
 
-        // sec
-        if self.visible.sec {
-            let sec_offset =
-                self.trig_values.tan.signum() * self.trig_values.sin.abs();
-            self.labels.update_position(
-                Label::Sec,
-                vec2(
-                    self.radius * 0.5
-                        - (self.trig_values.tan * 5.0)
-                        - sec_offset * 10.0,
-                    self.trig_values_scaled.tan * 0.5 + 18.0,
-                ),
-            );
-        }
-        else {
-            self.labels
-                .update_position(Label::Sec, vec2(1000.0, 1000.0));
+    /// Toggles the visibility of `label`'s function.
+    fn toggle_visible(&mut self, label: Label) {
+        match label {
+            Label::Sin => self.visible.sin = !self.visible.sin,
+            Label::Cos => self.visible.cos = !self.visible.cos,
+            Label::Tan => self.visible.tan = !self.visible.tan,
+            Label::Cot => self.visible.cot = !self.visible.cot,
+            Label::Sec => self.visible.sec = !self.visible.sec,
+            Label::Csc => self.visible.csc = !self.visible.csc,
+            Label::Theta | Label::Unit => {}
         }
+    }
 
-        // csc
-        if self.visible.csc {
-            self.labels.update_position(
-                Label::Csc,
-                vec2(-25.0, self.trig_values_scaled.csc * 0.5),
-            );
+    /// The position of `line` in the value panel, if it's shown.
+    fn value_slot(&self, line: ValueLine) -> Option<Vec2> {
+        self.value_slots
+            .iter()
+            .find_map(|&(l, slot)| (l == line).then_some(slot))
+    }
+
+    /// The elements which can currently be focused, in tab order.
+    fn focusable(&self) -> Vec<Focusable> {
+        let mut available = Vec::new();
+
+        if self.draw_values {
+            available
+                .extend((0..self.value_rects.len()).map(Focusable::ValueRow));
         }
-        else {
-            self.labels
-                .update_position(Label::Csc, vec2(1000.0, 1000.0));
+        if self.draw_history {
+            available.push(Focusable::HistorySlider);
+        }
+        if self.protractor.is_visible() {
+            available.push(Focusable::Protractor);
         }
 
-        // theta
-        let (th_y, th_x) = (self.theta * 0.5).sin_cos();
-        self.labels.update_position(
-            Label::Theta,
-            vec2(th_x * self.radius * 0.93, th_y * self.radius * 0.93),
-        );
-
-        // unit
-        let (un_y, un_x) = (self.theta - PI * 0.5).sin_cos();
-        self.labels.update_position(
-            Label::Unit,
-            vec2(
-                self.trig_values_scaled.cos * 0.5 + 15.0 * un_x,
-                self.trig_values_scaled.sin * 0.5 + 15.0 * un_y,
-            ),
-        );
+        available
     }
 
-    fn update_theta(&mut self, delta_time: f32) {
-        if !self.is_running {
+    /// Lets the node be dragged around the circle to set θ, pausing the
+    /// motion until it's released.
+    fn update_node_drag(
+        &mut self,
+        mouse_pos: Vec2,
+        mouse_down: bool,
+        mouse_clicked: bool,
+    ) {
+        if !mouse_down {
+            if let Some(was_running) = self.node_drag.take() {
+                self.is_running = was_running;
+            }
             return;
         }
 
-        self.theta += self.rate * delta_time;
+        if self.node_drag.is_none() {
+            let (y, x) = self.theta.sin_cos();
+            let node = vec2(x, y) * self.radius;
 
-        if self.theta >= TAU {
-            self.theta -= TAU;
-        }
-    }
+            if !mouse_clicked || mouse_pos.distance(node) > NODE_PICK_DISTANCE {
+                return;
+            }
 
-    fn compute_trig_values(&mut self) {
-        let TrigValues { sin, cos, tan, cot, sec, csc } = &mut self.trig_values;
+            self.node_drag = Some(self.is_running);
+        }
 
-        *sin = self.theta.sin();
-        *cos = self.theta.cos();
-        *tan = self.theta.tan();
-        *cot = tan.recip();
-        *sec = cos.recip();
-        *csc = sin.recip();
+        self.is_running = false;
+        self.theta = self
+            .snap
+            .apply(mouse_pos.y.atan2(mouse_pos.x).rem_euclid(TAU));
+    }
 
-        self.trig_values_scaled = self.trig_values * self.radius;
+    fn update_measure(&mut self, mouse_pos: Vec2, mouse_clicked: bool) {
+        if !self.measure.is_active() || !mouse_clicked {
+            return;
+        }
 
-        // some values can be inf, so this is needed to prevent a geometry error!
-        self.trig_values.clamp_inf();
-        self.trig_values_scaled.clamp_inf();
+        if (mouse_pos.length() - self.radius).abs() <= MEASURE_PICK_DISTANCE {
+            self.measure.pick(mouse_pos.y.atan2(mouse_pos.x));
+        }
     }
 
-    // Setting methods
+    fn update_flashcards(&mut self) {
+        let Some((export, saved)) = &mut self.flashcards
+        else {
+            return;
+        };
 
-    pub fn increment_rate(&mut self) {
-        self.rate += RATE_INCREMENT;
-    }
+        if let Some(card) = export.next_card() {
+            self.theta = card.twelfths as f32 * PI / 12.0;
+            self.is_running = false;
+            self.draw_values = card.show_values;
+        }
+        else {
+            println!("exported flashcards to {}", export.dir().display());
 
-    pub fn decrement_rate(&mut self) {
-        self.rate = f32::max(0.0, self.rate - RATE_INCREMENT);
+            let saved = saved.clone();
+            self.flashcards = None;
+            self.restore(saved);
+        }
     }
 
-    pub fn toggle_running(&mut self) {
-        self.is_running = !self.is_running;
-    }
+    fn update_animation(&mut self) {
+        let Some((export, saved)) = &mut self.animation
+        else {
+            return;
+        };
 
-    pub fn toggle_labels(&mut self) {
-        self.draw_labels = !self.draw_labels;
-    }
+        if let Some(theta) = export.next_frame() {
+            self.theta = theta;
+            self.is_running = false;
+        }
+        else {
+            self.finished_animation = Some(export.dir().to_path_buf());
 
-    pub fn toggle_values(&mut self) {
-        self.draw_values = !self.draw_values;
+            let saved = saved.clone();
+            self.animation = None;
+            self.restore(saved);
+        }
     }
 
-    pub fn toggle_theta(&mut self) {
-        self.draw_theta = !self.draw_theta;
-    }
+    fn update_recorder(&mut self, delta_time: f32) {
+        let Some(recorder) = &mut self.recorder
+        else {
+            return;
+        };
 
-    pub fn reset_theta(&mut self) {
-        self.theta = 0.0;
+        // unclamped, so that undefined values are logged as undefined
+        if let Err(e) = recorder.record(
+            delta_time,
+            self.theta,
+            &TrigValues::new(self.theta),
+            self.caption.as_deref(),
+        ) {
+            eprintln!("stopped logging data: {e}");
+            self.recorder = None;
+        }
     }
 
-    pub fn reset_rate(&mut self) {
-        self.rate = DEFAULT_RATE;
-    }
+    fn update_mic(&mut self, delta_time: f32) {
+        let Some(reading) = self.mic.update(delta_time)
+        else {
+            return;
+        };
 
-    pub fn increase_scale(&mut self) {
-        self.radius += 10.0;
+        match self.mic.mode {
+            MicMode::Rate => self.set_rate(rate_for_pitch(reading.hz)),
+            MicMode::Amplitude => {
+                self.radius =
+                    UNIT_RADIUS * (reading.level * 5.0).clamp(0.1, 1.5);
+            }
+            // θ is set directly from the timecode, as if the circle had been
+            // turning at the current rate since 00:00:00:00
+            MicMode::Timecode => {
+                if let Some(timecode) = reading.timecode {
+                    self.theta =
+                        (timecode.to_secs() * self.rate).rem_euclid(TAU);
+                    self.is_running = false;
+                }
+            }
+            MicMode::Off => {}
+        }
     }
 
-    pub fn decrease_scale(&mut self) {
-        self.radius -= 10.0;
-    }
+    fn update_history(
+        &mut self,
+        delta_time: f32,
+        mouse_pos: Vec2,
+        mouse_down: bool,
+        mouse_clicked: bool,
+    ) {
+        let slider = self.history_slider_rect();
+        let is_focused = self.focus.is_focused(Focusable::HistorySlider);
 
-    pub fn reset_scale(&mut self) {
-        self.radius = UNIT_RADIUS;
-    }
+        if (!mouse_down && !is_focused) || !self.draw_history {
+            self.history.end_scrub();
+        }
+        else if self.history.is_scrubbing()
+            || (mouse_clicked && slider.contains(mouse_pos))
+        {
+            // the position is held while scrubbing from the keyboard
+            let t = if mouse_down {
+                (mouse_pos.x - slider.left()) / slider.w()
+            }
+            else {
+                self.history.position()
+            };
 
-    pub fn toggle_theme(&mut self) {
-        self.theme.toggle_light_dark();
+            if let Some(sample) = self.history.scrub_to(t) {
+                self.theta = sample.theta;
+                self.set_rate(sample.rate);
+                self.is_running = sample.is_running;
+            }
+        }
+
+        self.history
+            .record(delta_time, self.theta, self.rate, self.is_running);
     }
 
-    pub fn bg_color(&self) -> Rgb {
-        if self.theme.is_dark() {
-            Rgb::new(0.0, 0.0, 0.0)
+    fn update_protractor(
+        &mut self,
+        mouse_pos: Vec2,
+        mouse_down: bool,
+        mouse_clicked: bool,
+    ) {
+        let angle = mouse_pos.y.atan2(mouse_pos.x);
+
+        if !mouse_down {
+            self.protractor.end_drag();
         }
-        else {
-            Rgb::new(0.9, 0.9, 0.9)
+        else if self.protractor.is_dragging() {
+            self.protractor.drag_to(angle);
+        }
+        else if mouse_clicked
+            && self.protractor.is_visible()
+            && !self.measure.is_active()
+        {
+            let dist = mouse_pos.length();
+
+            if dist <= self.radius && dist >= self.radius * 0.7 {
+                self.protractor.begin_drag(angle);
+            }
         }
     }
 
-    // Draw methods
+    fn update_label_positions(&mut self) {
+        match self.mode {
+            Mode::Circular => {}
+            Mode::Hyperbolic => {
+                self.update_hyperbolic_label_positions();
+                return;
+            }
+            // the epicycles aren't labelled
+            Mode::Epicycles => {
+                for label in [
+                    Label::Sin,
+                    Label::Cos,
+                    Label::Tan,
+                    Label::Cot,
+                    Label::Sec,
+                    Label::Csc,
+                    Label::Theta,
+                    Label::Unit,
+                ] {
+                    self.labels.update_position(label, vec2(1000.0, 1000.0));
+                }
+                return;
+            }
+        }
 
-    pub fn draw_bg_lines(&self, draw: &Draw) {
-        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        // under the strobe, the lines are drawn at the latest flash
+        let theta =
+            self.strobe.latest().unwrap_or(self.theta) - self.ray_angle();
+        let view = self.measured_view();
 
-        draw.line()
-            .stroke_weight(STROKE_WEIGHT - 1.0)
+        for label in Label::ALL {
+            let Some(function) = label.function()
+            else {
+                continue;
+            };
+
+            self.labels.flip_to(label, function.label_side(theta));
+
+            let pos = if self.visible.shows(function) {
+                view.place(
+                    function.label_placement(theta, self.labels.side(label)),
+                )
+            }
+            else {
+                vec2(1000.0, 1000.0)
+            };
+            self.labels.update_position(label, pos);
+        }
+
+        // theta, just inside the circle halfway round its arc
+        let (th_y, th_x) = (self.measured_theta() * 0.5).sin_cos();
+        self.labels.update_position(
+            Label::Theta,
+            view.to_pixels(vec2(th_x, th_y) * 0.93),
+        );
+
+        // unit, over the middle of the radius
+        let (un_y, un_x) = (self.measured_theta() - PI * 0.5).sin_cos();
+        self.labels.update_position(
+            Label::Unit,
+            view.place(Placement::new(
+                vec2(self.trig_values.cos, self.trig_values.sin) * 0.5,
+                vec2(un_x, un_y) * AXIS_LABEL_GAP,
+            )),
+        );
+    }
+
+    /// Places the sinh, cosh and tanh labels beside their lines, using the
+    /// labels of sin, cos and tan, and hides the rest.
+    fn update_hyperbolic_label_positions(&mut self) {
+        let view = self.hyperbola_view();
+        let HyperbolicValues { sinh, cosh, tanh, .. } = self.hyperbolic;
+        let hidden = vec2(1000.0, 1000.0);
+        let shown =
+            |visible: bool, pos: Vec2| if visible { pos } else { hidden };
+
+        // cosh sits on the other side of the axis from the shaded area
+        self.labels.flip_to(Label::Cos, -sinh);
+
+        self.labels.update_position(
+            Label::Sin,
+            shown(
+                self.visible.sin,
+                view.place(Placement::new(
+                    vec2(cosh, sinh * 0.5),
+                    vec2(HYPERBOLIC_LABEL_GAP, 0.0),
+                )),
+            ),
+        );
+        self.labels.update_position(
+            Label::Cos,
+            shown(
+                self.visible.cos,
+                view.place(Placement::new(
+                    vec2(cosh * 0.5, 0.0),
+                    vec2(0.0, AXIS_LABEL_GAP * self.labels.side(Label::Cos)),
+                )),
+            ),
+        );
+        self.labels.update_position(
+            Label::Tan,
+            shown(
+                self.visible.tan,
+                view.place(Placement::new(
+                    vec2(1.0, tanh * 0.5),
+                    vec2(-HYPERBOLIC_LABEL_GAP, 0.0),
+                )),
+            ),
+        );
+
+        for label in [
+            Label::Cot,
+            Label::Sec,
+            Label::Csc,
+            Label::Theta,
+            Label::Unit,
+        ] {
+            self.labels.update_position(label, hidden);
+        }
+    }
+
+    /// How fast θ is turning in radians per second of real time: the rate
+    /// as slowed by bullet time and scaled by the time scale, or `0` while
+    /// paused.
+    fn angular_velocity(&self) -> f32 {
+        if !self.is_running || self.history.is_scrubbing() {
+            return 0.0;
+        }
+
+        self.rate
+            * self.bullet_time.rate_scale(self.theta, &self.bookmarks)
+            * self.time_scale
+    }
+
+    fn update_theta(&mut self, delta_time: f32) {
+        if !self.is_running {
+            return;
+        }
+
+        if let Some(ramp) = &mut self.ramp {
+            self.rate = ramp.advance(delta_time, self.rate);
+
+            if ramp.is_finished() {
+                self.ramp = None;
+            }
+        }
+
+        self.theta += self.rate
+            * self.bullet_time.rate_scale(self.theta, &self.bookmarks)
+            * delta_time;
+
+        if self.theta >= TAU {
+            self.theta -= TAU;
+        }
+    }
+
+    fn update_script(&mut self, delta_time: f32) {
+        let Some(script) = &mut self.script
+        else {
+            return;
+        };
+
+        let mut state = ScriptState {
+            theta: self.theta,
+            rate: self.rate,
+            is_running: self.is_running,
+            caption: self.caption.take(),
+            time_scale: self.time_scale,
+            ramping: self.ramp.is_some(),
+            ramp: None,
+        };
+
+        if let Err(e) =
+            script.run(&mut state, self.events.current(), delta_time)
+        {
+            eprintln!("stopped {SCRIPT_FILE}: {e}");
+            self.script = None;
+            return;
+        }
+
+        self.chapters
+            .update(script.chapters(), script.time(), delta_time);
+        self.theta = state.theta.rem_euclid(TAU);
+        // the script sees the ramped rate, so it's only taken over if the
+        // script changed it
+        if state.rate != self.rate {
+            self.set_rate(state.rate);
+        }
+        self.is_running = state.is_running;
+        self.caption = state.caption;
+        if state.ramp.is_some() {
+            self.ramp = state.ramp;
+        }
+        self.time_scale =
+            state.time_scale.clamp(MIN_TIME_SCALE, MAX_TIME_SCALE);
+        self.narrator.update(self.caption.as_deref());
+    }
+
+    fn compute_trig_values(&mut self) {
+        // under the strobe, the circle is only seen at each flash
+        let theta = self.strobe.latest().unwrap_or(self.theta);
+        self.trig_values = TrigValues::new(theta - self.ray_angle());
+        self.hyperbolic = HyperbolicValues::from_theta(theta);
+
+        // some values can be inf, so this is needed to prevent a geometry error!
+        self.trig_values.clamp_inf();
+    }
+
+    // Setting methods
+
+    /// Sets the rate, cancelling any ramp so that it isn't overwritten on the
+    /// next frame.
+    fn set_rate(&mut self, rate: f32) {
+        self.ramp = None;
+        self.rate = rate;
+    }
+
+    pub fn increment_rate(&mut self) {
+        self.set_rate(self.rate + RATE_INCREMENT);
+    }
+
+    pub fn decrement_rate(&mut self) {
+        self.set_rate(f32::max(0.0, self.rate - RATE_INCREMENT));
+    }
+
+    pub fn toggle_running(&mut self) {
+        self.is_running = !self.is_running;
+    }
+
+    pub fn toggle_labels(&mut self) {
+        self.draw_labels = !self.draw_labels;
+    }
+
+    pub fn toggle_label_outlines(&mut self) {
+        self.label_outlines = !self.label_outlines;
+    }
+
+    pub fn toggle_values(&mut self) {
+        self.draw_values = !self.draw_values;
+    }
+
+    pub fn toggle_range_bars(&mut self) {
+        self.draw_range_bars = !self.draw_range_bars;
+    }
+
+    pub fn toggle_theta(&mut self) {
+        self.draw_theta = !self.draw_theta;
+    }
+
+    pub fn toggle_reference_angle(&mut self) {
+        self.draw_reference_angle = !self.draw_reference_angle;
+    }
+
+    /// Measures angles from the point's current direction, or stops if
+    /// they already are.
+    pub fn toggle_reference_ray(&mut self) {
+        self.reference_ray = match self.reference_ray {
+            Some(_) => None,
+            None => Some(self.theta),
+        };
+    }
+
+    /// The direction angles are measured from: the reference ray if there is
+    /// one, or else the x-axis.
+    fn ray_angle(&self) -> f32 {
+        self.reference_ray.unwrap_or(0.0)
+    }
+
+    /// θ as it's measured, counterclockwise from the reference ray if there
+    /// is one.
+    fn measured_theta(&self) -> f32 {
+        self.reference_ray
+            .map_or(self.theta, |ray| (self.theta - ray).rem_euclid(TAU))
+    }
+
+    /// The name of the angle given by [`Model::measured_theta`].
+    fn measured_name(&self) -> &'static str {
+        if self.reference_ray.is_some() {
+            "θ − θ₀"
+        }
+        else {
+            "θ"
+        }
+    }
+
+    pub fn toggle_quadrant_arc(&mut self) {
+        self.quadrant_arc = !self.quadrant_arc;
+    }
+
+    pub fn toggle_clock(&mut self) {
+        self.draw_clock = !self.draw_clock;
+    }
+
+    pub fn toggle_de_moivre(&mut self) {
+        self.de_moivre.toggle();
+    }
+
+    /// Adds a circle in epicycles mode, raises the degree of the Taylor
+    /// polynomials if they're shown, or steps the power of De Moivre's
+    /// theorem forward otherwise.
+    pub fn next_power(&mut self) {
+        if self.mode == Mode::Epicycles {
+            self.epicycles.change_count(1);
+        }
+        else if self.taylor.is_active() {
+            self.taylor.change_degree(1);
+        }
+        else {
+            self.step_de_moivre_forward();
+        }
+    }
+
+    /// Removes a circle in epicycles mode, lowers the degree of the Taylor
+    /// polynomials if they're shown, or steps the power of De Moivre's
+    /// theorem back otherwise.
+    pub fn previous_power(&mut self) {
+        if self.mode == Mode::Epicycles {
+            self.epicycles.change_count(-1);
+        }
+        else if self.taylor.is_active() {
+            self.taylor.change_degree(-1);
+        }
+        else {
+            self.step_de_moivre_back();
+        }
+    }
+
+    pub fn step_de_moivre_forward(&mut self) {
+        if self.de_moivre.is_active() {
+            self.de_moivre.step_forward();
+        }
+    }
+
+    pub fn step_de_moivre_back(&mut self) {
+        if self.de_moivre.is_active() {
+            self.de_moivre.step_back();
+        }
+    }
+
+    pub fn cycle_domain_shading(&mut self) {
+        self.domain.cycle();
+    }
+
+    pub fn toggle_angle_sum(&mut self) {
+        self.angle_sum.toggle();
+    }
+
+    /// Whether φ is on screen, either in angle addition mode or as β in the
+    /// identities.
+    fn shows_phi(&self) -> bool {
+        self.angle_sum.is_active() || self.identities.is_active()
+    }
+
+    /// Moves φ forward, if it's shown.
+    pub fn increment_phi(&mut self) {
+        if self.shows_phi() {
+            self.angle_sum.shift_phi(ANGLE_SUM_INCREMENT);
+        }
+    }
+
+    /// Moves φ back, if it's shown.
+    pub fn decrement_phi(&mut self) {
+        if self.shows_phi() {
+            self.angle_sum.shift_phi(-ANGLE_SUM_INCREMENT);
+        }
+    }
+
+    pub fn increment_phi_rate(&mut self) {
+        self.angle_sum.shift_rate(RATE_INCREMENT);
+    }
+
+    pub fn decrement_phi_rate(&mut self) {
+        self.angle_sum.shift_rate(-RATE_INCREMENT);
+    }
+
+    pub fn cycle_inverse_mode(&mut self) {
+        self.inverse.cycle();
+    }
+
+    /// Turning two fingers changes the rate, and tapping with two toggles
+    /// the motion.
+    pub fn handle_touch(&mut self, event: TouchEvent, time: f32) {
+        match self.touches.handle(event, time) {
+            Some(Gesture::Rotate(angle)) => {
+                // turning the way the point turns on screen speeds it up,
+                // which is clockwise if the figure's mirrored one way
+                let scale =
+                    self.layout.mirror.scale() * self.scene_mirror.scale();
+                let angle = angle * scale.x * scale.y;
+                self.set_rate(
+                    (self.rate + angle * TOUCH_RATE_PER_RADIAN).max(0.0),
+                );
+            }
+            Some(Gesture::Tap) => self.toggle_running(),
+            None => {}
+        }
+    }
+
+    pub fn toggle_stats(&mut self) {
+        self.stats.toggle();
+    }
+
+    /// Records the length of a frame and how long it took to update, in
+    /// seconds.
+    pub fn record_update_time(&mut self, frame_secs: f32, update_secs: f32) {
+        self.stats.record_update(frame_secs, update_secs);
+    }
+
+    pub fn toggle_snap(&mut self) {
+        self.snap.toggle();
+    }
+
+    /// Multiplies the speed everything runs at by `factor`.
+    pub fn scale_time(&mut self, factor: f32) {
+        self.time_scale =
+            (self.time_scale * factor).clamp(MIN_TIME_SCALE, MAX_TIME_SCALE);
+    }
+
+    pub fn toggle_calibration(&mut self) {
+        if self.calibration.is_shown() {
+            self.close_calibration();
+        }
+        else {
+            self.calibration.toggle();
+        }
+    }
+
+    /// Hides the calibration screen, saving any change to the brightness or
+    /// contrast, and returns whether it was shown.
+    pub fn close_calibration(&mut self) -> bool {
+        if !self.calibration.close() {
+            return false;
+        }
+
+        let (brightness, contrast) =
+            (self.calibration.brightness(), self.calibration.contrast());
+        if self.state.brightness != brightness
+            || self.state.contrast != contrast
+        {
+            self.state.brightness = brightness;
+            self.state.contrast = contrast;
+
+            if let Err(e) = self.state.save() {
+                eprintln!("failed to save state: {e}");
+            }
+        }
+
+        true
+    }
+
+    /// Adjusts the brightness or contrast if the calibration screen is
+    /// shown, returning whether `key` did so.
+    pub fn calibrate(&mut self, key: Key) -> bool {
+        self.calibration.is_shown() && self.calibration.key_pressed(key)
+    }
+
+    pub fn toggle_identities(&mut self) {
+        self.identities.toggle();
+    }
+
+    pub fn toggle_comparison(&mut self) {
+        self.comparison.toggle(self.theta);
+    }
+
+    pub fn shift_comparison(&mut self, delta: f32) {
+        self.comparison.shift_psi(delta);
+    }
+
+    pub fn toggle_hyperbolic(&mut self) {
+        self.mode.toggle(Mode::Hyperbolic);
+    }
+
+    pub fn toggle_epicycles(&mut self) {
+        self.mode.toggle(Mode::Epicycles);
+    }
+
+    pub fn cycle_waveform(&mut self) {
+        self.epicycles.cycle_waveform();
+    }
+
+    pub fn toggle_bullet_time(&mut self) {
+        self.bullet_time.toggle();
+    }
+
+    pub fn toggle_strobe(&mut self) {
+        let rate = self.strobe.toggle(self.rate);
+        self.set_rate(rate);
+    }
+
+    pub fn toggle_trace(&mut self) {
+        self.trace.toggle();
+    }
+
+    pub fn toggle_integral(&mut self) {
+        self.trace.toggle_integral();
+    }
+
+    /// Toggles the Taylor polynomials, showing the traces they're drawn
+    /// over if they're hidden.
+    pub fn toggle_taylor(&mut self) {
+        self.taylor.toggle();
+
+        if self.taylor.is_active() && !self.trace.is_active() {
+            self.trace.toggle();
+        }
+    }
+
+    pub fn toggle_key_cast(&mut self) {
+        self.keycast.toggle();
+    }
+
+    /// Shows `key` in the corner if key presses are being shown, with the
+    /// name of the action it's bound to.
+    pub fn cast_key(&mut self, key: Key, mods: ModifiersState) {
+        if !self.keycast.is_active() {
+            return;
+        }
+        let Some(keys) = describe_keys(key, mods)
+        else {
+            return;
+        };
+
+        let press = match self.keymap.action(key, mods).and_then(action_name) {
+            Some(name) => format!("{keys}   {}", name.replace('_', " ")),
+            None => keys,
+        };
+        self.keycast.push(press);
+    }
+
+    pub fn toggle_derivatives(&mut self) {
+        self.draw_derivatives = !self.draw_derivatives;
+    }
+
+    pub fn toggle_complex_plane(&mut self) {
+        self.complex_plane = !self.complex_plane;
+    }
+
+    pub fn toggle_sound(&mut self) {
+        self.sound.toggle();
+    }
+
+    pub fn cycle_mic_mode(&mut self) {
+        // the circle returns to its usual size after being driven by the
+        // input level
+        if self.mic.mode == MicMode::Amplitude {
+            self.reset_scale();
+        }
+        self.mic.cycle_mode();
+    }
+
+    pub fn reload_script(&mut self) {
+        self.script = Script::load();
+        self.caption = None;
+        self.chapters.clear();
+
+        if self.script.is_none() {
+            eprintln!("no script loaded from {SCRIPT_FILE}");
+        }
+    }
+
+    pub fn toggle_chapter_menu(&mut self) {
+        self.chapters.toggle_menu();
+    }
+
+    /// Seeks the script to the start of the chapter at `index`, if there is
+    /// one.
+    pub fn jump_to_chapter(&mut self, index: usize) {
+        let Some(script) = &mut self.script
+        else {
+            return;
+        };
+
+        if let Some(start) = self.chapters.jump(index) {
+            script.seek(start);
+        }
+    }
+
+    /// Saves the next frame as a PNG in the export directory.
+    pub fn take_screenshot(&mut self, app: &App) {
+        match export_path("screenshot", "png") {
+            Ok(path) => {
+                println!("saved screenshot to {}", path.display());
+
+                if self.offscreen.is_some() {
+                    self.pending_screenshot = Some(path);
+                }
+                else {
+                    app.main_window().capture_frame(&path);
+                }
+            }
+            Err(e) => eprintln!("failed to save screenshot: {e}"),
+        }
+    }
+
+    /// Renders this update's flashcard, animation frame or screenshot at the
+    /// export resolution, if one is set.
+    pub fn render_offscreen(&mut self, app: &App) {
+        let Some(mut offscreen) = self.offscreen.take()
+        else {
+            return;
+        };
+
+        if let Some(path) = self
+            .capture_path()
+            .or_else(|| self.pending_screenshot.take())
+        {
+            // the window's contents are scaled up to fit the image
+            let [w, h] = offscreen.size();
+            let window = self.layout.window();
+            let scale = (w as f32 / window.w()).min(h as f32 / window.h());
+
+            let draw = Draw::new();
+            draw_scene(
+                self,
+                &draw
+                    .scale(scale * self.view_scale())
+                    .translate(self.layout.origin().extend(0.0)),
+            );
+            offscreen.render(&app.main_window(), &draw, path);
+        }
+
+        self.offscreen = Some(offscreen);
+    }
+
+    /// Waits for every captured frame to be saved, returning `false` if it
+    /// timed out.
+    pub fn await_captures(&self, app: &App) -> bool {
+        let window = app.main_window();
+        let offscreen_saved =
+            self.offscreen.as_ref().is_none_or(|o| o.wait(&window));
+
+        window.await_capture_frame_jobs().is_ok() && offscreen_saved
+    }
+
+    pub fn cycle_paper(&mut self) {
+        self.paper.cycle();
+    }
+
+    pub fn toggle_polar_grid(&mut self) {
+        self.draw_polar_grid = !self.draw_polar_grid;
+        self.save_polar_grid();
+    }
+
+    /// Switches the polar grid's spokes between every 15° and every 30°,
+    /// showing the grid if it's hidden.
+    pub fn cycle_polar_spokes(&mut self) {
+        if self.draw_polar_grid {
+            self.polar_spokes.cycle();
+        }
+        self.draw_polar_grid = true;
+        self.save_polar_grid();
+    }
+
+    /// Saves whether the polar grid is shown, and its spokes, for the next
+    /// run.
+    fn save_polar_grid(&mut self) {
+        self.state.polar_grid = self.draw_polar_grid;
+        self.state.polar_spokes = self.polar_spokes;
+
+        if let Err(e) = self.state.save() {
+            eprintln!("failed to save state: {e}");
+        }
+    }
+
+    pub fn toggle_fullscreen(&mut self, app: &App) {
+        let fullscreen = app.main_window().is_fullscreen();
+        self.set_fullscreen(app, !fullscreen);
+    }
+
+    /// Enters or leaves fullscreen, fitting the circle to the screen and
+    /// restoring its size afterwards.
+    fn set_fullscreen(&mut self, app: &App, fullscreen: bool) {
+        app.main_window().set_fullscreen(fullscreen);
+
+        if fullscreen {
+            self.windowed_radius.get_or_insert(self.radius);
+            self.fit_radius = true;
+        }
+        else if let Some(radius) = self.windowed_radius.take() {
+            self.radius = radius;
+            self.fit_radius = false;
+        }
+    }
+
+    pub fn toggle_panel(&mut self) {
+        self.panel.toggle();
+    }
+
+    pub fn update_panel(&mut self, since_start: std::time::Duration) {
+        // the sliders allow typing in any value, so they're checked after
+        let (mut rate, mut radius) = (self.rate, self.radius);
+        let visible = &mut self.visible;
+        self.panel.update(
+            since_start,
+            Controls {
+                is_running: &mut self.is_running,
+                draw_labels: &mut self.draw_labels,
+                draw_values: &mut self.draw_values,
+                draw_theta: &mut self.draw_theta,
+                visible: [
+                    ("sin", &mut visible.sin),
+                    ("cos", &mut visible.cos),
+                    ("tan", &mut visible.tan),
+                    ("cot", &mut visible.cot),
+                    ("sec", &mut visible.sec),
+                    ("csc", &mut visible.csc),
+                ],
+                draw_order: &mut self.draw_order,
+                rate: &mut rate,
+                radius: &mut radius,
+                safe_area: &mut self.safe_area,
+                adjusting_safe_area: &mut self.adjusting_safe_area,
+            },
+        );
+        if rate != self.rate {
+            self.set_rate(rate.max(0.0));
+        }
+        if radius != self.radius {
+            self.radius = radius.max(MIN_RADIUS);
+            self.fit_radius = false;
+        }
+
+        // saved once it's been adjusted, rather than on every change
+        if !self.adjusting_safe_area && self.state.safe_area != self.safe_area {
+            self.state.safe_area = self.safe_area;
+
+            if let Err(e) = self.state.save() {
+                eprintln!("failed to save state: {e}");
+            }
+        }
+        if self.state.draw_order != self.draw_order {
+            self.state.draw_order = self.draw_order;
+
+            if let Err(e) = self.state.save() {
+                eprintln!("failed to save state: {e}");
+            }
+        }
+    }
+
+    pub fn cycle_layout(&mut self) {
+        self.layout.arrangement.cycle();
+    }
+
+    pub fn cycle_mirror(&mut self) {
+        self.layout.mirror.cycle();
+        self.state.mirror = self.layout.mirror;
+
+        if let Err(e) = self.state.save() {
+            eprintln!("failed to save state: {e}");
+        }
+    }
+
+    /// Cycles mirroring the whole window, readouts and all, for rear
+    /// projection.
+    pub fn cycle_scene_mirror(&mut self) {
+        self.scene_mirror.cycle();
+        self.state.scene_mirror = self.scene_mirror;
+
+        if let Err(e) = self.state.save() {
+            eprintln!("failed to save state: {e}");
+        }
+    }
+
+    pub fn toggle_broadcast(&mut self) {
+        self.broadcast = !self.broadcast;
+    }
+
+    pub fn toggle_history(&mut self) {
+        self.draw_history = !self.draw_history;
+    }
+
+    /// Saves the run so far as a ghost and restarts from where it began, or
+    /// clears the ghost if there already is one.
+    pub fn toggle_ghost(&mut self) {
+        if self.ghost.take().is_some() {
+            return;
+        }
+
+        self.ghost = Ghost::new(self.history.take_run());
+
+        if let Some(ghost) = &self.ghost {
+            self.theta = ghost.start_theta();
+        }
+    }
+
+    pub fn focus_next(&mut self) {
+        self.focus.next(&self.focusable());
+    }
+
+    pub fn focus_previous(&mut self) {
+        self.focus.previous(&self.focusable());
+    }
+
+    pub fn clear_focus(&mut self) -> bool {
+        self.focus.clear()
+    }
+
+    /// Activates the focused element, returning `false` if nothing is
+    /// focused.
+    pub fn activate_focused(&mut self) -> bool {
+        match self.focus.current() {
+            Some(Focusable::ValueRow(i)) => {
+                if let Some(&(line, _)) = self.value_rects.get(i) {
+                    self.activate_value_line(line);
+                }
+            }
+            // resumes from the scrubbed position
+            Some(Focusable::HistorySlider) => {
+                self.focus.clear();
+            }
+            Some(Focusable::Protractor) => self.protractor.style.cycle(),
+            None => return false,
+        }
+
+        true
+    }
+
+    /// Adjusts the focused element in `direction` (`1` or `-1`), returning
+    /// `false` if it can't be adjusted.
+    pub fn adjust_focused(&mut self, direction: f32) -> bool {
+        const SCRUB_STEP: f32 = 0.01;
+        const PROTRACTOR_STEP_DEGREES: f32 = 5.0;
+
+        match self.focus.current() {
+            // moving down the panel moves forward through the rows
+            Some(Focusable::ValueRow(_)) if direction > 0.0 => {
+                self.focus_previous();
+            }
+            Some(Focusable::ValueRow(_)) => self.focus_next(),
+            Some(Focusable::HistorySlider) => {
+                let t = self.history.position() + direction * SCRUB_STEP;
+                if let Some(sample) = self.history.scrub_to(t) {
+                    self.theta = sample.theta;
+                    self.set_rate(sample.rate);
+                    self.is_running = sample.is_running;
+                }
+            }
+            Some(Focusable::Protractor) => {
+                self.protractor.rotation = (self.protractor.rotation
+                    + direction * PROTRACTOR_STEP_DEGREES.to_radians())
+                .rem_euclid(TAU);
+            }
+            None => return false,
+        }
+
+        true
+    }
+
+    pub fn reset_clock(&mut self) {
+        self.clock.reset();
+    }
+
+    pub fn toggle_countdown(&mut self) {
+        self.draw_countdown = !self.draw_countdown;
+    }
+
+    pub fn toggle_countdown_auto_pause(&mut self) {
+        self.countdown.auto_pause = !self.countdown.auto_pause;
+    }
+
+    pub fn increment_countdown_target(&mut self) {
+        self.countdown.shift_target(COUNTDOWN_TARGET_INCREMENT);
+    }
+
+    pub fn decrement_countdown_target(&mut self) {
+        self.countdown.shift_target(-COUNTDOWN_TARGET_INCREMENT);
+    }
+
+    pub fn toggle_measure(&mut self) {
+        self.measure.toggle();
+    }
+
+    pub fn cycle_protractor(&mut self) {
+        self.protractor.style.cycle();
+    }
+
+    /// Clears the current measurement, returning whether there was one.
+    pub fn clear_measure(&mut self) -> bool {
+        self.measure.clear()
+    }
+
+    pub fn start_tour(&mut self) {
+        self.tour.start();
+    }
+
+    pub const fn is_touring(&self) -> bool {
+        self.tour.is_active()
+    }
+
+    pub fn advance_tour(&mut self) {
+        if self.tour.advance() {
+            self.finish_tour();
+        }
+    }
+
+    pub fn skip_tour(&mut self) {
+        self.tour.skip();
+        self.finish_tour();
+    }
+
+    pub fn show_whats_new(&mut self) {
+        self.whats_new = true;
+        self.whats_new_page = 0;
+    }
+
+    /// The entries on each page of the "what's new" panel, as many as fit in
+    /// the window.
+    fn whats_new_pages(&self) -> Vec<Vec<&'static str>> {
+        let room =
+            self.layout.window().h() - WHATS_NEW_PADDING - WHATS_NEW_MARGIN;
+        let per_page = (room / WHATS_NEW_ROW_HEIGHT).floor().max(1.0) as usize;
+
+        entries_for(VERSION)
+            .chunks(per_page)
+            .map(<[_]>::to_vec)
+            .collect()
+    }
+
+    /// Turns the page of the "what's new" panel with the arrow or page keys,
+    /// returning whether it's shown.
+    pub fn turn_whats_new_page(&mut self, key: Key) -> bool {
+        if !self.whats_new || self.tour.is_active() {
+            return false;
+        }
+
+        let last = self.whats_new_pages().len().saturating_sub(1);
+        self.whats_new_page = match key {
+            Key::Right | Key::Down | Key::PageDown => self.whats_new_page + 1,
+            Key::Left | Key::Up | Key::PageUp => {
+                self.whats_new_page.saturating_sub(1)
+            }
+            _ => return false,
+        }
+        .min(last);
+
+        true
+    }
+
+    /// Hides the "what's new" panel, returning whether it was shown.
+    pub fn dismiss_whats_new(&mut self) -> bool {
+        std::mem::replace(&mut self.whats_new, false)
+    }
+
+    /// Whether the app is being presented from, in broadcast mode or
+    /// fullscreen, where `Esc` shouldn't close the window by accident.
+    pub fn is_presenting(&self, app: &App) -> bool {
+        self.broadcast || app.main_window().is_fullscreen()
+    }
+
+    /// Quits, or asks to confirm first if `confirm_quit` is set.
+    pub fn request_quit(&mut self, app: &App) {
+        if self.state.confirm_quit {
+            self.quit_prompt = true;
+        }
+        else {
+            app.quit();
+        }
+    }
+
+    pub const fn is_confirming_quit(&self) -> bool {
+        self.quit_prompt
+    }
+
+    /// Hides the quit prompt, returning whether `key` confirms quitting. A
+    /// modifier on its own leaves the prompt up.
+    pub fn answer_quit_prompt(&mut self, key: Key) -> bool {
+        let is_modifier = matches!(
+            key,
+            Key::LShift
+                | Key::RShift
+                | Key::LControl
+                | Key::RControl
+                | Key::LAlt
+                | Key::RAlt
+                | Key::LWin
+                | Key::RWin
+        );
+        if is_modifier {
+            return false;
+        }
+
+        self.quit_prompt = false;
+        matches!(key, Key::Return | Key::Y)
+    }
+
+    fn finish_tour(&mut self) {
+        self.state.tour_seen = true;
+
+        if let Err(e) = self.state.save() {
+            eprintln!("failed to save state: {e}");
+        }
+    }
+
+    /// Jumps θ to the `idx`th angle of [`JUMP_TWELFTHS`].
+    pub fn jump_to_special_angle(&mut self, idx: usize) {
+        let Some(&twelfths) = JUMP_TWELFTHS.get(idx)
+        else {
+            return;
+        };
+
+        self.theta = twelfths as f32 * PI / 12.0;
+        self.jump_pulse = JUMP_PULSE_SECS;
+    }
+
+    /// Steps θ forward (or back, if `direction` is negative) by one step
+    /// while paused, returning whether it moved. The arrow keys are left to
+    /// move φ while it's shown.
+    pub fn step_theta(&mut self, direction: f32) -> bool {
+        if self.is_running || self.shows_phi() {
+            return false;
+        }
+
+        if self.snap.is_active() {
+            self.theta = self.snap.step(self.theta, direction);
+            return true;
+        }
+
+        let step = self.state.step_degrees.to_radians();
+        self.theta = (self.theta + direction * step).rem_euclid(TAU);
+
+        true
+    }
+
+    pub fn reset_theta(&mut self) {
+        self.theta = 0.0;
+    }
+
+    pub fn reset_rate(&mut self) {
+        self.set_rate(DEFAULT_RATE);
+    }
+
+    pub fn increase_scale(&mut self) {
+        self.radius += 10.0;
+        self.fit_radius = false;
+    }
+
+    pub fn decrease_scale(&mut self) {
+        self.radius = (self.radius - 10.0).max(MIN_RADIUS);
+        self.fit_radius = false;
+    }
+
+    pub fn reset_scale(&mut self) {
+        self.radius = UNIT_RADIUS;
+        self.fit_radius = false;
+    }
+
+    pub fn toggle_theme(&mut self) {
+        self.theme.toggle_light_dark();
+    }
+
+    pub fn cycle_angle_unit(&mut self) {
+        self.angle_unit.cycle();
+    }
+
+    pub fn cycle_locale(&mut self) {
+        self.locale.cycle();
+    }
+
+    /// The track of the history slider.
+    fn history_slider_rect(&self) -> Rect {
+        Rect::from_x_y_w_h(
+            self.layout.window_center().x,
+            self.layout.window().bottom() + HISTORY_SLIDER_OFFSET,
+            HISTORY_SLIDER_WIDTH,
+            20.0,
+        )
+    }
+
+    fn overlay_context(&self) -> OverlayContext {
+        OverlayContext {
+            theta: self.theta,
+            rate: self.rate,
+            radius: self.radius,
+            values: self.trig_values,
+            is_running: self.is_running,
+            window: self.layout.window(),
+            is_dark: self.theme.is_dark(),
+        }
+    }
+
+    pub fn draw_overlays(&self, draw: &Draw) {
+        self.overlays.draw(draw, &self.overlay_context());
+    }
+
+    /// The scale the scene is drawn at, which shrinks it to fit within the
+    /// safe margins in broadcast mode.
+    pub fn view_scale(&self) -> f32 {
+        if self.broadcast {
+            1.0 - 2.0 * self.state.safe_margin
+        }
+        else {
+            1.0
+        }
+    }
+
+    /// The colour of the tan θ line, which is kept away from the key colour
+    /// in broadcast mode.
+    fn tan_color(&self) -> Rgb {
+        if self.broadcast {
+            BROADCAST_TAN_COLOR
+        }
+        else {
+            TAN_COLOR
+        }
+    }
+
+    /// The colour of the cot θ line, which is kept away from the key colour
+    /// in broadcast mode.
+    fn cot_color(&self) -> Rgb {
+        if self.broadcast {
+            BROADCAST_COT_COLOR
+        }
+        else {
+            COT_COLOR
+        }
+    }
+
+    /// The transform from world coordinates to the scene's, which are
+    /// centred on the circle.
+    fn view(&self) -> View {
+        View::new(Vec2::ZERO, self.radius)
+    }
+
+    /// The view turned to the reference ray, in which θ's lines are drawn
+    /// from its values.
+    fn measured_view(&self) -> View {
+        self.view().rotated(self.ray_angle())
+    }
+
+    /// Where the point is on the circle, in the figure's coordinates.
+    fn node(&self) -> Vec2 {
+        self.measured_view()
+            .to_pixels(vec2(self.trig_values.cos, self.trig_values.sin))
+    }
+
+    /// The transform from world coordinates to the scene's for the
+    /// hyperbola, which is drawn smaller than the circle to fit it in.
+    fn hyperbola_view(&self) -> View {
+        View::new(Vec2::ZERO, self.radius * HYPERBOLA_SCALE)
+    }
+
+    /// The colour of `function`'s line.
+    fn function_color(&self, function: Function) -> Rgb {
+        match function {
+            Function::Tan => self.tan_color(),
+            Function::Cot => self.cot_color(),
+            _ => function.color(),
+        }
+    }
+
+    pub fn bg_color(&self) -> Rgb {
+        if self.broadcast {
+            KEY_COLOR
+        }
+        else if self.theme.is_dark() {
+            Rgb::new(0.0, 0.0, 0.0)
+        }
+        else {
+            Rgb::new(0.9, 0.9, 0.9)
+        }
+    }
+
+    // Draw methods
+
+    /// `draw` with the figure's mirroring undone, for what's placed by the
+    /// layout rather than on the figure.
+    fn unmirrored(&self, draw: &Draw) -> Draw {
+        let scale = self.layout.mirror.scale();
+        draw.scale_x(scale.x).scale_y(scale.y)
+    }
+
+    /// `draw` moved to `pos` on the figure with its mirroring undone, so that
+    /// text drawn at its origin is in the right place but still readable.
+    fn upright(&self, draw: &Draw, pos: Vec2) -> Draw {
+        self.unmirrored(&draw.xy(pos))
+    }
+
+    pub fn draw_bg_lines(&self, draw: &Draw) {
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+
+        // the image isn't part of the figure
+        self.draw_background_image(&self.unmirrored(draw));
+
+        if self.paper == Paper::Engineering {
+            self.draw_engineering_paper(draw);
+        }
+        if self.draw_polar_grid {
+            self.draw_polar_grid(draw);
+        }
+
+        draw.line()
+            .stroke_weight(STROKE_WEIGHT - 1.0)
             .start(vec2(-1000.0, 0.0))
             .end(vec2(1000.0, 0.0))
             .color(Rgba::new(color, color, color, 0.1));
 
-        draw.line()
-            .stroke_weight(STROKE_WEIGHT - 1.0)
-            .start(vec2(0.0, 1000.0))
-            .end(vec2(0.0, -1000.0))
-            .color(Rgba::new(color, color, color, 0.1));
+        draw.line()
+            .stroke_weight(STROKE_WEIGHT - 1.0)
+            .start(vec2(0.0, 1000.0))
+            .end(vec2(0.0, -1000.0))
+            .color(Rgba::new(color, color, color, 0.1));
+
+        if self.complex_plane {
+            self.draw_argand_grid(draw);
+        }
+    }
+
+    /// Draws the background image, if there is one, scaled to cover the
+    /// window.
+    fn draw_background_image(&self, draw: &Draw) {
+        let Some(texture) = &self.background_texture
+        else {
+            return;
+        };
+
+        let window = self.layout.window();
+        let [w, h] = texture.size();
+        let (w, h) = (w as f32, h as f32);
+        let scale = (window.w() / w).max(window.h() / h);
+        let bg = self.bg_color();
+
+        draw.texture(texture)
+            .xy(window.xy())
+            .w_h(w * scale, h * scale);
+        // fade the image into the background
+        draw.rect().xy(window.xy()).wh(window.wh()).color(Rgba::new(
+            bg.red,
+            bg.green,
+            bg.blue,
+            1.0 - self.state.background_opacity,
+        ));
+    }
+
+    fn draw_engineering_paper(&self, draw: &Draw) {
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let opacity = self.state.background_opacity;
+        let window = self.layout.window();
+        let step = self.radius / ENGINEERING_DIVISIONS as f32;
+        let reach = (window.w().max(window.h()) / step).ceil() as i32;
+
+        for i in (-reach..=reach).filter(|&i| i != 0) {
+            let offset = i as f32 * step;
+            let is_major = i % ENGINEERING_MAJOR_EVERY as i32 == 0;
+            let (weight, alpha) = if is_major {
+                (1.0, 0.16 * opacity)
+            }
+            else {
+                (0.5, 0.08 * opacity)
+            };
+
+            draw.line()
+                .stroke_weight(weight)
+                .start(vec2(offset, window.bottom()))
+                .end(vec2(offset, window.top()))
+                .color(Rgba::new(color, color, color, alpha));
+            draw.line()
+                .stroke_weight(weight)
+                .start(vec2(window.left(), offset))
+                .end(vec2(window.right(), offset))
+                .color(Rgba::new(color, color, color, alpha));
+        }
+    }
+
+    /// Draws rings at each quarter of the radius, labelled with their
+    /// distance from the centre, and spokes every 15° or 30°.
+    fn draw_polar_grid(&self, draw: &Draw) {
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let opacity = self.state.background_opacity;
+        let window = self.layout.window();
+        let extent = window.w().hypot(window.h());
+        let ring_step = self.radius / POLAR_RINGS as f32;
+        let num_rings = (extent / ring_step).ceil() as u32;
+
+        for ring in 1..=num_rings {
+            let alpha = if ring % POLAR_RINGS == 0 { 0.16 } else { 0.08 };
+
+            let radius = ring as f32 * ring_step;
+
+            draw.ellipse()
+                .no_fill()
+                .radius(radius)
+                .stroke_weight(1.0)
+                .stroke(Rgba::new(color, color, color, alpha * opacity));
+
+            // 0.25, 0.5, 0.75, 1, 1.25...
+            let decimals = match ring % POLAR_RINGS {
+                0 => 0,
+                2 => 1,
+                _ => 2,
+            };
+            let (y, x) = (-PI / 24.0).sin_cos();
+
+            self.upright(draw, vec2(x, y) * radius + vec2(14.0, 0.0))
+                .text(
+                    &self
+                        .locale
+                        .format(ring as f32 / POLAR_RINGS as f32, decimals),
+                )
+                .layout(&font_layout(LABEL_FONT_SIZE - 3, Regular, Center))
+                .color(Rgba::new(color, color, color, 0.5 * opacity));
+        }
+
+        let spokes = self.polar_spokes.count();
+
+        for spoke in 0..spokes {
+            let (y, x) = (spoke as f32 * TAU / spokes as f32).sin_cos();
+            // the quadrants' edges stand out like the whole radii
+            let alpha = if spoke % (spokes / 4) == 0 {
+                0.16
+            }
+            else {
+                0.08
+            };
+
+            draw.line()
+                .stroke_weight(1.0)
+                .start(Vec2::ZERO)
+                .end(vec2(x, y) * extent)
+                .color(Rgba::new(color, color, color, alpha * opacity));
+        }
+    }
+
+    fn draw_argand_grid(&self, draw: &Draw) {
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let reach = (600.0 / self.radius).ceil() as i32;
+
+        for i in (-reach..=reach).filter(|&i| i != 0) {
+            let offset = i as f32 * self.radius;
+
+            draw.line()
+                .stroke_weight(1.0)
+                .start(vec2(offset, -1000.0))
+                .end(vec2(offset, 1000.0))
+                .color(Rgba::new(color, color, color, 0.08));
+            draw.line()
+                .stroke_weight(1.0)
+                .start(vec2(-1000.0, offset))
+                .end(vec2(1000.0, offset))
+                .color(Rgba::new(color, color, color, 0.08));
+
+            self.upright(draw, vec2(offset + 8.0, -12.0))
+                .text(&i.to_string())
+                .layout(&font_layout(LABEL_FONT_SIZE, Regular, Center))
+                .color(Rgba::new(color, color, color, 0.4));
+            self.upright(draw, vec2(-14.0, offset + 10.0))
+                .text(&format!("{i}i"))
+                .layout(&font_layout(LABEL_FONT_SIZE, Regular, Center))
+                .color(Rgba::new(color, color, color, 0.4));
+        }
+
+        self.upright(draw, vec2(self.layout.circle().right() - 15.0, 14.0))
+            .text("Re")
+            .layout(&font_layout(18, Italic, Center))
+            .color(Rgba::new(color, color, color, 0.6));
+        self.upright(draw, vec2(18.0, self.layout.circle().top() - 15.0))
+            .text("Im")
+            .layout(&font_layout(18, Italic, Center))
+            .color(Rgba::new(color, color, color, 0.6));
+    }
+
+    pub fn draw_protractor(&self, draw: &Draw) {
+        if !self.protractor.is_visible() {
+            return;
+        }
+
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let sweep = self.protractor.style.sweep();
+        let is_full = self.protractor.style == ProtractorStyle::Full;
+        let num_degrees = sweep.to_degrees().round() as u32;
+
+        let on_circle = |angle: f32, radius: f32| {
+            let (y, x) = (self.protractor.rotation + angle).sin_cos();
+            vec2(x, y) * radius
+        };
+
+        draw.polygon()
+            .points(
+                (0..=num_degrees)
+                    .map(|deg| {
+                        on_circle((deg as f32).to_radians(), self.radius)
+                    })
+                    .chain((!is_full).then_some(Vec2::ZERO)),
+            )
+            .color(Rgba::new(color, color, color, 0.05));
+
+        if !is_full {
+            draw.line()
+                .start(on_circle(0.0, self.radius))
+                .end(on_circle(PI, self.radius))
+                .color(Rgba::new(color, color, color, 0.5))
+                .stroke_weight(1.0);
+        }
+
+        for deg in (0..=num_degrees).filter(|&deg| !is_full || deg < 360) {
+            let angle = (deg as f32).to_radians();
+            let tick_len = if deg % 10 == 0 {
+                16.0
+            }
+            else if deg % 5 == 0 {
+                10.0
+            }
+            else {
+                5.0
+            };
+
+            draw.line()
+                .start(on_circle(angle, self.radius))
+                .end(on_circle(angle, self.radius - tick_len))
+                .color(Rgba::new(color, color, color, 0.5))
+                .stroke_weight(1.0);
+
+            if deg % 10 == 0 {
+                self.upright(draw, on_circle(angle, self.radius - 28.0))
+                    .text(&deg.to_string())
+                    .layout(&font_layout(11, Regular, Center))
+                    .color(Rgba::new(color, color, color, 0.7));
+            }
+        }
+    }
+
+    /// Shades where the selected function is negative or greater than 1 in
+    /// magnitude, and marks where it's undefined, with a legend.
+    pub fn draw_domain_shading(&self, draw: &Draw) {
+        let Some(function) = self.domain.function()
+        else {
+            return;
+        };
+
+        let on_circle = |angle: f32, radius: f32| {
+            let (y, x) = angle.sin_cos();
+            vec2(x, y) * radius
+        };
+        let arc = |start: f32, end: f32, radius: f32| {
+            let num_points = ((end - start).to_degrees().ceil() as u32).max(1);
+            (0..=num_points).map(move |i| {
+                let t = i as f32 / num_points as f32;
+                on_circle(start + (end - start) * t, radius)
+            })
+        };
+        let band_radius = self.radius * 1.08;
+
+        let negative = regions(function, |val| val < 0.0);
+        let neg = DOMAIN_NEGATIVE_COLOR;
+        for &(start, end) in &negative {
+            draw.polygon()
+                .points(arc(start, end, self.radius).chain([Vec2::ZERO]))
+                .color(Rgba::new(neg.red, neg.green, neg.blue, 0.2));
+        }
+
+        let large = regions(function, |val| val.abs() > 1.0);
+        let large_color = DOMAIN_LARGE_COLOR;
+        for &(start, end) in &large {
+            draw.polygon()
+                .points(
+                    arc(start, end, self.radius)
+                        .chain(arc(start, end, band_radius).rev()),
+                )
+                .color(Rgba::new(
+                    large_color.red,
+                    large_color.green,
+                    large_color.blue,
+                    0.5,
+                ));
+        }
+
+        let undefined = undefined_angles(function);
+        for &angle in undefined {
+            draw.line()
+                .start(Vec2::ZERO)
+                .end(on_circle(angle, band_radius))
+                .color(DOMAIN_UNDEFINED_COLOR)
+                .stroke_weight(STROKE_WEIGHT);
+        }
+
+        let name = match function {
+            Label::Sin => SIN_LABEL,
+            Label::Cos => COS_LABEL,
+            Label::Tan => TAN_LABEL,
+            Label::Cot => COT_LABEL,
+            Label::Sec => SEC_LABEL,
+            Label::Csc => CSC_LABEL,
+            Label::Theta | Label::Unit => return,
+        };
+        let entries = [
+            (DOMAIN_NEGATIVE_COLOR, "negative", negative.is_empty()),
+            (DOMAIN_LARGE_COLOR, "magnitude > 1", large.is_empty()),
+            (DOMAIN_UNDEFINED_COLOR, "undefined", undefined.is_empty()),
+        ];
+
+        let draw = self.unmirrored(draw);
+        let legend = self.layout.legend_slot(0);
+        let color = if self.theme.is_dark() { 0.8 } else { 0.2 };
+        draw.text(name)
+            .xy(legend + vec2(0.0, 30.0))
+            .layout(&font_layout(18, Italic, Left))
+            .color(Rgb::new(color, color, color));
+
+        for (i, (swatch, text, is_never)) in entries.into_iter().enumerate() {
+            let y = -(i as f32) * 22.0;
+
+            draw.rect()
+                .xy(legend + vec2(-90.0, y + 3.0))
+                .w_h(12.0, 12.0)
+                .color(swatch);
+            draw.text(&if is_never {
+                format!("{text} (never)")
+            }
+            else {
+                String::from(text)
+            })
+            .xy(legend + vec2(15.0, y))
+            .layout(&font_layout(16, Italic, Left))
+            .color(Rgb::new(color, color, color));
+        }
+    }
+
+    /// Draws the reference angle θ′, the acute angle between θ and the
+    /// nearest side of the x-axis (or of the reference ray), as a small arc
+    /// labelled with how it's found from θ in the current quadrant.
+    pub fn draw_reference_angle(&self, draw: &Draw) {
+        const POINTS: usize = 32;
+
+        if !self.draw_reference_angle {
+            return;
+        }
+
+        // measured from the reference ray, if there is one
+        let (ray, theta) = (self.ray_angle(), self.measured_theta());
+        let theta = theta.rem_euclid(TAU);
+        let name = self.measured_name();
+        let subtracted = if self.reference_ray.is_some() {
+            format!("({name})")
+        }
+        else {
+            name.to_string()
+        };
+        let (axis, rule) = match quadrant(theta) {
+            1 => (0.0, name.to_string()),
+            2 => (PI, format!("π − {subtracted}")),
+            3 => (PI, format!("{name} − π")),
+            _ => (TAU, format!("2π − {subtracted}")),
+        };
+        let reference = (theta - axis).abs();
+        let radius = self.radius * 0.25;
+        let on_arc = |t: f32, radius: f32| {
+            let (y, x) = (ray + axis + (theta - axis) * t).sin_cos();
+            vec2(x, y) * radius
+        };
+        let color = Rgba::new(
+            REFERENCE_ANGLE_COLOR.red,
+            REFERENCE_ANGLE_COLOR.green,
+            REFERENCE_ANGLE_COLOR.blue,
+            1.0,
+        );
+
+        draw.line()
+            .start(Vec2::ZERO)
+            .end(on_arc(0.0, radius * 1.3))
+            .color(Rgba::new(color.red, color.green, color.blue, 0.6))
+            .stroke_weight(1.5);
+        draw.polyline()
+            .weight(STROKE_WEIGHT - 1.0)
+            .points(
+                (0..=POINTS).map(|i| on_arc(i as f32 / POINTS as f32, radius)),
+            )
+            .color(color);
+
+        let text = if rule == name {
+            format!("θ′ = {}", self.fmt_angle(reference, 2, true))
+        }
+        else {
+            format!("θ′ = {rule} = {}", self.fmt_angle(reference, 2, true))
+        };
+        self.draw_outlined_text(draw, &text, on_arc(0.5, radius + 45.0), color);
+    }
+
+    /// Draws the reference ray θ₀, which the θ arc and the lines are measured
+    /// from.
+    pub fn draw_reference_ray(&self, draw: &Draw) {
+        let Some(ray) = self.reference_ray
+        else {
+            return;
+        };
+
+        let color = Rgba::new(
+            REFERENCE_RAY_COLOR.red,
+            REFERENCE_RAY_COLOR.green,
+            REFERENCE_RAY_COLOR.blue,
+            1.0,
+        );
+        let on_circle = |angle: f32, radius: f32| {
+            let (y, x) = angle.sin_cos();
+            vec2(x, y) * radius
+        };
+
+        draw.line()
+            .start(Vec2::ZERO)
+            .end(on_circle(ray, self.radius + 30.0))
+            .color(color)
+            .stroke_weight(STROKE_WEIGHT - 1.0);
+        draw.ellipse()
+            .xy(on_circle(ray, self.radius + 30.0))
+            .radius(4.0)
+            .color(color);
+        self.draw_outlined_text(
+            draw,
+            "θ₀",
+            on_circle(ray, self.radius + 50.0),
+            color,
+        );
+    }
+
+    pub fn draw_unit_circle(&self, draw: &Draw) {
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+
+        draw.ellipse()
+            .no_fill()
+            .radius(self.radius)
+            .stroke_weight(STROKE_WEIGHT - 0.3)
+            .stroke(Rgba::new(color, color, color, 0.3))
+            .xy(Vec2::ZERO);
+
+        if self.draw_theta {
+            self.draw_theta_circle(draw);
+        }
+
+        for &bookmark in &self.bookmarks {
+            let (y, x) = bookmark.sin_cos();
+            let dir = vec2(x, y);
+
+            // the stretch of the circle θ slows down over
+            if self.bullet_time.is_active() {
+                let window = self.bullet_time.window();
+                let points = (0..=32).map(|i| {
+                    let angle =
+                        bookmark - window + window * 2.0 * i as f32 / 32.0;
+                    let (y, x) = angle.sin_cos();
+                    vec2(x, y) * (self.radius + 9.0)
+                });
+
+                draw.polyline()
+                    .weight(STROKE_WEIGHT - 1.0)
+                    .points(points)
+                    .color(Rgba::new(color, color, color, 0.25));
+            }
+
+            draw.line()
+                .start(dir * (self.radius + 4.0))
+                .end(dir * (self.radius + 14.0))
+                .color(Rgba::new(color, color, color, 0.6))
+                .stroke_weight(STROKE_WEIGHT - 1.0);
+        }
+    }
+
+    pub fn draw_ghost(&self, draw: &Draw) {
+        let Some(ghost) = &self.ghost
+        else {
+            return;
+        };
+
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let alpha = if ghost.is_finished() { 0.15 } else { 0.35 };
+        let (sin, cos) = ghost.current().theta.sin_cos();
+        let pt = vec2(cos, sin) * self.radius;
+
+        draw.line()
+            .start(Vec2::ZERO)
+            .end(pt)
+            .color(Rgba::new(color, color, color, alpha))
+            .stroke_weight(STROKE_WEIGHT - 1.0);
+
+        let trail = ghost.trail(20);
+        for (i, sample) in trail.iter().enumerate() {
+            let (sin, cos) = sample.theta.sin_cos();
+            let fade = (i + 1) as f32 / trail.len() as f32;
+
+            draw.ellipse()
+                .radius(3.0)
+                .color(Rgba::new(color, color, color, alpha * fade))
+                .xy(vec2(cos, sin) * self.radius);
+        }
+
+        draw.ellipse()
+            .radius(8.0)
+            .no_fill()
+            .stroke_weight(2.0)
+            .stroke(Rgba::new(color, color, color, alpha * 2.0))
+            .xy(pt);
+    }
+
+    /// Draws a tick outside the circle at the angle θ has just snapped to.
+    pub fn draw_snap(&self, draw: &Draw) {
+        let Some((angle, strength)) = self.snap.tick()
+        else {
+            return;
+        };
+
+        let (y, x) = angle.sin_cos();
+        let dir = vec2(x, y);
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+
+        draw.line()
+            .start(dir * (self.radius + 6.0))
+            .end(dir * (self.radius + 18.0))
+            .stroke_weight(STROKE_WEIGHT)
+            .color(Rgba::new(color, color, color, strength * 0.6));
+    }
+
+    pub fn draw_node(&self, draw: &Draw) {
+        let pt = self.node();
+
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+
+        draw.ellipse()
+            .radius(8.0)
+            .color(Rgba::new(color, color, color, 0.75))
+            .xy(pt);
+
+        if self.jump_pulse > 0.0 {
+            let t = self.jump_pulse / JUMP_PULSE_SECS;
+
+            draw.ellipse()
+                .no_fill()
+                .radius(8.0 + (1.0 - t) * 30.0)
+                .stroke_weight(STROKE_WEIGHT)
+                .stroke(Rgba::new(color, color, color, t))
+                .xy(pt);
+        }
+    }
+
+    /// Draws the point's velocity, tangent to the circle, and its
+    /// acceleration, towards the centre, at the speed it's actually turning.
+    ///
+    /// Both are drawn to the same scale, which makes the velocity half the
+    /// radius long, so the acceleration is longer than it the faster the
+    /// point turns. Their true sizes are shown beside them.
+    pub fn draw_derivatives(&self, draw: &Draw) {
+        let omega = self.angular_velocity();
+        if !self.draw_derivatives || omega.abs() < f32::EPSILON {
+            return;
+        }
+
+        let (sin, cos) = self.theta.sin_cos();
+        let point = vec2(cos, sin) * self.radius;
+        let scale = 0.5 / omega.abs();
+
+        // the derivatives of (cos ωt, sin ωt)
+        let velocity = vec2(-sin, cos) * omega;
+        let acceleration = -vec2(cos, sin) * omega * omega;
+
+        for (name, vector, color) in [
+            ("v", velocity, VELOCITY_COLOR),
+            ("a", acceleration, ACCELERATION_COLOR),
+        ] {
+            let end = point + vector * scale * self.radius;
+            let color = Rgba::new(color.red, color.green, color.blue, 1.0);
+
+            draw_vector(draw, point, end, color);
+            self.draw_outlined_text(
+                draw,
+                &format!("{name} = {}", self.locale.format(vector.length(), 3)),
+                end + (end - point).normalize_or_zero() * 25.0,
+                color,
+            );
+        }
+    }
+
+    /// The visible lines of each function and the radius.
+    fn trig_segments(&self) -> Vec<Segment> {
+        self.segments_of(self.trig_values, self.measured_view())
+    }
+
+    /// The visible lines of each function and the radius for `values`, in
+    /// the scene's coordinates through `view`.
+    fn segments_of(&self, values: TrigValues, view: View) -> Vec<Segment> {
+        let radius = Segment {
+            start: view.to_pixels(Vec2::ZERO),
+            end: view.to_pixels(vec2(values.cos, values.sin)),
+            color: Rgb::new(1.0, 1.0, 1.0),
+        };
+
+        Function::ALL
+            .into_iter()
+            .filter(|&function| self.visible.shows(function))
+            .map(|function| {
+                let (start, end) = values.line(function);
+                Segment {
+                    start: view.to_pixels(start),
+                    end: view.to_pixels(end),
+                    color: self.function_color(function),
+                }
+            })
+            .chain(std::iter::once(radius))
+            .collect()
+    }
+
+    /// Draws the lines of the comparison angle ψ faintly beneath θ's, with
+    /// the arc from ψ to θ.
+    pub fn draw_comparison(&self, draw: &Draw) {
+        const ALPHA: f32 = 0.35;
+        const ARC_POINTS: usize = 48;
+
+        if !self.comparison.is_active() {
+            return;
+        }
+
+        let mut values = self.comparison.values();
+        // some values can be inf, so this is needed to prevent a geometry error!
+        values.clamp_inf();
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+
+        for seg in self.segments_of(values, self.view()) {
+            draw.line()
+                .start(seg.start)
+                .end(seg.end)
+                .color(Rgba::new(
+                    seg.color.red,
+                    seg.color.green,
+                    seg.color.blue,
+                    ALPHA,
+                ))
+                .stroke_weight(STROKE_WEIGHT - 1.0);
+        }
+
+        let view = self.view();
+        let psi = self.comparison.psi();
+        let diff = self.comparison.difference(self.theta);
+        let on_circle = |angle: f32| {
+            let (y, x) = angle.sin_cos();
+            vec2(x, y)
+        };
+        // beside the point at `angle` on a circle of `radius`, outside it
+        let beside = |angle: f32, radius: f32| {
+            view.place(Placement::new(
+                on_circle(angle) * radius,
+                on_circle(angle) * LABEL_GAP,
+            ))
+        };
+        let arc_radius = 0.3;
+
+        draw.polyline()
+            .weight(STROKE_WEIGHT - 1.0)
+            .points((0..=ARC_POINTS).map(|i| {
+                let t = i as f32 / ARC_POINTS as f32;
+                view.to_pixels(on_circle(psi + diff * t) * arc_radius)
+            }))
+            .color(Rgba::new(color, color, color, ALPHA * 2.0));
+
+        if diff.abs() > 0.2 {
+            self.upright(draw, beside(psi + diff * 0.5, arc_radius))
+                .text("θ − ψ")
+                .layout(&font_layout(LABEL_FONT_SIZE, Regular, Center))
+                .color(Rgba::new(color, color, color, ALPHA * 2.0));
+        }
+
+        // ψ's lines are labelled in the same places as θ's, so only once
+        // they've moved apart from them
+        if self.draw_labels && diff.abs() > 0.2 {
+            for function in Function::ALL {
+                if !self.visible.shows(function) {
+                    continue;
+                }
+
+                let line_color = self.function_color(function);
+                let side = function.label_side(psi).signum();
+                self.draw_outlined_text(
+                    draw,
+                    &function.label().replace('θ', "ψ"),
+                    view.place(function.label_placement(psi, side)),
+                    Rgba::new(
+                        line_color.red,
+                        line_color.green,
+                        line_color.blue,
+                        ALPHA * 2.0,
+                    ),
+                );
+            }
+        }
+
+        draw.ellipse()
+            .radius(6.0)
+            .no_fill()
+            .stroke_weight(2.0)
+            .stroke(Rgba::new(color, color, color, ALPHA * 2.0))
+            .xy(view.to_pixels(on_circle(psi)));
+        self.upright(draw, beside(psi, 1.0))
+            .text("ψ")
+            .layout(&font_layout(LABEL_FONT_SIZE, Italic, Center))
+            .color(Rgba::new(color, color, color, ALPHA * 2.0));
+    }
+
+    /// Draws the lines kept by [`Model::toggle_before`] faintly, with the
+    /// circle and point as they were.
+    pub fn draw_before(&self, draw: &Draw) {
+        const ALPHA: f32 = 0.3;
+
+        let Some(before) = &self.before
+        else {
+            return;
+        };
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+
+        if (before.radius - self.radius).abs() > f32::EPSILON {
+            draw.ellipse()
+                .no_fill()
+                .radius(before.radius)
+                .stroke_weight(STROKE_WEIGHT - 1.0)
+                .stroke(Rgba::new(color, color, color, ALPHA * 0.5));
+        }
+
+        for seg in &before.segments {
+            draw.line()
+                .start(seg.start)
+                .end(seg.end)
+                .color(Rgba::new(
+                    seg.color.red,
+                    seg.color.green,
+                    seg.color.blue,
+                    ALPHA,
+                ))
+                .stroke_weight(STROKE_WEIGHT);
+        }
+
+        let (y, x) = before.theta.sin_cos();
+        draw.ellipse()
+            .radius(8.0)
+            .no_fill()
+            .stroke_weight(2.0)
+            .stroke(Rgba::new(color, color, color, ALPHA * 2.0))
+            .xy(vec2(x, y) * before.radius);
+    }
+
+    pub fn draw_trig_lines(&self, draw: &Draw) {
+        for label in self.draw_order {
+            match label {
+                Label::Sin if self.visible.sin => self.draw_sin_line(draw),
+                Label::Cos if self.visible.cos => self.draw_cos_line(draw),
+                Label::Tan if self.visible.tan => self.draw_tan_line(draw),
+                Label::Cot if self.visible.cot => self.draw_cot_line(draw),
+                Label::Sec if self.visible.sec => self.draw_sec_line(draw),
+                Label::Csc if self.visible.csc => self.draw_csc_line(draw),
+                Label::Unit => self.draw_unit_line(draw),
+                _ => {}
+            }
+        }
+    }
+
+    /// Draws the past values of sin θ as a wave to the right of the circle and
+    /// cos θ as a wave below it, with guide lines from the node to each.
+    pub fn draw_trace(&self, draw: &Draw) {
+        if !self.trace.is_active() {
+            return;
+        }
+
+        let region = self.layout.circle();
+        let start = self.radius + TRACE_GAP;
+        let sin_len = region.right() - start;
+        let cos_len = -region.bottom() - start;
+        let along = |behind: f32| start + behind / TAU * TRACE_PERIOD;
+
+        let (y, x) = self.theta.sin_cos();
+        let node = vec2(x, y) * self.radius;
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let guide = Rgba::new(color, color, color, 0.3);
+
+        // axes, when there's room for them beside the circle
+        if sin_len > 0.0 {
+            draw.line()
+                .start(vec2(start, 0.0))
+                .end(vec2(region.right(), 0.0))
+                .color(guide)
+                .stroke_weight(1.0);
+        }
+        if cos_len > 0.0 {
+            draw.line()
+                .start(vec2(0.0, -start))
+                .end(vec2(0.0, region.bottom()))
+                .color(guide)
+                .stroke_weight(1.0);
+        }
+
+        // guides from the node into each wave
+        draw.line()
+            .start(node)
+            .end(vec2(start, node.y))
+            .color(guide)
+            .stroke_weight(1.0);
+        draw.line()
+            .start(node)
+            .end(vec2(node.x, -start))
+            .color(guide)
+            .stroke_weight(1.0);
+
+        draw.polyline()
+            .weight(STROKE_WEIGHT - 1.0)
+            .points(
+                self.trace
+                    .points()
+                    .filter(|&(behind, _)| along(behind) - start <= sin_len)
+                    .map(|(behind, pt)| {
+                        vec2(along(behind), pt.sin * self.radius)
+                    }),
+            )
+            .color(SIN_COLOR);
+        draw.polyline()
+            .weight(STROKE_WEIGHT - 1.0)
+            .points(
+                self.trace
+                    .points()
+                    .filter(|&(behind, _)| along(behind) - start <= cos_len)
+                    .map(|(behind, pt)| {
+                        vec2(pt.cos * self.radius, -along(behind))
+                    }),
+            )
+            .color(COS_COLOR);
+    }
+
+    /// Shades the signed area under the sine trace from 0 to θ, with its
+    /// value, 1 − cos θ.
+    pub fn draw_integral(&self, draw: &Draw) {
+        // the number of points along each degree of the curve
+        const RESOLUTION: f32 = 1.0;
+
+        if !self.trace.shows_integral() {
+            return;
+        }
+
+        let region = self.layout.circle();
+        let start = self.radius + TRACE_GAP;
+        let sin_len = region.right() - start;
+        let theta = self.theta.rem_euclid(TAU);
+        // the position of angle `a` along the trace, which runs back from θ
+        let along = |a: f32| start + (theta - a) / TAU * TRACE_PERIOD;
+
+        // the area above the axis, from 0 to π, and below it, from π to θ
+        for (from, to, color) in [
+            (0.0, theta.min(PI), SIN_COLOR),
+            (PI, theta, NEGATIVE_AREA_COLOR),
+        ] {
+            // cut off where the trace leaves the region
+            let from = from.max(theta - sin_len / TRACE_PERIOD * TAU);
+            if to <= from {
+                continue;
+            }
+
+            let steps = ((to - from).to_degrees() * RESOLUTION).ceil() as usize;
+            let curve = (0..=steps).map(|i| {
+                let a = from + (to - from) * i as f32 / steps.max(1) as f32;
+                vec2(along(a), a.sin() * self.radius)
+            });
+
+            draw.polygon()
+                .points(
+                    curve.chain([vec2(along(to), 0.0), vec2(along(from), 0.0)]),
+                )
+                .color(Rgba::new(color.red, color.green, color.blue, 0.3));
+        }
+
+        self.upright(draw, vec2(start + 200.0, self.radius + 85.0))
+            .text(&format!(
+                "∫ sin x dx from 0 to θ = 1 − cos θ = {}",
+                self.locale.format(1.0 - theta.cos(), 4)
+            ))
+            .w(400.0)
+            .layout(&font_layout(16, Regular, Left))
+            .color(SIN_COLOR);
+    }
+
+    /// Draws the Taylor polynomials over the wave traces, with how far each
+    /// is from the true value at θ.
+    pub fn draw_taylor(&self, draw: &Draw) {
+        // how far the polynomials are followed from the axis, in radii,
+        // before they're cut off
+        const LIMIT: f32 = 1.5;
+        // the distance between the points of each curve in pixels
+        const STEP: f32 = 2.0;
+
+        if !self.taylor.is_active() || !self.trace.is_active() {
+            return;
+        }
+
+        let region = self.layout.circle();
+        let start = self.radius + TRACE_GAP;
+        let sin_len = region.right() - start;
+        let cos_len = -region.bottom() - start;
+
+        // each curve is split where its angle wraps and where it leaves the
+        // limit, as the polynomials jump there
+        let curve = |len: f32, approx: &dyn Fn(f32) -> f32| {
+            let mut pieces = vec![Vec::new()];
+            let mut last_angle = f32::INFINITY;
+
+            for i in 0..=(len.max(0.0) / STEP) as usize {
+                let d = i as f32 * STEP;
+                let angle = (self.theta - d / TRACE_PERIOD * TAU + PI)
+                    .rem_euclid(TAU)
+                    - PI;
+                let value = approx(angle);
+
+                if angle > last_angle || value.abs() > LIMIT {
+                    pieces.push(Vec::new());
+                }
+                last_angle = angle;
+
+                if value.abs() <= LIMIT {
+                    pieces.last_mut().unwrap().push((d, value * self.radius));
+                }
+            }
+
+            pieces.retain(|piece| piece.len() > 1);
+            pieces
+        };
+
+        let weight = STROKE_WEIGHT - 1.5;
+        for piece in curve(sin_len, &|x| self.taylor.sin(x)) {
+            draw.polyline()
+                .weight(weight)
+                .points(piece.into_iter().map(|(d, y)| vec2(start + d, y)))
+                .color(Rgba::new(
+                    SIN_COLOR.red,
+                    SIN_COLOR.green,
+                    SIN_COLOR.blue,
+                    0.5,
+                ));
+        }
+        for piece in curve(cos_len, &|x| self.taylor.cos(x)) {
+            draw.polyline()
+                .weight(weight)
+                .points(piece.into_iter().map(|(d, x)| vec2(x, -start - d)))
+                .color(Rgba::new(
+                    COS_COLOR.red,
+                    COS_COLOR.green,
+                    COS_COLOR.blue,
+                    0.5,
+                ));
+        }
+
+        let degree = self.taylor.degree();
+        let readouts = [
+            (
+                SIN_LABEL,
+                self.taylor.sin(self.theta),
+                self.theta.sin(),
+                SIN_COLOR,
+            ),
+            (
+                COS_LABEL,
+                self.taylor.cos(self.theta),
+                self.theta.cos(),
+                COS_COLOR,
+            ),
+        ];
+        for (i, (label, approx, exact, color)) in
+            readouts.into_iter().enumerate()
+        {
+            let pos = vec2(start + 200.0, self.radius + 60.0 - i as f32 * 25.0);
+
+            self.upright(draw, pos)
+                .text(&format!(
+                    "{label} ≈ {} (degree {degree}, error {:.1e})",
+                    self.locale.format(approx, 4),
+                    (approx - exact).abs(),
+                ))
+                .w(400.0)
+                .layout(&font_layout(16, Regular, Left))
+                .color(color);
+        }
+    }
+
+    /// Draws how far through the rate ramp is beneath the rate readout at
+    /// `slot`, with the rate it's heading for.
+    fn draw_ramp(&self, draw: &Draw, slot: Vec2, color: Rgba) {
+        const WIDTH: f32 = 120.0;
+
+        let Some(ramp) = &self.ramp
+        else {
+            return;
+        };
+
+        let start = slot + vec2(0.0, -20.0);
+        let faint = Rgba::new(color.red, color.green, color.blue, 0.3);
+
+        draw.line()
+            .start(start)
+            .end(start + vec2(WIDTH, 0.0))
+            .color(faint)
+            .stroke_weight(4.0);
+        draw.line()
+            .start(start)
+            .end(start + vec2(WIDTH * ramp.progress(), 0.0))
+            .color(color)
+            .stroke_weight(4.0);
+
+        draw.text(&format!("→ {}", self.fmt_rate(ramp.to())))
+            .xy(start + vec2(WIDTH + 10.0, 0.0))
+            .layout(&font_layout(14, Italic, Left))
+            .color(color);
+    }
+
+    pub fn draw_values(&self, draw: &Draw) {
+        if !self.draw_values {
+            return;
+        }
+        match self.mode {
+            Mode::Circular => {}
+            Mode::Hyperbolic => {
+                self.draw_hyperbolic_values(draw);
+                return;
+            }
+            Mode::Epicycles => {
+                self.draw_epicycle_values(draw);
+                return;
+            }
+        }
+
+        let TrigValues { sin, cos, .. } = self.trig_values;
+        let shade = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let text_color = Rgba::new(shade, shade, shade, 1.0);
+
+        for &(line, slot) in &self.value_slots {
+            let (text, color) = match line {
+                ValueLine::Theta if self.draw_theta => {
+                    (format!("θ = {}", self.fmt_theta()), text_color)
+                }
+                ValueLine::Theta => continue,
+                ValueLine::Heading(group) => {
+                    self.draw_value_heading(draw, group, slot);
+                    continue;
+                }
+                ValueLine::Value(label) => {
+                    let Some(text) = self.fmt_value_line(label)
+                    else {
+                        continue;
+                    };
+                    (text, self.label_color(label))
+                }
+                ValueLine::Z => (format!("z = {}", self.fmt_z()), text_color),
+                ValueLine::Identity => (
+                    format!(
+                        "sin²θ + cos²θ = {}",
+                        self.locale.format(sin * sin + cos * cos, 3)
+                    ),
+                    text_color,
+                ),
+                // on the unit circle, the arc is as long as θ in radians
+                ValueLine::ArcLength => (
+                    format!(
+                        "arc length = {}",
+                        self.locale.format(self.theta, 3)
+                    ),
+                    text_color,
+                ),
+                ValueLine::Rate => {
+                    let rate = if self.is_running { self.rate } else { 0.0 };
+                    let grey = if self.theme.is_dark() { 0.6 } else { 0.4 };
+                    (
+                        format!("rate = {}", self.fmt_rate(rate)),
+                        Rgba::new(grey, grey, grey, 1.0),
+                    )
+                }
+            };
+
+            draw.text(&text)
+                .xy(slot)
+                .layout(&font_layout(18, Italic, Left))
+                .color(color);
+
+            if let Some(text) = self.fmt_comparison_line(line) {
+                draw.text(&text)
+                    .xy(slot - vec2(0.0, 18.0))
+                    .layout(&font_layout(14, Italic, Left))
+                    .color(Rgba::new(color.red, color.green, color.blue, 0.6));
+            }
+
+            if line == ValueLine::Rate {
+                self.draw_ramp(draw, slot, color);
+            }
+        }
+
+        if self.draw_range_bars {
+            self.draw_range_bars(draw);
+        }
+    }
+
+    /// Draws the waveform and each circle's frequency and amplitude in place
+    /// of the value panel.
+    fn draw_epicycle_values(&self, draw: &Draw) {
+        let shade = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let text_color = Rgba::new(shade, shade, shade, 1.0);
+        let circles = self.epicycles.circles();
+
+        let lines = [
+            format!("{} wave", self.epicycles.waveform().name()),
+            format!("θ = {}", self.fmt_theta()),
+            format!(
+                "y = {}",
+                self.locale
+                    .format(Epicycles::value_at(&circles, self.theta), 3)
+            ),
+        ]
+        .into_iter()
+        .chain(circles.iter().map(|&(freq, amp)| {
+            let multiple = if (freq - 1.0).abs() < f32::EPSILON {
+                String::new()
+            }
+            else {
+                self.locale.format(freq, 0)
+            };
+            format!("{} sin {multiple}θ", self.locale.format(amp, 3))
+        }));
+
+        // the circles are listed at half height, as there can be many
+        let slots = self.value_slots.iter().map(|&(_, slot)| slot);
+        let top = slots.clone().next().unwrap_or_default();
+
+        for (i, text) in lines.enumerate() {
+            let (pos, size) = if i < 3 {
+                (top - vec2(0.0, i as f32 * 30.0), 18)
+            }
+            else {
+                (top - vec2(0.0, 90.0 + (i - 3) as f32 * 20.0), 14)
+            };
+
+            draw.text(&text)
+                .xy(pos)
+                .layout(&font_layout(size, Italic, Left))
+                .color(text_color);
+        }
+    }
+
+    /// Draws the hyperbolic angle and functions in place of the value panel.
+    fn draw_hyperbolic_values(&self, draw: &Draw) {
+        let HyperbolicValues { t, sinh, cosh, tanh } = self.hyperbolic;
+        let shade = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let text_color = Rgb::new(shade, shade, shade);
+        let fmt = |val: f32| self.locale.format(val, 3);
+
+        let lines = [
+            (format!("t = {}", fmt(t)), text_color),
+            (format!("{SINH_LABEL} = {}", fmt(sinh)), SIN_COLOR),
+            (format!("{COSH_LABEL} = {}", fmt(cosh)), COS_COLOR),
+            (format!("{TANH_LABEL} = {}", fmt(tanh)), self.tan_color()),
+            (
+                format!("cosh²t − sinh²t = {}", fmt(cosh * cosh - sinh * sinh)),
+                text_color,
+            ),
+            (format!("shaded area = t/2 = {}", fmt(t * 0.5)), text_color),
+        ];
+
+        for ((text, color), &(_, slot)) in lines.iter().zip(&self.value_slots) {
+            draw.text(text)
+                .xy(slot)
+                .layout(&font_layout(18, Italic, Left))
+                .color(*color);
+        }
+    }
+
+    /// Formats the line shown beneath `line` in the value panel while
+    /// comparing against ψ, such as `sin ψ = 0.5`.
+    fn fmt_comparison_line(&self, line: ValueLine) -> Option<String> {
+        if !self.comparison.is_active() {
+            return None;
+        }
+
+        let psi = self.comparison.psi();
+        let TrigValues { sin, cos, tan, cot, sec, csc } =
+            self.comparison.values();
+        let format = self.display.format_of(Label::Theta);
+
+        let (name, val, label) = match line {
+            ValueLine::Theta => {
+                return Some(format!(
+                    "ψ = {}, θ − ψ = {}",
+                    self.fmt_angle(psi, format.decimals, format.exact),
+                    self.fmt_angle(
+                        self.comparison.difference(self.theta),
+                        format.decimals,
+                        format.exact,
+                    ),
+                ));
+            }
+            ValueLine::Value(Label::Sin) => (SIN_LABEL, sin, Label::Sin),
+            ValueLine::Value(Label::Cos) => (COS_LABEL, cos, Label::Cos),
+            ValueLine::Value(Label::Tan) => (TAN_LABEL, tan, Label::Tan),
+            ValueLine::Value(Label::Cot) => (COT_LABEL, cot, Label::Cot),
+            ValueLine::Value(Label::Sec) => (SEC_LABEL, sec, Label::Sec),
+            ValueLine::Value(Label::Csc) => (CSC_LABEL, csc, Label::Csc),
+            _ => return None,
+        };
+
+        Some(format!(
+            "{} = {}",
+            name.replace('θ', "ψ"),
+            self.fmt_value_at(label, val, psi)
+        ))
+    }
+
+    /// Formats a line of the value panel such as `sin θ = 0.5`, if `label`
+    /// is a function.
+    fn fmt_value_line(&self, label: Label) -> Option<String> {
+        let TrigValues { sin, cos, tan, cot, sec, csc } = self.trig_values;
+        let (name, val) = match label {
+            Label::Sin => (SIN_LABEL, sin),
+            Label::Cos => (COS_LABEL, cos),
+            Label::Tan => (TAN_LABEL, tan),
+            Label::Cot => (COT_LABEL, cot),
+            Label::Sec => (SEC_LABEL, sec),
+            Label::Csc => (CSC_LABEL, csc),
+            Label::Theta | Label::Unit => return None,
+        };
+
+        Some(format!("{name} = {}", self.fmt_value(label, val)))
+    }
+
+    /// Draws each pinned value as a tag joined to its line's label, and the
+    /// value being dragged out of the panel.
+    pub fn draw_pins(&self, draw: &Draw) {
+        let bg = self.bg_color();
+
+        let draw_tag = |label: Label, center: Vec2| {
+            let Some(text) = self.fmt_value_line(label)
+            else {
+                return;
+            };
+            let color = self.label_color(label);
+
+            draw.rect()
+                .xy(center)
+                .wh(tag_size())
+                .color(bg)
+                .stroke_weight(1.5)
+                .stroke(color);
+            draw.text(&text)
+                .xy(center)
+                .layout(&font_layout(14, Italic, Center))
+                .color(color);
+        };
+
+        for &pin in self.pins.iter() {
+            let anchor = self.label_position(pin.label);
+            let center = anchor + pin.offset;
+
+            draw.line()
+                .start(anchor)
+                .end(center)
+                .stroke_weight(1.0)
+                .color(self.label_color(pin.label));
+            draw_tag(pin.label, center);
+        }
+
+        if let Some((label, pos)) = self.pins.dragging() {
+            // only once it's been dragged off its row
+            if !value_rect_contains(&self.value_rects, label, pos) {
+                draw_tag(label, pos);
+            }
+        }
+    }
+
+    /// Draws the heading of a group in the value panel, with a triangle
+    /// pointing down while it's expanded and right while it's collapsed.
+    fn draw_value_heading(&self, draw: &Draw, group: ValueGroup, slot: Vec2) {
+        let grey = if self.theme.is_dark() { 0.6 } else { 0.4 };
+        let color = Rgb::new(grey, grey, grey);
+        let tip = vec2(value_rect(slot).left() + 10.0, slot.y);
+
+        if self.value_groups.is_collapsed(group) {
+            draw.tri()
+                .points(
+                    tip + vec2(-3.0, 5.0),
+                    tip + vec2(-3.0, -5.0),
+                    tip + vec2(5.0, 0.0),
+                )
+                .color(color);
+        }
+        else {
+            draw.tri()
+                .points(
+                    tip + vec2(-5.0, 3.0),
+                    tip + vec2(5.0, 3.0),
+                    tip + vec2(0.0, -5.0),
+                )
+                .color(color);
+        }
+
+        draw.text(group.name())
+            .xy(slot + vec2(20.0, 0.0))
+            .layout(&font_layout(14, Regular, Left))
+            .color(color);
+    }
+
+    /// Draws a bar beneath each value showing where it sits within `[-1, 1]`,
+    /// or on a log scale for the unbounded functions.
+    fn draw_range_bars(&self, draw: &Draw) {
+        const WIDTH: f32 = 120.0;
+
+        let TrigValues { sin, cos, tan, cot, sec, csc } = self.trig_values;
+        let rows = [
+            (Label::Sin, sin, true),
+            (Label::Cos, cos, true),
+            (Label::Tan, tan, false),
+            (Label::Cot, cot, false),
+            (Label::Sec, sec, false),
+            (Label::Csc, csc, false),
+        ];
+        let track = if self.theme.is_dark() { 0.35 } else { 0.65 };
+
+        for (label, val, is_bounded) in rows {
+            let Some(slot) = self.value_slot(ValueLine::Value(label))
+            else {
+                continue;
+            };
+            let center = value_rect(slot).xy() - vec2(0.0, 20.0);
+            let along = |pos: f32| center + vec2(pos * WIDTH * 0.5, 0.0);
+
+            draw.line()
+                .start(along(-1.0))
+                .end(along(1.0))
+                .color(Rgb::new(track, track, track))
+                .stroke_weight(1.0);
+
+            // ticks at 0, and at ±1 on the log scale
+            let ticks: &[f32] = if is_bounded {
+                &[0.0]
+            }
+            else {
+                &[-1.0, 0.0, 1.0]
+            };
+            for &tick in ticks {
+                let pos = range_bar_position(tick, is_bounded);
+                draw.line()
+                    .start(along(pos) - vec2(0.0, 4.0))
+                    .end(along(pos) + vec2(0.0, 4.0))
+                    .color(Rgb::new(track, track, track))
+                    .stroke_weight(1.0);
+            }
+
+            let pos = range_bar_position(val, is_bounded);
+            draw.line()
+                .start(along(0.0))
+                .end(along(pos))
+                .color(self.label_color(label))
+                .stroke_weight(STROKE_WEIGHT);
+            draw.ellipse()
+                .xy(along(pos))
+                .radius(3.0)
+                .color(self.label_color(label));
+        }
+    }
+
+    /// Formats the value of `label`'s function according to its display
+    /// settings.
+    fn fmt_value(&self, label: Label, val: f32) -> String {
+        self.fmt_value_at(label, val, self.theta)
+    }
+
+    /// Formats the value of `label`'s function at `angle`, which is exact
+    /// where `angle` is a special angle.
+    fn fmt_value_at(&self, label: Label, val: f32, angle: f32) -> String {
+        let format = self.display.format_of(label);
+        let is_special = special_angle_twelfths(angle).is_some();
+
+        // some values can be infinite (clamped to f32::MAX), so this
+        // handles very large values in such a case
+        if val > 1.0e9 {
+            String::from("inf")
+        }
+        else if val < -1.0e9 {
+            String::from("-inf")
+        }
+        else if let Some(exact) =
+            exact_value(val).filter(|_| format.exact && is_special)
+        {
+            exact
+        }
+        else {
+            self.locale.format(val, format.decimals)
+        }
+    }
+
+    /// Formats the point on the circle as the complex number `cos θ + i sin θ`.
+    fn fmt_z(&self) -> String {
+        fmt_complex(
+            &self.fmt_value(Label::Cos, self.trig_values.cos),
+            &self.fmt_value(Label::Sin, self.trig_values.sin),
+        )
+    }
+
+    /// Formats θ in radians (as a fraction of π where exact) and degrees,
+    /// followed by θ − θ₀ if there's a reference ray.
+    fn fmt_theta(&self) -> String {
+        let format = self.display.format_of(Label::Theta);
+        let fmt = |angle| {
+            let text = self.fmt_angle(angle, format.decimals, format.exact);
+
+            // radians are hard to picture, so degrees are shown alongside
+            // them
+            if self.angle_unit == AngleUnit::Radians {
+                format!(
+                    "{text} ({})",
+                    self.fmt_angle_in(AngleUnit::Degrees, angle, 0, false)
+                )
+            }
+            else {
+                text
+            }
+        };
+
+        match self.reference_ray {
+            Some(_) => format!(
+                "{}, θ − θ₀ = {}",
+                fmt(self.theta),
+                fmt(self.measured_theta())
+            ),
+            None => fmt(self.theta),
+        }
+    }
+
+    /// Formats `angle` in the selected unit, as an exact fraction at the
+    /// special angles if `exact` is set.
+    fn fmt_angle(&self, angle: f32, decimals: usize, exact: bool) -> String {
+        self.fmt_angle_in(self.angle_unit, angle, decimals, exact)
+    }
+
+    fn fmt_angle_in(
+        &self,
+        unit: AngleUnit,
+        angle: f32,
+        decimals: usize,
+        exact: bool,
+    ) -> String {
+        let twelfths = special_angle_twelfths(angle).filter(|_| exact);
+
+        match unit {
+            AngleUnit::Radians => twelfths.map_or_else(
+                || self.locale.format(angle, decimals),
+                fmt_pi_fraction,
+            ),
+            AngleUnit::Degrees => {
+                format!("{}º", self.locale.format(angle.to_degrees(), 0))
+            }
+            AngleUnit::Turns => {
+                let turns = twelfths.map_or_else(
+                    || self.locale.format(angle / TAU, decimals.max(3)),
+                    fmt_turn_fraction,
+                );
+                format!("{turns} turn")
+            }
+            AngleUnit::PiMultiples => {
+                // within about half a degree
+                const TOLERANCE: f32 = 0.003;
+
+                nearest_fraction(
+                    angle / PI,
+                    self.state.max_pi_denominator,
+                    TOLERANCE,
+                )
+                .map_or_else(
+                    || format!("{}π", self.locale.format(angle / PI, decimals)),
+                    |(num, den)| fmt_pi_multiple(num, den),
+                )
+            }
+        }
+    }
+
+    /// Formats a rate in the selected unit per second.
+    fn fmt_rate(&self, rate: f32) -> String {
+        match self.angle_unit {
+            AngleUnit::Radians => format!(
+                "{} rad/s\n           ({} deg/s)",
+                self.locale.format(rate, 2),
+                self.locale.format(rate.to_degrees(), 0)
+            ),
+            AngleUnit::Degrees => {
+                format!("{}º/s", self.locale.format(rate.to_degrees(), 0))
+            }
+            AngleUnit::Turns => {
+                format!("{} turns/s", self.locale.format(rate / TAU, 3))
+            }
+            AngleUnit::PiMultiples => {
+                format!("{}π rad/s", self.locale.format(rate / PI, 3))
+            }
+        }
+    }
+
+    /// The current value table as (name, value) rows.
+    pub fn value_rows(&self) -> Vec<(String, String)> {
+        let TrigValues { sin, cos, tan, cot, sec, csc } = self.trig_values;
+
+        let mut rows = vec![
+            (String::from("θ"), self.fmt_theta()),
+            (String::from(SIN_LABEL), self.fmt_value(Label::Sin, sin)),
+            (String::from(COS_LABEL), self.fmt_value(Label::Cos, cos)),
+            (String::from(TAN_LABEL), self.fmt_value(Label::Tan, tan)),
+            (String::from(COT_LABEL), self.fmt_value(Label::Cot, cot)),
+            (String::from(SEC_LABEL), self.fmt_value(Label::Sec, sec)),
+            (String::from(CSC_LABEL), self.fmt_value(Label::Csc, csc)),
+        ];
+
+        if self.complex_plane {
+            rows.insert(1, (String::from("z"), self.fmt_z()));
+        }
+
+        rows
+    }
+
+    /// Exports the value table as a large-print PDF and a Braille ASCII
+    /// (BRF) file.
+    pub fn export_value_table(&self) -> std::io::Result<()> {
+        let title = "Unit circle values";
+        let rows = self.value_rows();
+
+        let pdf_path = export_path("values", "pdf")?;
+        write_large_print_pdf(&pdf_path, title, &rows, &self.watermark)?;
+        let brf_path = export_path("values", "brf")?;
+        write_brf(&brf_path, title, &rows)?;
+
+        println!(
+            "exported value table to {} and {}",
+            pdf_path.display(),
+            brf_path.display()
+        );
+        Ok(())
+    }
+
+    /// Adds a bookmark at θ, or removes the one already there.
+    pub fn toggle_bookmark(&mut self) {
+        let theta = self.theta;
+        let len = self.bookmarks.len();
+        self.bookmarks.retain(|&b| (b - theta).abs() > 1.0e-3);
+
+        if self.bookmarks.len() == len {
+            self.bookmarks.push(theta);
+        }
+    }
+
+    /// Exports the history recorded so far as a web page which plays it
+    /// back.
+    pub fn export_replay(&self) -> std::io::Result<()> {
+        let lines = self
+            .draw_order
+            .iter()
+            .filter_map(|&label| {
+                let function = label.function()?;
+                self.visible
+                    .shows(function)
+                    .then(|| (label.name(), self.function_color(function)))
+            })
+            .collect();
+
+        let replay = Replay {
+            samples: self.history.session().to_vec(),
+            lines,
+            bookmarks: self.bookmarks.clone(),
+        };
+        if replay.samples.is_empty() {
+            return Err(std::io::Error::other("nothing has been recorded yet"));
+        }
+
+        let path = export_path("replay", "html")?;
+        replay.write(&path)?;
+
+        println!("exported replay to {}", path.display());
+        Ok(())
+    }
+
+    /// Writes the layout, lines, labels and values in the scene to a text
+    /// file, for diffing against a dump from another version.
+    pub fn dump_scene(&self) -> std::io::Result<()> {
+        let mut dump = SceneDump::default();
+
+        dump.rect("window", self.layout.window());
+        dump.rect("circle_region", self.layout.circle());
+        dump.value("mirror", self.layout.mirror.name());
+        dump.value("radius", format!("{:.2}", self.radius));
+        dump.value("theta", format!("{:.4}", self.theta));
+        dump.value("mode", format!("{:?}", self.mode));
+        dump.value("theme", format!("{:?}", self.theme));
+
+        for Segment { start, end, color } in self.trig_segments() {
+            dump.line("line", start, end, color);
+        }
+
+        dump.point("node", self.node());
+        for bookmark in &self.bookmarks {
+            dump.value("bookmark", format!("{bookmark:.4}"));
+        }
+
+        for label in Label::ALL {
+            dump.value(
+                &format!("label {}", label.name()),
+                format!(
+                    "at ({:.2}, {:.2}) opacity {:.3} side {:.2}",
+                    self.labels.get_position(label).x,
+                    self.labels.get_position(label).y,
+                    self.labels.get_opacity(label),
+                    self.labels.side(label),
+                ),
+            );
+        }
+
+        if self.draw_values {
+            for &(line, slot) in &self.value_slots {
+                let name = format!("value {line:?}");
+                match line {
+                    ValueLine::Value(label) => {
+                        if let Some(text) = self.fmt_value_line(label) {
+                            let color = self.label_color(label);
+                            dump.text(&name, &text, slot, color);
+                        }
+                    }
+                    _ => dump.point(&name, slot),
+                }
+            }
+        }
+
+        let path = export_path("scene", "txt")?;
+        dump.write(&path)?;
+
+        println!("dumped scene to {}", path.display());
+        Ok(())
+    }
+
+    /// Exports a Markdown summary of the session: the settings, values,
+    /// bookmarked angles and links to everything exported.
+    pub fn export_session_notes(&self) -> std::io::Result<()> {
+        let on_off = |on: bool| String::from(if on { "on" } else { "off" });
+        let visible = [
+            (SIN_LABEL, self.visible.sin),
+            (COS_LABEL, self.visible.cos),
+            (TAN_LABEL, self.visible.tan),
+            (COT_LABEL, self.visible.cot),
+            (SEC_LABEL, self.visible.sec),
+            (CSC_LABEL, self.visible.csc),
+        ]
+        .into_iter()
+        .filter_map(|(label, is_visible)| is_visible.then_some(label))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+        let notes = SessionNotes {
+            settings: vec![
+                (String::from("θ"), self.fmt_theta()),
+                (
+                    String::from("rate"),
+                    format!("{} rad/s", self.locale.format(self.rate, 2)),
+                ),
+                (String::from("running"), on_off(self.is_running)),
+                (String::from("visible functions"), visible),
+                (String::from("labels"), on_off(self.draw_labels)),
+                (String::from("complex plane"), on_off(self.complex_plane)),
+                (
+                    String::from("session time"),
+                    fmt_duration(self.clock.elapsed_secs(), self.locale),
+                ),
+            ],
+            values: self.value_rows(),
+            bookmarks: self.bookmarks.clone(),
+            started: self.session_start,
+        };
+
+        let path = export_path("session_notes", "md")?;
+        notes.write(&path)?;
+
+        println!("exported session notes to {}", path.display());
+        Ok(())
+    }
+
+    pub fn export_poster(&self) -> std::io::Result<()> {
+        export_poster(self.complex_plane, &self.watermark)
+    }
+
+    /// Exports worksheet versions of the poster and value table, with some
+    /// of the values left blank, along with their answer keys.
+    pub fn export_worksheet(&self) -> std::io::Result<()> {
+        let fraction = self.state.worksheet_blanks;
+
+        let angles = standard_angles(self.complex_plane);
+        let poster_path = export_path("worksheet_poster", "pdf")?;
+        write_poster_pdf(
+            &poster_path,
+            "The unit circle: worksheet",
+            &blank_poster(&angles, fraction),
+            &self.watermark,
+        )?;
+        let poster_key_path = export_path("worksheet_poster_answers", "pdf")?;
+        write_poster_pdf(
+            &poster_key_path,
+            "The unit circle: answers",
+            &angles,
+            &self.watermark,
+        )?;
+
+        let rows = self.value_rows();
+        let table_path = export_path("worksheet_values", "pdf")?;
+        write_large_print_pdf(
+            &table_path,
+            "Unit circle values: worksheet",
+            &blank_rows(&rows, fraction),
+            &self.watermark,
+        )?;
+        let table_key_path = export_path("worksheet_values_answers", "pdf")?;
+        write_large_print_pdf(
+            &table_key_path,
+            "Unit circle values: answers",
+            &rows,
+            &self.watermark,
+        )?;
+
+        println!(
+            "exported worksheets to {} and {}, with answers in {} and {}",
+            poster_path.display(),
+            table_path.display(),
+            poster_key_path.display(),
+            table_key_path.display()
+        );
+        Ok(())
+    }
+
+    /// Starts rendering a flashcard image for each standard angle, with the
+    /// values shown and hidden.
+    pub fn export_flashcards(&mut self) -> std::io::Result<()> {
+        if self.flashcards.is_none() {
+            let export = FlashcardExport::new(export_dir("flashcards")?);
+            self.flashcards = Some((export, self.snapshot()));
+        }
+
+        Ok(())
+    }
+
+    /// Starts logging the values to a file in `format`, or stops logging if
+    /// it's already started.
+    pub fn toggle_recording(
+        &mut self,
+        format: LogFormat,
+    ) -> std::io::Result<()> {
+        if let Some(recorder) = self.recorder.take() {
+            let (path, subtitles) = recorder.finish()?;
+            println!("logged data to {}", path.display());
+
+            if let Some(subtitles) = subtitles {
+                println!("wrote captions to {}", subtitles.display());
+            }
+        }
+        else {
+            self.recorder = Some(Recorder::start(format)?);
+        }
+
+        Ok(())
+    }
+
+    /// Keeps a copy of the current lines to compare against, or clears it if
+    /// there already is one.
+    pub fn toggle_before(&mut self) {
+        self.before = match self.before {
+            Some(_) => None,
+            None => Some(Before {
+                segments: self.trig_segments(),
+                radius: self.radius,
+                theta: self.theta,
+            }),
+        };
+    }
+
+    /// Starts rendering one revolution of theta, to be saved as a GIF.
+    pub fn export_animation(&mut self) -> std::io::Result<()> {
+        if self.animation.is_none() && self.flashcards.is_none() {
+            let export = AnimationExport::new(export_dir("revolution_frames")?);
+            self.animation = Some((export, self.snapshot()));
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the frames of a finished animation into a GIF, once they've
+    /// all been written.
+    pub fn finish_animation(&mut self, app: &App) {
+        let Some(frames) = self.finished_animation.take()
+        else {
+            return;
+        };
+
+        // frames are saved in the background, so they may not all be on disk
+        // yet
+        if !self.await_captures(app) {
+            eprintln!("timed out waiting for animation frames to be saved");
+        }
+
+        match export_path("revolution", "gif") {
+            Ok(out) => spawn_gif_encoder(frames, out),
+            Err(e) => eprintln!("failed to export animation: {e}"),
+        }
+    }
+
+    /// The path to save the current frame to while exporting flashcards or
+    /// an animation.
+    pub fn capture_path(&self) -> Option<std::path::PathBuf> {
+        self.flashcards
+            .as_ref()
+            .and_then(|(export, _)| export.current_path())
+            .or_else(|| {
+                self.animation
+                    .as_ref()
+                    .and_then(|(export, _)| export.current_path())
+            })
+    }
+
+    /// Outlines the safe area with a dashed rectangle while it's being
+    /// adjusted.
+    pub fn draw_safe_area(&self, draw: &Draw) {
+        const DASH: f32 = 12.0;
+        const GAP: f32 = 8.0;
+
+        if !self.adjusting_safe_area {
+            return;
+        }
+
+        let area = self.layout.window();
+        let corners = [
+            area.top_left(),
+            area.top_right(),
+            area.bottom_right(),
+            area.bottom_left(),
+        ];
+
+        for (i, &start) in corners.iter().enumerate() {
+            let end = corners[(i + 1) % corners.len()];
+            let len = start.distance(end);
+            let dir = (end - start).normalize_or_zero();
+            let mut along = 0.0;
+
+            while along < len {
+                draw.line()
+                    .start(start + dir * along)
+                    .end(start + dir * (along + DASH).min(len))
+                    .color(MEASURE_COLOR)
+                    .stroke_weight(2.0);
+                along += DASH + GAP;
+            }
+        }
+    }
+
+    /// Draws the time scale while everything is running faster or slower
+    /// than real time.
+    pub fn draw_time_scale(&self, draw: &Draw) {
+        if (self.time_scale - 1.0).abs() < f32::EPSILON {
+            return;
+        }
+
+        let color = if self.theme.is_dark() { 0.6 } else { 0.4 };
+        let scale = if self.time_scale < 1.0 {
+            format!("1/{}", self.locale.format(self.time_scale.recip(), 0))
+        }
+        else {
+            self.locale.format(self.time_scale, 0)
+        };
+
+        draw.text(&format!("time × {scale}"))
+            .xy(self.layout.status_slot(4))
+            .layout(&font_layout(18, Italic, Left))
+            .color(Rgba::new(color, color, color, 1.0));
+    }
+
+    pub fn draw_clock(&self, draw: &Draw) {
+        if !self.draw_clock {
+            return;
+        }
+
+        let clock_color = if self.theme.is_dark() { 0.6 } else { 0.4 };
+        let rev = Clock::revolution_secs(self.rate)
+            .filter(|_| self.is_running)
+            .map_or_else(
+                || String::from("--"),
+                |secs| format!("{} s", self.locale.format(secs, 1)),
+            );
+
+        draw.text(&format!(
+            "time = {}\nrev = {}",
+            fmt_duration(self.clock.elapsed_secs(), self.locale),
+            rev
+        ))
+        .xy(self.layout.status_slot(0))
+        .layout(&font_layout(18, Italic, Left))
+        .color(Rgb::new(clock_color, clock_color, clock_color));
+    }
+
+    pub fn draw_sound(&self, draw: &Draw) {
+        const PERIODS: f32 = 3.0;
+        const POINTS: usize = 240;
+
+        if !self.sound.is_active() {
+            return;
+        }
+
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let hz = if self.is_running {
+            pitch_hz(self.rate)
+        }
+        else {
+            0.0
+        };
+        let center = self.layout.window_center();
+        let (left, width, height) = (center.x - 300.0, 600.0, 35.0);
+        let y = self.layout.window().bottom() + 110.0;
+
+        draw.line()
+            .start(vec2(left, y))
+            .end(vec2(left + width, y))
+            .color(Rgba::new(color, color, color, 0.2))
+            .stroke_weight(1.0);
+
+        // the last few periods, ending at the current value of sin θ
+        draw.polyline()
+            .weight(STROKE_WEIGHT - 1.0)
+            .points((0..=POINTS).map(|i| {
+                let t = i as f32 / POINTS as f32;
+                let phase = self.theta - (1.0 - t) * PERIODS * TAU;
+                vec2(left + t * width, y + phase.sin() * height)
+            }))
+            .color(SIN_COLOR);
+
+        let readout = if hz > 0.0 {
+            let period_ms = 1000.0 / hz;
+            let note = nearest_note(hz)
+                .map_or_else(String::new, |(name, cents)| {
+                    format!(" ≈ {name} ({cents:+} cents)")
+                });
+
+            format!(
+                "f = {} Hz{note}\nT = {} ms  (window = {} ms)",
+                self.locale.format(hz, 1),
+                self.locale.format(period_ms, 2),
+                self.locale.format(period_ms * PERIODS, 1)
+            )
+        }
+        else {
+            String::from("f = 0 Hz\nT = --")
+        };
+
+        draw.text(&readout)
+            .xy(vec2(left + 100.0, y - height - 30.0))
+            .layout(&font_layout(16, Italic, Left))
+            .color(Rgba::new(color, color, color, 0.8));
+    }
+
+    pub fn draw_mic(&self, draw: &Draw) {
+        let color = if self.theme.is_dark() { 0.6 } else { 0.4 };
+        let target = match self.mic.mode {
+            MicMode::Off => return,
+            MicMode::Rate => String::from("rate"),
+            MicMode::Amplitude => String::from("size"),
+            MicMode::Timecode => self.mic.timecode().map_or_else(
+                || String::from("timecode (waiting for LTC)"),
+                |timecode| format!("timecode {timecode}"),
+            ),
+        };
+
+        draw.text(&format!("microphone → {target}"))
+            .xy(self.layout.status_slot(2))
+            .layout(&font_layout(18, Italic, Left))
+            .color(Rgb::new(color, color, color));
+    }
+
+    pub fn draw_recording(&self, draw: &Draw) {
+        let Some(recorder) = &self.recorder
+        else {
+            return;
+        };
+        let format = match recorder.format() {
+            LogFormat::Csv => "CSV",
+            LogFormat::JsonLines => "JSON",
+        };
+
+        draw.text(&format!(
+            "logging {format}  {}",
+            fmt_duration(recorder.elapsed(), self.locale)
+        ))
+        .xy(vec2(self.layout.circle().x(), self.layout.circle().top() - 20.0))
+        .w(self.layout.circle().w())
+        .layout(&font_layout(18, Italic, Center))
+        .color(RECORDING_COLOR);
+    }
+
+    /// Draws the keys and clicks pressed in the last moment in the bottom
+    /// left corner, newest at the bottom.
+    pub fn draw_key_cast(&self, draw: &Draw) {
+        let corner = self.layout.window().bottom_left();
+        let bg = self.bg_color();
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let presses: Vec<_> = self.keycast.presses().collect();
+
+        for (i, (press, opacity)) in presses.iter().rev().enumerate() {
+            let pos = corner + vec2(170.0, 40.0 + i as f32 * 45.0);
+
+            draw.rect()
+                .xy(pos)
+                .w_h(300.0, 36.0)
+                .color(Rgba::new(bg.red, bg.green, bg.blue, 0.85 * opacity))
+                .stroke(Rgba::new(color, color, color, 0.5 * opacity))
+                .stroke_weight(1.0);
+            draw.text(press)
+                .xy(pos)
+                .w(280.0)
+                .layout(&font_layout(20, Regular, Center))
+                .color(Rgba::new(color, color, color, *opacity));
+        }
+    }
+
+    #[cfg(feature = "inspector")]
+    pub fn draw_inspector(&self, draw: &Draw) {
+        if !self.inspector.is_visible() {
+            return;
+        }
+
+        let corner = self.layout.window().top_left();
+        let bg = self.bg_color();
+        let color = if self.theme.is_dark() { 0.8 } else { 0.2 };
+        let lines = self.inspector.lines();
+
+        draw.rect()
+            .xy(corner + vec2(220.0, -10.0 - lines.len() as f32 * 8.0))
+            .w_h(430.0, 10.0 + lines.len() as f32 * 16.0)
+            .color(Rgba::new(bg.red, bg.green, bg.blue, 0.85));
+
+        for (i, line) in lines.iter().enumerate() {
+            draw.text(line)
+                .xy(corner + vec2(220.0, -15.0 - i as f32 * 16.0))
+                .w(420.0)
+                .layout(&font_layout(13, Regular, Left))
+                .color(Rgb::new(color, color, color));
+        }
+    }
+
+    /// Draws the frame rate and the time spent on each part of a frame in
+    /// the top corner of the window.
+    pub fn draw_stats(&self, draw: &Draw) {
+        if !self.stats.is_visible() {
+            return;
+        }
+
+        let corner = self.layout.window().top_right();
+        let color = if self.theme.is_dark() { 0.8 } else { 0.2 };
+
+        for (i, line) in self.stats.lines().iter().enumerate() {
+            draw.text(line)
+                .xy(corner - vec2(70.0, 15.0 + i as f32 * 16.0))
+                .w(120.0)
+                .layout(&font_layout(14, Regular, Left))
+                .color(Rgb::new(color, color, color));
+        }
+    }
+
+    pub fn draw_caption(&self, draw: &Draw) {
+        let Some(caption) = &self.caption
+        else {
+            return;
+        };
+        let color = if self.theme.is_dark() { 0.8 } else { 0.2 };
+
+        draw.text(caption)
+            .xy(self.layout.caption())
+            .w(self.layout.circle().w())
+            .layout(&font_layout(22, Italic, Center))
+            .color(Rgb::new(color, color, color));
+    }
+
+    /// Draws the title card of a chapter which just started, and the chapter
+    /// menu if it's open.
+    pub fn draw_chapters(&self, draw: &Draw) {
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let bg = self.bg_color();
+
+        if let Some((title, alpha)) = self.chapters.title_card() {
+            draw.rect()
+                .xy(Vec2::ZERO)
+                .w_h(self.layout.circle().w(), 90.0)
+                .color(Rgba::new(bg.red, bg.green, bg.blue, 0.85 * alpha));
+
+            draw.text(title)
+                .xy(Vec2::ZERO)
+                .w(self.layout.circle().w())
+                .layout(&font_layout(40, Regular, Center))
+                .color(Rgba::new(color, color, color, alpha));
+        }
+
+        if !self.chapters.is_menu_visible() {
+            return;
+        }
+
+        let chapters = self.chapters.list();
+        let height = 90.0 + chapters.len().clamp(1, 9) as f32 * 26.0;
+        let center = self.layout.circle().xy();
+        let top = center.y + height * 0.5;
+
+        draw.rect()
+            .xy(center)
+            .w_h(400.0, height)
+            .color(Rgba::new(bg.red, bg.green, bg.blue, 0.95))
+            .stroke(Rgba::new(color, color, color, 0.5))
+            .stroke_weight(1.0);
+
+        draw.text("Chapters")
+            .xy(vec2(center.x, top - 30.0))
+            .w(360.0)
+            .layout(&font_layout(24, Regular, Center))
+            .color(Rgb::new(color, color, color));
+
+        if chapters.is_empty() {
+            draw.text(&format!("{SCRIPT_FILE} has no chapters"))
+                .xy(vec2(center.x, top - 70.0))
+                .w(360.0)
+                .layout(&font_layout(16, Italic, Center))
+                .color(Rgb::new(color, color, color));
+        }
+
+        for (i, chapter) in chapters.iter().take(9).enumerate() {
+            let marker = if self.chapters.current() == Some(i) {
+                "•"
+            }
+            else {
+                " "
+            };
+
+            draw.text(&format!("{marker} {}   {}", i + 1, chapter.title))
+                .xy(vec2(center.x, top - 70.0 - i as f32 * 26.0))
+                .w(360.0)
+                .layout(&font_layout(16, Regular, Left))
+                .color(Rgb::new(color, color, color));
+        }
+    }
+
+    /// Draws an overview of the whole circle in the corner when it's scaled
+    /// up past the edges of its region.
+    pub fn draw_minimap(&self, draw: &Draw) {
+        const SIZE: f32 = 120.0;
+        const MAP_RADIUS: f32 = 45.0;
+
+        let region = self.layout.circle();
+        if self.radius <= region.w().min(region.h()) * 0.5 {
+            return;
+        }
+
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let bg = self.bg_color();
+        let center = vec2(
+            region.right() - SIZE * 0.5 - 15.0,
+            region.bottom() + SIZE * 0.5 + 15.0,
+        );
+        let scale = MAP_RADIUS / self.radius;
+
+        draw.rect()
+            .xy(center)
+            .w_h(SIZE, SIZE)
+            .color(Rgba::new(bg.red, bg.green, bg.blue, 0.9))
+            .stroke(Rgba::new(color, color, color, 0.4))
+            .stroke_weight(1.0);
+
+        draw.ellipse()
+            .no_fill()
+            .radius(MAP_RADIUS)
+            .stroke_weight(1.0)
+            .stroke(Rgba::new(color, color, color, 0.6))
+            .xy(center);
+
+        draw.ellipse()
+            .radius(3.0)
+            .color(Rgb::new(color, color, color))
+            .xy(center
+                + self.layout.mirror.apply(self.node() / self.radius)
+                    * MAP_RADIUS);
+
+        // the part of the scene in view, clipped to the minimap
+        let visible =
+            Rect::from_xy_wh(center + region.xy() * scale, region.wh() * scale);
+        if let Some(visible) =
+            visible.overlap(Rect::from_xy_wh(center, vec2(SIZE, SIZE)))
+        {
+            draw.rect()
+                .no_fill()
+                .xy(visible.xy())
+                .wh(visible.wh())
+                .stroke(MEASURE_COLOR)
+                .stroke_weight(1.5);
+        }
+    }
+
+    pub fn draw_history(&self, draw: &Draw) {
+        if !self.draw_history {
+            return;
+        }
+
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let slider = self.history_slider_rect();
+        let pos = self.history.position();
+        let handle = vec2(slider.left() + pos * slider.w(), slider.y());
+
+        draw.line()
+            .start(vec2(slider.left(), slider.y()))
+            .end(vec2(slider.right(), slider.y()))
+            .color(Rgba::new(color, color, color, 0.3))
+            .stroke_weight(STROKE_WEIGHT);
+
+        draw.line()
+            .start(vec2(slider.left(), slider.y()))
+            .end(handle)
+            .color(Rgba::new(color, color, color, 0.7))
+            .stroke_weight(STROKE_WEIGHT);
+
+        draw.ellipse()
+            .radius(7.0)
+            .color(Rgb::new(color, color, color))
+            .xy(handle);
+
+        let duration = self.history.duration_secs();
+
+        draw.text(&format!(
+            "history  {} / {}",
+            fmt_duration(duration * pos, self.locale),
+            fmt_duration(duration, self.locale)
+        ))
+        .xy(vec2(slider.left() + 100.0, slider.y() + 20.0))
+        .layout(&font_layout(16, Italic, Left))
+        .color(Rgba::new(color, color, color, 0.7));
+    }
+
+    /// Draws a ring around the element with keyboard focus.
+    pub fn draw_focus(&self, draw: &Draw) {
+        const PADDING: f32 = 6.0;
+
+        let color = if self.theme.is_dark() { WHITE } else { BLACK };
+
+        let rect = match self.focus.current() {
+            Some(Focusable::ValueRow(i)) => {
+                let Some(&(_, rect)) = self.value_rects.get(i)
+                else {
+                    return;
+                };
+                rect
+            }
+            Some(Focusable::HistorySlider) => self.history_slider_rect(),
+            Some(Focusable::Protractor) => {
+                draw.ellipse()
+                    .no_fill()
+                    .radius(self.radius + PADDING)
+                    .stroke_weight(2.0)
+                    .stroke(color);
+                return;
+            }
+            None => return,
+        };
+
+        draw.rect()
+            .xy(rect.xy())
+            .wh(rect.wh() + Vec2::splat(PADDING * 2.0))
+            .no_fill()
+            .stroke_weight(2.0)
+            .stroke(color);
+    }
+
+    pub fn draw_countdown(&self, draw: &Draw) {
+        if !self.draw_countdown {
+            return;
+        }
+
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let (y, x) = self.countdown.target.sin_cos();
+
+        draw.line()
+            .start(vec2(x, y) * (self.radius - 10.0))
+            .end(vec2(x, y) * (self.radius + 10.0))
+            .color(Rgba::new(color, color, color, 0.6))
+            .stroke_weight(STROKE_WEIGHT - 1.0);
+
+        let remaining = self
+            .countdown
+            .remaining_secs(self.theta, self.rate)
+            .filter(|_| self.is_running)
+            .map_or_else(
+                || String::from("--"),
+                |secs| format!("{} s", self.locale.format(secs, 1)),
+            );
+
+        draw.text(&format!(
+            "target = {} ({}º)\nin {}{}",
+            self.locale.format(self.countdown.target, 2),
+            self.locale.format(self.countdown.target.to_degrees(), 0),
+            remaining,
+            if self.countdown.auto_pause {
+                ", then pause"
+            }
+            else {
+                ""
+            },
+        ))
+        .xy(self.layout.status_slot(1))
+        .layout(&font_layout(18, Italic, Left))
+        .color(Rgba::new(color, color, color, 0.6));
+    }
+
+    pub fn draw_de_moivre(&self, draw: &Draw) {
+        const ARC_POINTS: usize = 48;
+
+        if !self.de_moivre.is_active() {
+            return;
+        }
+
+        let on_circle = |angle: f32, radius: f32| {
+            let (y, x) = angle.sin_cos();
+            vec2(x, y) * radius
+        };
+        let list_top = self.layout.list_top();
+
+        for k in 1..=self.de_moivre.power() {
+            let start = self.theta * (k - 1) as f32;
+            let end = self.theta * k as f32;
+            let pt = on_circle(end, self.radius);
+
+            // successive arcs are drawn further out so they don't overlap
+            let arc_radius = self.radius + 8.0 + k as f32 * 5.0;
+            draw.polyline()
+                .weight(STROKE_WEIGHT - 1.0)
+                .points((0..=ARC_POINTS).map(|i| {
+                    let t = i as f32 / ARC_POINTS as f32;
+                    on_circle(start + (end - start) * t, arc_radius)
+                }))
+                .color(Rgba::new(
+                    DE_MOIVRE_COLOR.red,
+                    DE_MOIVRE_COLOR.green,
+                    DE_MOIVRE_COLOR.blue,
+                    0.6,
+                ));
+
+            draw.ellipse().radius(5.0).color(DE_MOIVRE_COLOR).xy(pt);
+
+            self.upright(draw, on_circle(end, self.radius - 22.0))
+                .text(&fmt_power(k))
+                .layout(&font_layout(LABEL_FONT_SIZE, Italic, Center))
+                .color(DE_MOIVRE_COLOR);
+
+            let multiple = if k == 1 { String::new() } else { k.to_string() };
+            self.unmirrored(draw)
+                .text(&format!(
+                    "{} = e^(i{multiple}θ) at {multiple}θ = {}º",
+                    fmt_power(k),
+                    self.locale.format(end.to_degrees().rem_euclid(360.0), 0)
+                ))
+                .xy(list_top - vec2(0.0, (k - 1) as f32 * 18.0))
+                .layout(&font_layout(16, Italic, Left))
+                .color(DE_MOIVRE_COLOR);
+        }
+    }
+
+    /// Draws the nested-triangles construction of the angle-sum identities
+    /// for α = θ and β = φ, with each segment annotated, and the identities
+    /// with their values.
+    pub fn draw_identities(&self, draw: &Draw) {
+        if !self.identities.is_active() {
+            return;
+        }
+
+        let alpha = self.theta;
+        let beta = self.angle_sum.phi;
+        let cons = Construction::new(alpha, beta);
+        let shade = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let faint = Rgba::new(shade, shade, shade, 0.4);
+        let at = |pt: Vec2| pt * self.radius;
+
+        // the hypotenuse, and the sides the identities add up to
+        draw.line()
+            .start(Vec2::ZERO)
+            .end(at(cons.p))
+            .color(faint)
+            .stroke_weight(STROKE_WEIGHT - 1.0);
+        draw.line()
+            .start(at(cons.s))
+            .end(at(cons.p))
+            .color(faint)
+            .stroke_weight(1.0);
+
+        for (start, end, text, from_cos) in cons.segments() {
+            let color = if from_cos {
+                IDENTITY_COS_COLOR
+            }
+            else {
+                IDENTITY_SIN_COLOR
+            };
+            let (start, end) = (at(start), at(end));
+
+            draw.line()
+                .start(start)
+                .end(end)
+                .color(color)
+                .stroke_weight(STROKE_WEIGHT - 1.0);
+
+            if start.distance(end) > 20.0 {
+                // beside the middle of the segment, away from the middle of
+                // the construction
+                let mid = (start + end) * 0.5;
+                let normal = (end - start).perp().normalize_or_zero();
+                let centre = at((cons.p + cons.r) * 0.5);
+                let side = if (mid - centre).dot(normal) < 0.0 {
+                    -1.0
+                }
+                else {
+                    1.0
+                };
+
+                self.draw_outlined_text(
+                    draw,
+                    text,
+                    mid + normal * side * 16.0,
+                    Rgba::new(color.red, color.green, color.blue, 1.0),
+                );
+            }
+        }
+
+        for pt in [cons.p, cons.q, cons.r, cons.t] {
+            draw.ellipse().radius(3.0).color(faint).xy(at(pt));
+        }
+
+        let (sin_a, cos_a) = alpha.sin_cos();
+        let (sin_b, cos_b) = beta.sin_cos();
+        let fmt = |val: f32| self.locale.format(val, 3);
+        let lines = [
+            format!(
+                "sin(α + β) = sin α cos β + cos α sin β = {} + {} = {}",
+                fmt(sin_a * cos_b),
+                fmt(cos_a * sin_b),
+                fmt((alpha + beta).sin()),
+            ),
+            format!(
+                "cos(α + β) = cos α cos β − sin α sin β = {} − {} = {}",
+                fmt(cos_a * cos_b),
+                fmt(sin_a * sin_b),
+                fmt((alpha + beta).cos()),
+            ),
+            format!(
+                "α = θ = {}º, β = φ = {}º",
+                self.locale.format(alpha.to_degrees(), 0),
+                self.locale.format(beta.to_degrees(), 0),
+            ),
+        ];
+        let draw = self.unmirrored(draw);
+        let list_top = self.layout.list_top();
+
+        for (i, line) in lines.iter().enumerate() {
+            draw.text(line)
+                .xy(list_top - vec2(0.0, i as f32 * 18.0))
+                .layout(&font_layout(16, Italic, Left))
+                .color(Rgba::new(shade, shade, shade, 0.8));
+        }
+    }
+
+    /// Draws φ and θ + φ on their own small circles, with the arc from θ to
+    /// θ + φ on the main circle and the values of θ + φ.
+    pub fn draw_angle_sum(&self, draw: &Draw) {
+        const ARC_POINTS: usize = 48;
+        const SIZE: f32 = 120.0;
+        const MINI_RADIUS: f32 = 45.0;
+
+        if !self.angle_sum.is_active() {
+            return;
+        }
+
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let bg = self.bg_color();
+        let on_circle = |angle: f32, radius: f32| {
+            let (y, x) = angle.sin_cos();
+            vec2(x, y) * radius
+        };
+        let phi = self.angle_sum.phi;
+        let sum = (self.theta + phi).rem_euclid(TAU);
+
+        // the arc from θ to θ + φ on the main circle
+        let arc_radius = self.radius + 12.0;
+        draw.polyline()
+            .weight(STROKE_WEIGHT - 1.0)
+            .points((0..=ARC_POINTS).map(|i| {
+                let t = i as f32 / ARC_POINTS as f32;
+                on_circle(self.theta + phi * t, arc_radius)
+            }))
+            .color(ANGLE_SUM_COLOR);
+        draw.line()
+            .start(Vec2::ZERO)
+            .end(on_circle(sum, self.radius))
+            .color(ANGLE_SUM_COLOR)
+            .stroke_weight(STROKE_WEIGHT - 1.0);
+        draw.ellipse()
+            .radius(5.0)
+            .color(ANGLE_SUM_COLOR)
+            .xy(on_circle(sum, self.radius));
+
+        let region = self.layout.circle();
+        let circles = [("φ", phi), ("θ + φ", sum)];
+
+        for (i, (name, angle)) in circles.into_iter().enumerate() {
+            let center = vec2(
+                region.right() - SIZE * 0.5 - 15.0,
+                region.top() - SIZE * 0.5 - 15.0 - i as f32 * (SIZE + 10.0),
+            );
+            let pt = center + on_circle(angle, MINI_RADIUS);
+
+            draw.rect()
+                .xy(center)
+                .w_h(SIZE, SIZE)
+                .color(Rgba::new(bg.red, bg.green, bg.blue, 0.9))
+                .stroke(Rgba::new(color, color, color, 0.4))
+                .stroke_weight(1.0);
+            draw.ellipse()
+                .no_fill()
+                .radius(MINI_RADIUS)
+                .stroke_weight(1.0)
+                .stroke(Rgba::new(color, color, color, 0.6))
+                .xy(center);
+
+            // sin and cos of the angle, as on the main circle
+            draw.line()
+                .start(vec2(pt.x, center.y))
+                .end(pt)
+                .color(SIN_COLOR)
+                .stroke_weight(1.5);
+            draw.line()
+                .start(center)
+                .end(vec2(pt.x, center.y))
+                .color(COS_COLOR)
+                .stroke_weight(1.5);
+            draw.line()
+                .start(center)
+                .end(pt)
+                .color(ANGLE_SUM_COLOR)
+                .stroke_weight(1.5);
+
+            self.upright(
+                draw,
+                center + vec2(-SIZE * 0.5 + 25.0, SIZE * 0.5 - 12.0),
+            )
+            .text(name)
+            .layout(&font_layout(LABEL_FONT_SIZE, Italic, Center))
+            .color(ANGLE_SUM_COLOR);
+        }
+
+        let (sin, cos) = sum.sin_cos();
+        let readout = format!(
+            "φ = {}º (rate {})\nθ + φ = {}º\n\
+             sin(θ + φ) = {}\ncos(θ + φ) = {}\ntan(θ + φ) = {}",
+            self.locale.format(phi.to_degrees(), 0),
+            self.locale.format(self.angle_sum.rate, 2),
+            self.locale.format(sum.to_degrees(), 0),
+            self.fmt_value(Label::Sin, sin),
+            self.fmt_value(Label::Cos, cos),
+            self.fmt_value(Label::Tan, sin / cos),
+        );
+
+        self.unmirrored(draw)
+            .text(&readout)
+            .xy(self.layout.legend_slot(2))
+            .layout(&font_layout(16, Italic, Left))
+            .color(ANGLE_SUM_COLOR);
+    }
+
+    /// Shades the principal branch of the selected inverse function, and
+    /// marks the principal value alongside the other angle with the same
+    /// value.
+    pub fn draw_inverse(&self, draw: &Draw) {
+        const ARC_POINTS: usize = 64;
+
+        if self.inverse == InverseMode::Off {
+            return;
+        }
+
+        let on_circle = |angle: f32, radius: f32| {
+            let (y, x) = angle.sin_cos();
+            vec2(x, y) * radius
+        };
+        let (start, end) = self.inverse.principal_range();
+        let arc = |radius: f32| {
+            (0..=ARC_POINTS).map(move |i| {
+                let t = i as f32 / ARC_POINTS as f32;
+                on_circle(start + (end - start) * t, radius)
+            })
+        };
+
+        draw.polygon()
+            .points(arc(self.radius).chain([Vec2::ZERO]))
+            .color(Rgba::new(
+                INVERSE_COLOR.red,
+                INVERSE_COLOR.green,
+                INVERSE_COLOR.blue,
+                0.12,
+            ));
+        draw.polyline()
+            .weight(STROKE_WEIGHT)
+            .points(arc(self.radius + 6.0))
+            .color(INVERSE_COLOR);
+
+        let principal = self.inverse.principal(self.theta);
+        let alternative = self.inverse.alternative(principal);
+
+        draw.ellipse()
+            .radius(6.0)
+            .color(INVERSE_COLOR)
+            .xy(on_circle(principal, self.radius + 6.0));
+        // the other solution is shown as a ghost of the principal value
+        draw.ellipse()
+            .no_fill()
+            .radius(6.0)
+            .stroke_weight(1.5)
+            .stroke(Rgba::new(
+                INVERSE_COLOR.red,
+                INVERSE_COLOR.green,
+                INVERSE_COLOR.blue,
+                0.5,
+            ))
+            .xy(on_circle(alternative, self.radius + 6.0));
+
+        let fmt_angle = |angle: f32| {
+            let sign = if angle < -1.0e-6 { "-" } else { "" };
+            special_angle_twelfths(angle.abs()).map_or_else(
+                || format!("{sign}{}", self.locale.format(angle.abs(), 2)),
+                |twelfths| format!("{sign}{}", fmt_pi_fraction(twelfths)),
+            )
+        };
+
+        self.unmirrored(draw)
+            .text(&format!(
+                "{}({} θ) = {}\nsolutions: {} + 2πk,\n    {} + 2πk",
+                self.inverse.name(),
+                self.inverse.function_name(),
+                fmt_angle(principal),
+                fmt_angle(principal),
+                fmt_angle(alternative),
+            ))
+            .xy(self.layout.status_slot(3))
+            .layout(&font_layout(18, Italic, Left))
+            .color(INVERSE_COLOR);
+    }
+
+    /// Draws fading marks at the last few strobe flashes, with the actual and
+    /// apparent rates and a caption explaining the effect.
+    pub fn draw_strobe(&self, draw: &Draw) {
+        if !self.strobe.is_active() {
+            return;
+        }
+
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let flashes: Vec<f32> = self.strobe.flashes().collect();
+
+        for (i, &flash) in flashes.iter().enumerate() {
+            let alpha = (i + 1) as f32 / flashes.len() as f32 * 0.5;
+            let (y, x) = flash.sin_cos();
+
+            draw.ellipse()
+                .no_fill()
+                .radius(8.0)
+                .stroke_weight(1.5)
+                .stroke(Rgba::new(color, color, color, alpha))
+                .xy(vec2(x, y) * self.radius);
+        }
+
+        let rate = if self.is_running { self.rate } else { 0.0 };
+        let apparent = apparent_rate(rate);
+        let text_color = if self.theme.is_dark() { 0.8 } else { 0.2 };
+        let draw = self.unmirrored(draw);
+
+        draw.text(&format!(
+            "strobe = {} flashes/s\nrate = {} rad/s\napparent = {} rad/s",
+            self.locale.format(STROBE_HZ, 0),
+            self.locale.format(rate, 2),
+            self.locale.format(apparent, 2),
+        ))
+        .xy(self.layout.legend_slot(1))
+        .layout(&font_layout(18, Italic, Left))
+        .color(Rgb::new(text_color, text_color, text_color));
+
+        // the script's caption takes priority
+        if self.caption.is_none() {
+            let explanation = if apparent.abs() < 0.01 {
+                "each flash catches the point in the same place, so it seems \
+                 to stand still"
+            }
+            else if apparent < 0.0 {
+                "each flash catches the point just short of a full turn, \
+                 so it seems to turn backwards"
+            }
+            else {
+                "each flash catches the point just past a whole number of \
+                 turns, so it seems to turn slowly"
+            };
+
+            draw.text(explanation)
+                .xy(self.layout.caption())
+                .w(self.layout.circle().w() - 40.0)
+                .layout(&font_layout(18, Italic, Center))
+                .color(Rgb::new(text_color, text_color, text_color));
+        }
+    }
+
+    pub fn draw_measure(&self, draw: &Draw) {
+        const ARC_POINTS: usize = 64;
+
+        if !self.measure.is_active() {
+            return;
+        }
+
+        let on_circle = |angle: f32| {
+            let (y, x) = angle.sin_cos();
+            vec2(x, y) * self.radius
+        };
+
+        if let Some(start) = self.measure.start() {
+            draw.ellipse()
+                .radius(5.0)
+                .color(MEASURE_COLOR)
+                .xy(on_circle(start));
+        }
+
+        let readout = if let Some((start, span)) = self.measure.interval() {
+            let end = start + span;
+
+            draw.ellipse()
+                .radius(5.0)
+                .color(MEASURE_COLOR)
+                .xy(on_circle(end));
+
+            draw.line()
+                .start(on_circle(start))
+                .end(on_circle(end))
+                .color(Rgba::new(
+                    MEASURE_COLOR.red,
+                    MEASURE_COLOR.green,
+                    MEASURE_COLOR.blue,
+                    0.5,
+                ))
+                .stroke_weight(STROKE_WEIGHT - 1.0);
+
+            draw.polyline()
+                .weight(STROKE_WEIGHT)
+                .points((0..=ARC_POINTS).map(|i| {
+                    let t = i as f32 / ARC_POINTS as f32;
+                    on_circle(start + span * t) * (1.0 + 12.0 / self.radius)
+                }))
+                .color(MEASURE_COLOR);
+
+            self.upright(
+                draw,
+                on_circle(start + span * 0.5) * (1.0 + 40.0 / self.radius),
+            )
+            .text(&format!(
+                "{} ({}º)",
+                self.locale.format(span, 2),
+                self.locale.format(span.to_degrees(), 0)
+            ))
+            .layout(&font_layout(LABEL_FONT_SIZE, Italic, Center))
+            .color(MEASURE_COLOR);
+
+            format!(
+                "Δθ = {} ({}º)\narc = {}\nchord = {}",
+                self.locale.format(span, 2),
+                self.locale.format(span.to_degrees(), 0),
+                self.locale.format(span, 2),
+                self.locale.format(chord_length(span), 2),
+            )
+        }
+        else {
+            String::from("measure: click two\npoints on the circle")
+        };
+
+        self.unmirrored(draw)
+            .text(&readout)
+            .xy(self.layout.status_bottom())
+            .layout(&font_layout(18, Italic, Left))
+            .color(MEASURE_COLOR);
+    }
+
+    pub fn draw_tour(&self, draw: &Draw) {
+        let Some((idx, step)) = self.tour.current()
+        else {
+            return;
+        };
+
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let highlight_color = Rgba::new(color, color, color, 0.8);
+
+        match step.highlight {
+            Highlight::Circle => {
+                draw.ellipse()
+                    .no_fill()
+                    .radius(self.radius + 8.0)
+                    .stroke_weight(STROKE_WEIGHT)
+                    .stroke(highlight_color)
+                    .xy(Vec2::ZERO);
+            }
+            Highlight::Segment(label) => {
+                draw.ellipse()
+                    .no_fill()
+                    .radius(26.0)
+                    .stroke_weight(STROKE_WEIGHT)
+                    .stroke(self.label_color(label))
+                    .xy(self.label_position(label));
+            }
+            Highlight::ValuePanel => {
+                draw.rect()
+                    .no_fill()
+                    .xy(self.layout.values().xy())
+                    .wh(self.layout.values().wh() - 30.0)
+                    .stroke_weight(STROKE_WEIGHT - 1.0)
+                    .stroke(highlight_color);
+            }
+            Highlight::None => {}
+        }
+
+        let bg = self.bg_color();
+        let caption = vec2(
+            self.layout.window_center().x,
+            self.layout.window().bottom() + 60.0,
+        );
+
+        draw.rect()
+            .xy(caption)
+            .w_h(600.0, 90.0)
+            .color(Rgba::new(bg.red, bg.green, bg.blue, 0.85))
+            .stroke(Rgba::new(color, color, color, 0.5))
+            .stroke_weight(1.0);
+
+        draw.text(&format!(
+            "{}\n{}/{} — Space to continue, Esc to skip",
+            step.caption,
+            idx + 1,
+            Tour::num_steps()
+        ))
+        .xy(caption)
+        .w(560.0)
+        .layout(&font_layout(18, Italic, Center))
+        .color(Rgb::new(color, color, color));
     }
 
-    pub fn draw_unit_circle(&self, draw: &Draw) {
+    /// Draws the calibration screen: a grey ramp, which should show every
+    /// step from black to white, and a swatch of each function's colour.
+    pub fn draw_calibration(&self, draw: &Draw) {
+        const STEPS: usize = 16;
+        const SWATCHES: [(Rgb, &str); 6] = [
+            (SIN_COLOR, SIN_LABEL),
+            (COS_COLOR, COS_LABEL),
+            (TAN_COLOR, TAN_LABEL),
+            (COT_COLOR, COT_LABEL),
+            (SEC_COLOR, SEC_LABEL),
+            (CSC_COLOR, CSC_LABEL),
+        ];
+
+        if !self.calibration.is_shown() {
+            return;
+        }
+
         let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let bg = self.bg_color();
+        let center = self.layout.window_center();
+        let width = 640.0;
 
-        draw.ellipse()
-            .no_fill()
-            .radius(self.radius)
-            .stroke_weight(STROKE_WEIGHT - 0.3)
-            .stroke(Rgba::new(color, color, color, 0.3))
-            .xy(Vec2::ZERO);
+        draw.rect()
+            .xy(center)
+            .w_h(width + 40.0, 400.0)
+            .color(bg)
+            .stroke(Rgba::new(color, color, color, 0.5))
+            .stroke_weight(1.0);
 
-        if self.draw_theta {
-            self.draw_theta_circle(draw);
+        draw.text("Calibration")
+            .xy(vec2(center.x, center.y + 160.0))
+            .w(width)
+            .layout(&font_layout(24, Regular, Center))
+            .color(Rgb::new(color, color, color));
+
+        let step_width = width / STEPS as f32;
+        for i in 0..STEPS {
+            let grey = i as f32 / (STEPS - 1) as f32;
+            draw.rect()
+                .xy(vec2(
+                    center.x - width * 0.5 + step_width * (i as f32 + 0.5),
+                    center.y + 90.0,
+                ))
+                .w_h(step_width, 60.0)
+                .color(Rgb::new(grey, grey, grey));
+        }
+
+        let swatch_width = width / SWATCHES.len() as f32;
+        for (i, (swatch, label)) in SWATCHES.into_iter().enumerate() {
+            let x = center.x - width * 0.5 + swatch_width * (i as f32 + 0.5);
+
+            draw.rect()
+                .xy(vec2(x, center.y - 5.0))
+                .w_h(swatch_width - 10.0, 60.0)
+                .color(swatch);
+            draw.text(label)
+                .xy(vec2(x, center.y - 55.0))
+                .w(swatch_width)
+                .layout(&font_layout(16, Regular, Center))
+                .color(swatch);
         }
+
+        draw.text(&format!(
+            "brightness {}   contrast {}",
+            self.locale.format(self.calibration.brightness(), 2),
+            self.locale.format(self.calibration.contrast(), 2),
+        ))
+        .xy(vec2(center.x, center.y - 100.0))
+        .w(width)
+        .layout(&font_layout(18, Regular, Center))
+        .color(Rgb::new(color, color, color));
+
+        draw.text(
+            "Up / Down for brightness, Left / Right for contrast, 0 to \
+             reset, Esc to close",
+        )
+        .xy(vec2(center.x, center.y - 150.0))
+        .w(width)
+        .layout(&font_layout(16, Italic, Center))
+        .color(Rgb::new(color, color, color));
     }
 
-    pub fn draw_node(&self, draw: &Draw) {
-        let pt = Vec2::new(
-            self.trig_values.cos * self.radius,
-            self.trig_values.sin * self.radius,
-        );
+    pub fn draw_quit_prompt(&self, draw: &Draw) {
+        if !self.quit_prompt {
+            return;
+        }
 
         let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let bg = self.bg_color();
+        let center = self.layout.window_center();
 
-        draw.ellipse()
-            .radius(8.0)
-            .color(Rgba::new(color, color, color, 0.75))
-            .xy(pt);
-    }
+        draw.rect()
+            .xy(center)
+            .w_h(460.0, 130.0)
+            .color(Rgba::new(bg.red, bg.green, bg.blue, 0.95))
+            .stroke(Rgba::new(color, color, color, 0.5))
+            .stroke_weight(1.0);
 
-    #[rustfmt::skip]
-    pub fn draw_trig_lines(&self, draw: &Draw) {
-        if self.visible.sin { self.draw_sin_line(draw); }
-        if self.visible.cos { self.draw_cos_line(draw); }
-        if self.visible.tan { self.draw_tan_line(draw); }
-        if self.visible.cot { self.draw_cot_line(draw); }
-        if self.visible.sec { self.draw_sec_line(draw); }
-        if self.visible.csc { self.draw_csc_line(draw); }
+        draw.text("Quit?")
+            .xy(vec2(center.x, center.y + 30.0))
+            .w(420.0)
+            .layout(&font_layout(24, Regular, Center))
+            .color(Rgb::new(color, color, color));
+
+        draw.text("Unsaved notes and bookmarks will be lost")
+            .xy(vec2(center.x, center.y - 5.0))
+            .w(420.0)
+            .layout(&font_layout(16, Regular, Center))
+            .color(Rgb::new(color, color, color));
 
-        self.draw_unit_line(draw);
+        draw.text("Enter or Y to quit, any other key to stay")
+            .xy(vec2(center.x, center.y - 40.0))
+            .w(420.0)
+            .layout(&font_layout(16, Italic, Center))
+            .color(Rgb::new(color, color, color));
     }
 
-    pub fn draw_values(&self, draw: &Draw) {
-        if !self.draw_values {
+    pub fn draw_whats_new(&self, draw: &Draw) {
+        if !self.whats_new || self.tour.is_active() {
             return;
         }
 
-        // some values can be infinite (clamped to f32::MAX), so this
-        // handles very large values in such a case
-        let fmt_large = |val: f32| {
-            if val > 1.0e9 {
-                String::from("inf")
-            }
-            else if val < -1.0e9 {
-                String::from("-inf")
-            }
-            else {
-                format!("{val:.2}")
-            }
-        };
+        let color = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let bg = self.bg_color();
 
-        // sin
-        draw.text(&format!("{} = {:.2}", SIN_LABEL, self.trig_values.sin))
-            .xy(vec2(430.0, 150.0))
-            .layout(&font_layout(18, Italic, Left))
-            .color(self.label_color(Label::Sin));
-        // cos
-        draw.text(&format!("{} = {:.2}", COS_LABEL, self.trig_values.cos))
-            .xy(vec2(430.0, 100.0))
-            .layout(&font_layout(18, Italic, Left))
-            .color(self.label_color(Label::Cos));
-        // tan
-        draw.text(&format!(
-            "{} = {}",
-            TAN_LABEL,
-            fmt_large(self.trig_values.tan)
-        ))
-        .xy(vec2(430.0, 50.0))
-        .layout(&font_layout(18, Italic, Left))
-        .color(self.label_color(Label::Tan));
-        // cot
-        draw.text(&format!(
-            "{} = {}",
-            COT_LABEL,
-            fmt_large(self.trig_values.cot)
-        ))
-        .xy(vec2(430.0, -50.0))
-        .layout(&font_layout(18, Italic, Left))
-        .color(self.label_color(Label::Cot));
-        // sec
-        draw.text(&format!(
-            "{} = {}",
-            SEC_LABEL,
-            fmt_large(self.trig_values.sec)
-        ))
-        .xy(vec2(430.0, -100.0))
-        .layout(&font_layout(18, Italic, Left))
-        .color(self.label_color(Label::Sec));
-        // csc
-        draw.text(&format!(
-            "{} = {}",
-            CSC_LABEL,
-            fmt_large(self.trig_values.csc)
-        ))
-        .xy(vec2(430.0, -150.0))
-        .layout(&font_layout(18, Italic, Left))
-        .color(self.label_color(Label::Csc));
+        let pages = self.whats_new_pages();
+        // the window may have shrunk since the page was turned
+        let page = self.whats_new_page.min(pages.len().saturating_sub(1));
+        let entries = pages.get(page).cloned().unwrap_or_default();
+        let height =
+            WHATS_NEW_PADDING + entries.len() as f32 * WHATS_NEW_ROW_HEIGHT;
+        let center = self.layout.window_center();
+        let top = center.y + height * 0.5;
 
-        // theta
-        if self.draw_theta {
-            draw.text(&format!(
-                "θ = {:.2} ({:.0}º)",
-                self.theta,
-                self.theta.to_degrees()
-            ))
-            .xy(vec2(430.0, 200.0))
-            .layout(&font_layout(18, Italic, Left))
-            .color(if self.theme.is_dark() {
-                WHITE
-            }
-            else {
-                BLACK
-            });
+        draw.rect()
+            .xy(center)
+            .w_h(560.0, height)
+            .color(Rgba::new(bg.red, bg.green, bg.blue, 0.95))
+            .stroke(Rgba::new(color, color, color, 0.5))
+            .stroke_weight(1.0);
+
+        draw.text(&format!("What's new in {VERSION}"))
+            .xy(vec2(center.x, top - 35.0))
+            .w(500.0)
+            .layout(&font_layout(24, Regular, Center))
+            .color(Rgb::new(color, color, color));
+
+        for (i, entry) in entries.iter().enumerate() {
+            draw.text(&format!("•  {entry}"))
+                .xy(vec2(
+                    center.x,
+                    top - 80.0 - i as f32 * WHATS_NEW_ROW_HEIGHT,
+                ))
+                .w(500.0)
+                .layout(&font_layout(16, Regular, Left))
+                .color(Rgb::new(color, color, color));
         }
 
-        // rate
-        let rate = if self.is_running { self.rate } else { 0.0 };
-        let rate_color = if self.theme.is_dark() { 0.6 } else { 0.4 };
+        let hint = if pages.len() > 1 {
+            format!(
+                "page {} of {}, ← → to turn · Space or Esc to close",
+                page + 1,
+                pages.len()
+            )
+        }
+        else {
+            String::from("Space or Esc to close")
+        };
 
-        draw.text(&format!(
-            // TODO come on...
-            "rate = {:.2} rad/s\n           ({:.0} deg/s)",
-            rate,
-            rate.to_degrees()
-        ))
-        .xy(vec2(430.0, -210.0))
-        .layout(&font_layout(18, Italic, Left))
-        .color(Rgb::new(rate_color, rate_color, rate_color));
+        draw.text(&hint)
+            .xy(vec2(center.x, top - height + 25.0))
+            .w(500.0)
+            .layout(&font_layout(16, Italic, Center))
+            .color(Rgb::new(color, color, color));
     }
 
     fn label_color(&self, label: Label) -> Rgba {
@@ -623,15 +5352,15 @@ impl Model {
                 if self.visible.cos { 1.0 } else { dimmed },
             ),
             Label::Tan => Rgba::new(
-                TAN_COLOR.red,
-                TAN_COLOR.green,
-                TAN_COLOR.blue,
+                self.tan_color().red,
+                self.tan_color().green,
+                self.tan_color().blue,
                 if self.visible.tan { 1.0 } else { dimmed },
             ),
             Label::Cot => Rgba::new(
-                COT_COLOR.red,
-                COT_COLOR.green,
-                COT_COLOR.blue,
+                self.cot_color().red,
+                self.cot_color().green,
+                self.cot_color().blue,
                 if self.visible.cot { 1.0 } else { dimmed },
             ),
             Label::Sec => Rgba::new(
@@ -659,18 +5388,22 @@ impl Model {
         let theta_color = if self.theme.is_dark() { 1.0 } else { 0.0 };
 
         if self.draw_labels {
-            draw.text("θ")
-                .xy(self.labels.get_position(Label::Theta))
-                .layout(&font_layout(LABEL_FONT_SIZE, Regular, Center))
-                .color(Rgba::new(
+            self.draw_label(
+                draw,
+                self.measured_name(),
+                Label::Theta,
+                Rgba::new(
                     theta_color,
                     theta_color,
                     theta_color,
                     self.labels.get_opacity(Label::Theta),
-                ));
+                ),
+            );
         }
 
-        let progress = self.theta / TAU;
+        // from the reference ray, if there is one
+        let (ray, theta) = (self.ray_angle(), self.measured_theta());
+        let progress = theta / TAU;
         let num_points = (THETA_POINTS as f32 * progress).ceil() as usize;
 
         // needed to prevent nan error
@@ -682,174 +5415,691 @@ impl Model {
             .weight(STROKE_WEIGHT)
             .points_colored((0..=num_points).map(|i| {
                 let t = i as f32 / num_points as f32;
-                let (y, x) = (self.theta * t).sin_cos();
+                let (y, x) = (ray + theta * t).sin_cos();
 
-                (
-                    vec2(x * self.radius, y * self.radius),
-                    Rgb::new(theta_color, theta_color, theta_color),
-                )
+                let color = if self.quadrant_arc {
+                    quadrant_color(theta * t)
+                }
+                else {
+                    Rgb::new(theta_color, theta_color, theta_color)
+                };
+
+                (vec2(x * self.radius, y * self.radius), color)
             }))
             .finish();
+
+        if self.quadrant_arc {
+            self.draw_quadrant_legend(draw);
+        }
     }
 
-    fn draw_sin_line(&self, draw: &Draw) {
+    /// Labels each quadrant in its colour, with the signs of sin and cos in
+    /// it.
+    fn draw_quadrant_legend(&self, draw: &Draw) {
+        const NAMES: [&str; 4] = ["I", "II", "III", "IV"];
+        const SIGNS: [&str; 4] = [
+            "sin +, cos +",
+            "sin +, cos −",
+            "sin −, cos −",
+            "sin −, cos +",
+        ];
+
+        for (i, color) in QUADRANT_COLORS.iter().enumerate() {
+            let (y, x) =
+                (self.ray_angle() + PI * 0.25 + i as f32 * PI * 0.5).sin_cos();
+            let pos = vec2(x, y) * (self.radius + 45.0);
+
+            self.upright(draw, pos)
+                .text(&format!("{}\n{}", NAMES[i], SIGNS[i]))
+                .w(120.0)
+                .layout(&font_layout(14, Regular, Center))
+                .color(*color);
+        }
+    }
+
+    /// Draws the label of a line, outlined in the background colour if
+    /// outlines are on so it stays readable where it crosses other lines.
+    fn draw_label(&self, draw: &Draw, text: &str, label: Label, color: Rgba) {
+        self.draw_outlined_text(
+            draw,
+            text,
+            self.labels.get_position(label),
+            color,
+        );
+    }
+
+    /// Draws `text` in the labels' font at `pos`, outlined while label
+    /// outlines are on.
+    fn draw_outlined_text(
+        &self,
+        draw: &Draw,
+        text: &str,
+        pos: Vec2,
+        color: Rgba,
+    ) {
+        const OUTLINE_WIDTH: f32 = 1.5;
+
+        let layout = font_layout(LABEL_FONT_SIZE, Regular, Center);
+        let draw = self.upright(draw, pos);
+
+        if self.label_outlines {
+            let bg = self.bg_color();
+
+            for i in 0..8 {
+                let (y, x) = (i as f32 * PI * 0.25).sin_cos();
+                draw.text(text)
+                    .xy(vec2(x, y) * OUTLINE_WIDTH)
+                    .layout(&layout)
+                    .color(Rgba::new(bg.red, bg.green, bg.blue, color.alpha));
+            }
+        }
+
+        draw.text(text).layout(&layout).color(color);
+    }
+
+    /// Draws `function`'s line for θ, through the geometry shared with
+    /// [`TrigCircle`](trig_visuals::TrigCircle) and the exports.
+    fn draw_function_line(&self, draw: &Draw, function: Function) {
+        let view = self.measured_view();
+        let (start, end) = self.trig_values.line(function);
+        let (start, end) = (view.to_pixels(start), view.to_pixels(end));
+
         draw.line()
-            .start(vec2(self.trig_values_scaled.cos, 0.0))
-            .end(vec2(self.trig_values_scaled.cos, self.trig_values_scaled.sin))
-            .color(SIN_COLOR)
+            .start(start)
+            .end(end)
+            .color(self.function_color(function))
             .stroke_weight(STROKE_WEIGHT);
+    }
+
+    fn draw_sin_line(&self, draw: &Draw) {
+        self.draw_function_line(draw, Function::Sin);
 
         if self.draw_labels {
-            draw.text(SIN_LABEL)
-                .xy(self.labels.get_position(Label::Sin))
-                .layout(&font_layout(LABEL_FONT_SIZE, Regular, Center))
-                .color(Rgba::new(
+            self.draw_label(
+                draw,
+                SIN_LABEL,
+                Label::Sin,
+                Rgba::new(
                     SIN_COLOR.red,
                     SIN_COLOR.green,
                     SIN_COLOR.blue,
                     self.labels.get_opacity(Label::Sin),
-                ));
+                ),
+            );
         }
     }
 
     fn draw_cos_line(&self, draw: &Draw) {
-        draw.line()
-            .start(Vec2::ZERO)
-            .end(vec2(self.trig_values_scaled.cos, 0.0))
-            .color(COS_COLOR)
-            .stroke_weight(STROKE_WEIGHT);
+        self.draw_function_line(draw, Function::Cos);
 
         if self.draw_labels {
-            draw.text(COS_LABEL)
-                .xy(self.labels.get_position(Label::Cos))
-                .layout(&font_layout(LABEL_FONT_SIZE, Regular, Center))
-                .color(Rgba::new(
+            self.draw_label(
+                draw,
+                COS_LABEL,
+                Label::Cos,
+                Rgba::new(
                     COS_COLOR.red,
                     COS_COLOR.green,
                     COS_COLOR.blue,
                     self.labels.get_opacity(Label::Cos),
-                ));
+                ),
+            );
         }
     }
 
     fn draw_tan_line(&self, draw: &Draw) {
-        draw.line()
-            .start(vec2(self.radius, 0.0))
-            .end(vec2(self.radius, self.trig_values_scaled.tan))
-            .color(TAN_COLOR)
-            .stroke_weight(STROKE_WEIGHT);
+        self.draw_function_line(draw, Function::Tan);
 
         if self.draw_labels {
-            draw.text(TAN_LABEL)
-                .xy(self.labels.get_position(Label::Tan))
-                .layout(&font_layout(LABEL_FONT_SIZE, Regular, Center))
-                .color(TAN_COLOR);
+            self.draw_label(
+                draw,
+                TAN_LABEL,
+                Label::Tan,
+                self.tan_color().into(),
+            );
         }
     }
 
     fn draw_cot_line(&self, draw: &Draw) {
-        draw.line()
-            .start(vec2(
-                self.trig_values_scaled.cos, self.trig_values_scaled.sin,
-            ))
-            .end(vec2(0.0, self.trig_values_scaled.csc))
-            .color(COT_COLOR)
-            .stroke_weight(STROKE_WEIGHT);
+        self.draw_function_line(draw, Function::Cot);
 
         if self.draw_labels {
-            draw.text(COT_LABEL)
-                .xy(self.labels.get_position(Label::Cot))
-                .layout(&font_layout(LABEL_FONT_SIZE, Regular, Center))
-                .color(COT_COLOR);
+            self.draw_label(
+                draw,
+                COT_LABEL,
+                Label::Cot,
+                self.cot_color().into(),
+            );
         }
     }
 
     fn draw_sec_line(&self, draw: &Draw) {
-        draw.line()
-            .start(Vec2::ZERO)
-            .end(vec2(self.radius, self.trig_values_scaled.tan))
-            .color(SEC_COLOR)
-            .stroke_weight(STROKE_WEIGHT);
+        self.draw_function_line(draw, Function::Sec);
 
         if self.draw_labels {
-            draw.text(SEC_LABEL)
-                .xy(self.labels.get_position(Label::Sec))
-                .layout(&font_layout(LABEL_FONT_SIZE, Regular, Center))
-                .color(Rgba::new(
+            self.draw_label(
+                draw,
+                SEC_LABEL,
+                Label::Sec,
+                Rgba::new(
                     SEC_COLOR.red,
                     SEC_COLOR.green,
                     SEC_COLOR.blue,
                     self.labels.get_opacity(Label::Sec),
-                ));
+                ),
+            );
         }
     }
 
     fn draw_csc_line(&self, draw: &Draw) {
+        self.draw_function_line(draw, Function::Csc);
+
+        if self.draw_labels {
+            self.draw_label(draw, CSC_LABEL, Label::Csc, CSC_COLOR.into());
+        }
+    }
+
+    /// Draws the chain of circles, each turning at its frequency about the
+    /// end of the last, and the waveform traced by the chain's end over the
+    /// last revolution.
+    pub fn draw_epicycles(&self, draw: &Draw) {
+        const WAVE_POINTS: usize = 240;
+
+        let circles = self.epicycles.circles();
+        let total: f32 = circles.iter().map(|&(_, amp)| amp.abs()).sum();
+        let region = self.layout.circle();
+        // the chain takes the left of the region and the wave the right
+        let unit = self.radius * 0.6 / total.max(1.0);
+        let center = vec2(region.left() + region.w() * 0.25, 0.0);
+        let wave_start = center.x + total * unit + 30.0;
+        let wave_len = region.right() - 20.0 - wave_start;
+        let shade = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let faint = Rgba::new(shade, shade, shade, 0.3);
+
+        let mut pt = center;
+        for &(freq, amp) in &circles {
+            let (y, x) = (freq * self.theta).sin_cos();
+            let next = pt + vec2(x, y) * amp * unit;
+
+            draw.ellipse()
+                .no_fill()
+                .radius(amp.abs() * unit)
+                .stroke_weight(1.0)
+                .stroke(faint)
+                .xy(pt);
+            draw.line()
+                .start(pt)
+                .end(next)
+                .color(Rgba::new(shade, shade, shade, 0.8))
+                .stroke_weight(STROKE_WEIGHT - 1.0);
+
+            pt = next;
+        }
+
+        draw.ellipse().radius(5.0).color(SIN_COLOR).xy(pt);
+
+        if wave_len <= 0.0 {
+            return;
+        }
+
+        // the wave scrolls out to the right, with the newest point at its
+        // start
+        let wave = (0..=WAVE_POINTS).map(|i| {
+            let t = i as f32 / WAVE_POINTS as f32;
+            let theta = self.theta - t * TAU;
+            vec2(
+                wave_start + t * wave_len,
+                center.y + Epicycles::value_at(&circles, theta) * unit,
+            )
+        });
+
+        draw.line()
+            .start(pt)
+            .end(vec2(wave_start, pt.y))
+            .color(faint)
+            .stroke_weight(1.0);
+        draw.line()
+            .start(vec2(wave_start, center.y))
+            .end(vec2(wave_start + wave_len, center.y))
+            .color(faint)
+            .stroke_weight(1.0);
+        draw.polyline()
+            .weight(STROKE_WEIGHT - 1.0)
+            .points(wave)
+            .color(SIN_COLOR);
+    }
+
+    /// Draws the unit hyperbola with its asymptotes, the area swept out by
+    /// the hyperbolic angle, and the lines of sinh, cosh and tanh.
+    pub fn draw_hyperbola(&self, draw: &Draw) {
+        const NUM_POINTS: usize = 96;
+        const EXTENT: f32 = MAX_HYPERBOLIC_ANGLE + 0.3;
+
+        let view = self.hyperbola_view();
+        let HyperbolicValues { t, cosh, tanh, .. } = self.hyperbolic;
+        let point = view.to_pixels(self.hyperbolic.point());
+        let shade = if self.theme.is_dark() { 1.0 } else { 0.0 };
+        let faint = Rgba::new(shade, shade, shade, 0.3);
+
+        // asymptotes
+        for dir in [vec2(1.0, 1.0), vec2(1.0, -1.0)] {
+            draw.line()
+                .start(view.to_pixels(-dir * EXTENT.cosh()))
+                .end(view.to_pixels(dir * EXTENT.cosh()))
+                .color(Rgba::new(shade, shade, shade, 0.15))
+                .stroke_weight(1.0);
+        }
+
+        // the area is half the hyperbolic angle, like a sector of the unit
+        // circle
+        if t.abs() > 1.0e-3 {
+            draw.polygon()
+                .points(
+                    std::iter::once(Vec2::ZERO).chain(
+                        hyperbola_points(0.0, t, NUM_POINTS / 2)
+                            .map(|pt| view.to_pixels(pt)),
+                    ),
+                )
+                .color(Rgba::new(shade, shade, shade, 0.12));
+        }
+
+        draw.polyline()
+            .weight(STROKE_WEIGHT - 0.3)
+            .points(
+                hyperbola_points(-EXTENT, EXTENT, NUM_POINTS)
+                    .map(|pt| view.to_pixels(pt)),
+            )
+            .color(faint);
+
         draw.line()
             .start(Vec2::ZERO)
-            .end(vec2(0.0, self.trig_values_scaled.csc))
-            .color(CSC_COLOR)
+            .end(point)
+            .color(faint)
             .stroke_weight(STROKE_WEIGHT);
 
-        if self.draw_labels {
-            draw.text(CSC_LABEL)
-                .xy(self.labels.get_position(Label::Csc))
-                .layout(&font_layout(LABEL_FONT_SIZE, Regular, Center))
-                .color(CSC_COLOR);
+        let lines = [
+            (
+                self.visible.cos,
+                Label::Cos,
+                COSH_LABEL,
+                Vec2::ZERO,
+                view.to_pixels(vec2(cosh, 0.0)),
+                COS_COLOR,
+            ),
+            (
+                self.visible.sin,
+                Label::Sin,
+                SINH_LABEL,
+                view.to_pixels(vec2(cosh, 0.0)),
+                point,
+                SIN_COLOR,
+            ),
+            (
+                self.visible.tan,
+                Label::Tan,
+                TANH_LABEL,
+                view.to_pixels(vec2(1.0, 0.0)),
+                view.to_pixels(vec2(1.0, tanh)),
+                self.tan_color(),
+            ),
+        ];
+
+        for (visible, label, text, start, end, color) in lines {
+            if !visible {
+                continue;
+            }
+
+            draw.line()
+                .start(start)
+                .end(end)
+                .color(color)
+                .stroke_weight(STROKE_WEIGHT);
+
+            if self.draw_labels {
+                self.draw_label(
+                    draw,
+                    text,
+                    label,
+                    Rgba::new(
+                        color.red,
+                        color.green,
+                        color.blue,
+                        self.labels.get_opacity(label),
+                    ),
+                );
+            }
         }
+
+        draw.ellipse()
+            .radius(8.0)
+            .color(Rgba::new(shade, shade, shade, 0.75))
+            .xy(point);
     }
 
     fn draw_unit_line(&self, draw: &Draw) {
         draw.line()
             .start(Vec2::ZERO)
-            .end(vec2(self.trig_values_scaled.cos, self.trig_values_scaled.sin))
+            .end(self.node())
             .color(Rgba::new(1.0, 1.0, 1.0, 0.2))
             .stroke_weight(STROKE_WEIGHT);
 
         if self.draw_labels {
             let unit_color = if self.theme.is_dark() { 0.8 } else { 0.2 };
 
-            draw.text("1")
-                .xy(self.labels.get_position(Label::Unit))
-                .layout(&font_layout(LABEL_FONT_SIZE, Regular, Center))
-                .color(Rgba::new(
+            self.draw_label(
+                draw,
+                "1",
+                Label::Unit,
+                Rgba::new(
                     unit_color,
                     unit_color,
                     unit_color,
                     self.labels.get_opacity(Label::Unit),
-                ));
+                ),
+            );
         }
     }
 }
 
-fn key_pressed(_app: &App, model: &mut Model, key: Key) {
+fn raw_window_event(
+    _app: &App,
+    model: &mut Model,
+    event: &nannou::winit::event::WindowEvent,
+) {
+    model.panel.handle_raw_event(event);
+}
+
+fn touch(app: &App, model: &mut Model, event: TouchEvent) {
+    model.handle_touch(event, app.time);
+}
+
+/// Handles the keys which depend on what's going on (the tour, keyboard focus
+/// and overlays), then performs the action the key is bound to.
+fn key_pressed(app: &App, model: &mut Model, key: Key) {
+    let shift = app.keys.mods.shift();
+    model.cast_key(key, app.keys.mods);
+
     match key {
-        Key::Space => model.toggle_running(),
-        Key::L => model.toggle_labels(),
-        Key::V => model.toggle_values(),
-        Key::T => model.toggle_theta(),
-        // Key::H => model.toggle_theme(),
-        Key::Up => model.increment_rate(),
-        Key::Down => model.decrement_rate(),
-        Key::R => model.reset_theta(),
-        Key::S => model.reset_rate(),
-        Key::Equals => model.increase_scale(),
-        Key::Minus => model.decrease_scale(),
-        Key::Key0 => model.reset_scale(),
-        _ => {}
+        _ if model.is_confirming_quit() => {
+            if model.answer_quit_prompt(key) {
+                app.quit();
+            }
+        }
+        _ if model.panel.wants_keyboard() => {}
+        Key::Space if model.is_touring() => model.advance_tour(),
+        Key::Escape if model.is_touring() => model.skip_tour(),
+        Key::Space | Key::Escape if model.dismiss_whats_new() => {}
+        _ if model.turn_whats_new_page(key) => {}
+        Key::Escape if model.close_calibration() => {}
+        _ if model.calibrate(key) => {}
+        _ if model.overlays.key_pressed(key, app.keys.mods) => {}
+        Key::Tab if shift => model.focus_previous(),
+        Key::Tab => model.focus_next(),
+        Key::Return | Key::Space if model.activate_focused() => {}
+        Key::Escape if model.clear_focus() => {}
+        Key::Up | Key::Right if model.adjust_focused(1.0) => {}
+        Key::Down | Key::Left if model.adjust_focused(-1.0) => {}
+        _ => {
+            if let Some(action) = model.keymap.action(key, app.keys.mods) {
+                perform(app, model, action);
+            }
+        }
+    }
+}
+
+fn perform(app: &App, model: &mut Model, action: Action) {
+    match action {
+        Action::ShowWhatsNew => model.show_whats_new(),
+        Action::StartTour => model.start_tour(),
+        Action::ToggleBroadcast => model.toggle_broadcast(),
+        Action::ToggleCalibration => model.toggle_calibration(),
+        #[cfg(feature = "inspector")]
+        Action::ToggleInspector => model.inspector.toggle(),
+        Action::ToggleStats => model.toggle_stats(),
+        Action::ToggleKeyCast => model.toggle_key_cast(),
+        Action::CycleLayout => model.cycle_layout(),
+        Action::CycleMirror => model.cycle_mirror(),
+        Action::CycleSceneMirror => model.cycle_scene_mirror(),
+        Action::TogglePanel => model.toggle_panel(),
+        Action::ReloadScript => model.reload_script(),
+        Action::Screenshot => model.take_screenshot(app),
+        Action::DumpScene => {
+            if let Err(e) = model.dump_scene() {
+                eprintln!("failed to dump scene: {e}");
+            }
+        }
+        Action::ToggleFullscreen => model.toggle_fullscreen(app),
+        Action::ToggleSnap => model.toggle_snap(),
+        Action::TogglePolarGrid => model.toggle_polar_grid(),
+        Action::CyclePolarSpokes => model.cycle_polar_spokes(),
+        Action::CyclePaper => model.cycle_paper(),
+        Action::Quit => {
+            if !model.clear_measure() && !model.is_presenting(app) {
+                model.request_quit(app);
+            }
+        }
+        Action::QuitAlways => model.request_quit(app),
+        Action::ToggleRunning => model.toggle_running(),
+        Action::LogCsv | Action::LogJson => {
+            let format = if action == Action::LogJson {
+                LogFormat::JsonLines
+            }
+            else {
+                LogFormat::Csv
+            };
+            if let Err(e) = model.toggle_recording(format) {
+                eprintln!("failed to log data: {e}");
+            }
+        }
+        Action::ToggleLabelOutlines => model.toggle_label_outlines(),
+        Action::ToggleLabels => model.toggle_labels(),
+        Action::ToggleRangeBars => model.toggle_range_bars(),
+        Action::ToggleValues => model.toggle_values(),
+        Action::ToggleQuadrantArc => model.toggle_quadrant_arc(),
+        Action::ToggleReferenceAngle => model.toggle_reference_angle(),
+        Action::ToggleReferenceRay => model.toggle_reference_ray(),
+        Action::ToggleTheta => model.toggle_theta(),
+        Action::ResetClock => model.reset_clock(),
+        Action::ToggleClock => model.toggle_clock(),
+        Action::ToggleCountdownAutoPause => {
+            model.toggle_countdown_auto_pause();
+        }
+        Action::ToggleCountdown => model.toggle_countdown(),
+        Action::CycleDomainShading => model.cycle_domain_shading(),
+        Action::IncreaseTimeScale => model.scale_time(2.0),
+        Action::DecreaseTimeScale => model.scale_time(0.5),
+        Action::ToggleIdentities => model.toggle_identities(),
+        Action::ToggleAngleSum => model.toggle_angle_sum(),
+        Action::IncrementPhiRate => model.increment_phi_rate(),
+        Action::DecrementPhiRate => model.decrement_phi_rate(),
+        Action::StepForward => {
+            if !model.step_theta(1.0) {
+                model.increment_phi();
+            }
+        }
+        Action::StepBack => {
+            if !model.step_theta(-1.0) {
+                model.decrement_phi();
+            }
+        }
+        Action::ToggleBookmark => model.toggle_bookmark(),
+        Action::ToggleComparison => model.toggle_comparison(),
+        Action::ComparisonForward => {
+            model.shift_comparison(COMPARISON_INCREMENT);
+        }
+        Action::ComparisonBack => {
+            model.shift_comparison(-COMPARISON_INCREMENT);
+        }
+        Action::ToggleHyperbolic => model.toggle_hyperbolic(),
+        Action::ToggleEpicycles => model.toggle_epicycles(),
+        Action::CycleWaveform => model.cycle_waveform(),
+        Action::ToggleBulletTime => model.toggle_bullet_time(),
+        Action::ToggleStrobe => model.toggle_strobe(),
+        Action::ToggleTrace => model.toggle_trace(),
+        Action::ToggleTaylor => model.toggle_taylor(),
+        Action::ToggleIntegral => model.toggle_integral(),
+        Action::ToggleDeMoivre => model.toggle_de_moivre(),
+        Action::CycleInverseMode => model.cycle_inverse_mode(),
+        Action::ToggleComplexPlane => model.toggle_complex_plane(),
+        Action::ToggleDerivatives => model.toggle_derivatives(),
+        Action::CycleMicMode => model.cycle_mic_mode(),
+        Action::ToggleSound => model.toggle_sound(),
+        Action::NextPower => model.next_power(),
+        Action::PreviousPower => model.previous_power(),
+        Action::ToggleGhost => model.toggle_ghost(),
+        Action::ToggleHistory => model.toggle_history(),
+        Action::ToggleMeasure => model.toggle_measure(),
+        Action::CycleProtractor => model.cycle_protractor(),
+        Action::CycleLocale => model.cycle_locale(),
+        Action::CycleAngleUnit => model.cycle_angle_unit(),
+        Action::ExportAnimation => {
+            if let Err(e) = model.export_animation() {
+                eprintln!("failed to export animation: {e}");
+            }
+        }
+        Action::ExportWorksheet => {
+            if let Err(e) = model.export_worksheet() {
+                eprintln!("failed to export worksheet: {e}");
+            }
+        }
+        Action::ExportSessionNotes => {
+            if let Err(e) = model.export_session_notes() {
+                eprintln!("failed to export session notes: {e}");
+            }
+        }
+        Action::ExportReplay => {
+            if let Err(e) = model.export_replay() {
+                eprintln!("failed to export replay: {e}");
+            }
+        }
+        Action::ExportPoster => {
+            if let Err(e) = model.export_poster() {
+                eprintln!("failed to export poster: {e}");
+            }
+        }
+        Action::ExportFlashcards => {
+            if let Err(e) = model.export_flashcards() {
+                eprintln!("failed to export flashcards: {e}");
+            }
+        }
+        Action::ExportValueTable => {
+            if let Err(e) = model.export_value_table() {
+                eprintln!("failed to export value table: {e}");
+            }
+        }
+        Action::DecrementCountdownTarget => {
+            model.decrement_countdown_target();
+        }
+        Action::IncrementCountdownTarget => {
+            model.increment_countdown_target();
+        }
+        // Action::ToggleTheme => model.toggle_theme(),
+        Action::IncrementRate => model.increment_rate(),
+        Action::DecrementRate => model.decrement_rate(),
+        Action::ResetTheta => model.reset_theta(),
+        Action::ToggleBefore => model.toggle_before(),
+        Action::ResetRate => model.reset_rate(),
+        Action::IncreaseScale => model.increase_scale(),
+        Action::DecreaseScale => model.decrease_scale(),
+        Action::ResetScale => model.reset_scale(),
+        Action::JumpToAngle(idx) => model.jump_to_special_angle(idx),
+        Action::ToggleChapterMenu => model.toggle_chapter_menu(),
+        Action::JumpToChapter(idx) => model.jump_to_chapter(idx),
     }
 }
 
 #[allow(clippy::needless_pass_by_value)]
 fn view(app: &App, model: &Model, frame: Frame) {
-    let draw = &app.draw().translate(vec3(-120.0, 0.0, 0.0));
+    let start = Instant::now();
+    let draw = app.draw();
+    // mirroring the whole window is left out of exports, which aren't
+    // projected
+    let mirror = model.scene_mirror.scale();
+    let scene = draw
+        .scale_x(mirror.x)
+        .scale_y(mirror.y)
+        .scale(model.view_scale())
+        .translate(model.layout.origin().extend(0.0));
+    draw_scene(model, &scene);
+    // not part of the scene, so they're left out of offscreen exports
+    model.draw_stats(&scene);
+    #[cfg(feature = "inspector")]
+    model.draw_inspector(&scene);
+    model.draw_quit_prompt(&scene);
+    // applied to the window rather than the scene, so that exports keep
+    // their colours as they are
+    model.calibration.apply(&draw, app.window_rect());
+    let drawn = Instant::now();
+
+    draw.to_frame(app, &frame).unwrap();
+    model.stats.record_view(
+        (drawn - start).as_secs_f32(),
+        drawn.elapsed().as_secs_f32(),
+    );
+    model.panel.draw_to_frame(&frame);
+
+    // exports are rendered in `Model::render_offscreen` instead if there's an
+    // export resolution
+    if let Some(path) =
+        model.capture_path().filter(|_| model.offscreen.is_none())
+    {
+        app.main_window().capture_frame(path);
+    }
+}
+
+/// Draws everything in the scene, in the circle's coordinates.
+fn draw_scene(model: &Model, draw: &Draw) {
     draw.background().color(model.bg_color());
 
-    model.draw_bg_lines(draw);
-    model.draw_unit_circle(draw);
-    model.draw_trig_lines(draw);
-    model.draw_node(draw);
-    model.draw_values(draw);
+    // the figure is mirrored, but not the readouts around it
+    let scale = model.layout.mirror.scale();
+    let figure = &draw.scale_x(scale.x).scale_y(scale.y);
 
-    draw.to_frame(app, &frame).unwrap();
+    model.draw_bg_lines(figure);
+    model.draw_protractor(figure);
+    model.draw_domain_shading(figure);
+    model.draw_inverse(figure);
+    match model.mode {
+        Mode::Circular => {
+            model.draw_unit_circle(figure);
+            model.draw_reference_angle(figure);
+            model.draw_reference_ray(figure);
+            model.draw_before(figure);
+            model.draw_comparison(figure);
+            model.draw_trig_lines(figure);
+            model.draw_integral(figure);
+            model.draw_trace(figure);
+            model.draw_taylor(figure);
+            model.draw_ghost(figure);
+            model.draw_snap(figure);
+            model.draw_node(figure);
+            model.draw_derivatives(figure);
+            model.draw_de_moivre(figure);
+            model.draw_strobe(figure);
+            model.draw_angle_sum(figure);
+            model.draw_identities(figure);
+            model.draw_measure(figure);
+        }
+        Mode::Hyperbolic => model.draw_hyperbola(figure),
+        Mode::Epicycles => model.draw_epicycles(figure),
+    }
+    model.draw_values(draw);
+    model.draw_pins(draw);
+    model.draw_clock(draw);
+    model.draw_time_scale(draw);
+    model.draw_safe_area(draw);
+    model.draw_countdown(draw);
+    model.draw_caption(draw);
+    model.draw_recording(draw);
+    model.draw_chapters(draw);
+    model.draw_minimap(draw);
+    model.draw_sound(draw);
+    model.draw_mic(draw);
+    model.draw_history(draw);
+    model.draw_focus(draw);
+    model.draw_overlays(draw);
+    model.draw_key_cast(draw);
+    model.draw_tour(draw);
+    model.draw_whats_new(draw);
+    model.draw_calibration(draw);
 }
@@ -0,0 +1,78 @@
+use std::process::{Child, Command, Stdio};
+
+/// Reads the script's captions aloud by passing each new one to a
+/// text-to-speech command, for self-narrating demos.
+#[derive(Debug, Default)]
+pub struct Narrator {
+    /// The program and its arguments, with the caption added as the last
+    /// argument.
+    command: Vec<String>,
+    last_caption: Option<String>,
+    speaking: Option<Child>,
+}
+
+impl Narrator {
+    /// Creates a narrator for `command`, which is split on whitespace. With
+    /// no command, nothing is spoken.
+    pub fn new(command: Option<&str>) -> Self {
+        Self {
+            command: command
+                .map(|command| {
+                    command.split_whitespace().map(String::from).collect()
+                })
+                .unwrap_or_default(),
+            last_caption: None,
+            speaking: None,
+        }
+    }
+
+    /// Speaks `caption` if it's different from the last one, cutting off
+    /// whatever was still being said.
+    pub fn update(&mut self, caption: Option<&str>) {
+        if self.last_caption.as_deref() == caption {
+            return;
+        }
+        self.last_caption = caption.map(String::from);
+
+        let Some(caption) = caption
+        else {
+            return;
+        };
+
+        self.stop();
+
+        let Some((program, args)) = self.command.split_first()
+        else {
+            return;
+        };
+
+        match Command::new(program)
+            .args(args)
+            .arg(caption)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => self.speaking = Some(child),
+            Err(e) => {
+                eprintln!("failed to run narration command {program}: {e}");
+                // don't keep trying for every caption
+                self.command.clear();
+            }
+        }
+    }
+
+    /// Stops speaking, if the command is still running.
+    pub fn stop(&mut self) {
+        if let Some(mut child) = self.speaking.take() {
+            _ = child.kill();
+            _ = child.wait();
+        }
+    }
+}
+
+impl Drop for Narrator {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
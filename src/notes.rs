@@ -0,0 +1,95 @@
+use crate::display::value_rows_at;
+use crate::export::EXPORT_DIR;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// What goes into the session notes.
+#[derive(Debug)]
+pub struct SessionNotes {
+    /// The settings at the end of the session, as (name, value) rows.
+    pub settings: Vec<(String, String)>,
+    /// The value table as currently displayed.
+    pub values: Vec<(String, String)>,
+    pub bookmarks: Vec<f32>,
+    /// When the session started, so that only this session's exports are
+    /// listed.
+    pub started: SystemTime,
+}
+
+impl SessionNotes {
+    /// Writes the notes as Markdown to `path`, which should be in the export
+    /// directory so that links to other exports resolve.
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let mut md = String::from("# Session notes\n\n## Settings\n\n");
+        write_table(&mut md, &self.settings);
+
+        md.push_str("\n## Values\n\n");
+        write_table(&mut md, &self.values);
+
+        if !self.bookmarks.is_empty() {
+            md.push_str("\n## Bookmarks\n");
+
+            for &theta in &self.bookmarks {
+                let rows = value_rows_at(theta);
+                _ = writeln!(md, "\n### θ = {}\n", rows[0].1);
+                write_table(&mut md, &rows[1..]);
+            }
+        }
+
+        let exports = self.exports()?;
+        if !exports.is_empty() {
+            md.push_str("\n## Exports\n\n");
+
+            let (images, files): (Vec<_>, Vec<_>) = exports
+                .iter()
+                .map(|export| export.display().to_string().replace('\\', "/"))
+                .partition(|name| name.ends_with(".png"));
+
+            for name in files {
+                _ = writeln!(md, "- [{name}]({name})");
+            }
+            for name in images {
+                _ = write!(md, "\n![{name}]({name})\n");
+            }
+        }
+
+        fs::write(path, md)
+    }
+
+    /// The files exported during the session, relative to the export
+    /// directory. Images in subdirectories (such as flashcards) are included.
+    fn exports(&self) -> io::Result<Vec<PathBuf>> {
+        let mut exports = Vec::new();
+        let mut dirs = vec![PathBuf::new()];
+
+        while let Some(dir) = dirs.pop() {
+            for entry in fs::read_dir(Path::new(EXPORT_DIR).join(&dir))? {
+                let entry = entry?;
+                let path = dir.join(entry.file_name());
+                let metadata = entry.metadata()?;
+
+                if metadata.is_dir() {
+                    dirs.push(path);
+                }
+                else if metadata.modified()? >= self.started
+                    && path.extension().is_some_and(|ext| ext != "md")
+                {
+                    exports.push(path);
+                }
+            }
+        }
+
+        exports.sort();
+        Ok(exports)
+    }
+}
+
+fn write_table(md: &mut String, rows: &[(String, String)]) {
+    md.push_str("| | |\n|---|---|\n");
+    for (name, value) in rows {
+        _ = writeln!(md, "| {name} | {value} |");
+    }
+}
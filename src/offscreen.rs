@@ -0,0 +1,120 @@
+use nannou::draw::{Renderer, RendererBuilder};
+use nannou::prelude::*;
+use std::fmt;
+use std::path::PathBuf;
+
+/// Renders frames to a texture of a fixed size and saves them as PNGs, so
+/// exported images don't depend on the size of the window.
+pub struct OffscreenRenderer {
+    size: [u32; 2],
+    /// Built on the first export, as the texture can take a lot of memory.
+    target: Option<Target>,
+}
+
+struct Target {
+    texture: wgpu::Texture,
+    renderer: Renderer,
+    capturer: wgpu::TextureCapturer,
+}
+
+impl fmt::Debug for OffscreenRenderer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OffscreenRenderer")
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OffscreenRenderer {
+    /// Returns an error if `size` is larger than the GPU's textures can be.
+    pub fn new(window: &Window, size: [u32; 2]) -> Result<Self, String> {
+        let max = window.device().limits().max_texture_dimension_2d;
+        let [w, h] = size;
+
+        if w > max || h > max {
+            return Err(format!(
+                "{w}x{h} is larger than this GPU supports ({max}x{max})"
+            ));
+        }
+
+        Ok(Self { size, target: None })
+    }
+
+    /// The width and height of the rendered images, in pixels.
+    pub const fn size(&self) -> [u32; 2] {
+        self.size
+    }
+
+    /// Renders `draw` and saves it to `path` in the background.
+    pub fn render(&mut self, window: &Window, draw: &Draw, path: PathBuf) {
+        let size = self.size;
+        let target =
+            self.target.get_or_insert_with(|| Target::new(window, size));
+        let device = window.device();
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("offscreen"),
+            });
+
+        target.renderer.render_to_texture(
+            device,
+            &mut encoder,
+            draw,
+            &target.texture,
+        );
+        let snapshot =
+            target
+                .capturer
+                .capture(device, &mut encoder, &target.texture);
+        window.queue().submit(Some(encoder.finish()));
+
+        let saved = snapshot.read(move |result| {
+            let saved = result
+                .map_err(|_| String::from("couldn't read the texture"))
+                .and_then(|image| {
+                    image.to_owned().save(&path).map_err(|e| e.to_string())
+                });
+
+            if let Err(e) = saved {
+                eprintln!("failed to save {}: {e}", path.display());
+            }
+        });
+
+        if saved.is_err() {
+            eprintln!("failed to read the offscreen texture");
+        }
+    }
+
+    /// Waits for every image to be saved, returning `false` if it timed out.
+    pub fn wait(&self, window: &Window) -> bool {
+        self.target.as_ref().is_none_or(|target| {
+            target
+                .capturer
+                .await_active_snapshots(window.device())
+                .is_ok()
+        })
+    }
+}
+
+impl Target {
+    fn new(window: &Window, size: [u32; 2]) -> Self {
+        let device = window.device();
+        let texture = wgpu::TextureBuilder::new()
+            .size(size)
+            .usage(
+                wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+            )
+            .sample_count(1)
+            .format(wgpu::TextureFormat::Rgba16Float)
+            .build(device);
+        let renderer = RendererBuilder::new()
+            .build_from_texture_descriptor(device, texture.descriptor());
+
+        Self {
+            texture,
+            renderer,
+            capturer: wgpu::TextureCapturer::default(),
+        }
+    }
+}
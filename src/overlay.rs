@@ -0,0 +1,100 @@
+use crate::events::Event;
+use nannou::{event::ModifiersState, prelude::*};
+use std::fmt;
+use trig_visuals::TrigValues;
+
+/// What overlays can see of the visualisation each frame.
+// the built-in watermark only reads the window and theme; the rest are for
+// the overlays forks register
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug)]
+pub struct OverlayContext {
+    pub theta: f32,
+    pub rate: f32,
+    /// The radius of the unit circle in pixels.
+    pub radius: f32,
+    pub values: TrigValues,
+    pub is_running: bool,
+    /// The whole window, in the same coordinates as the circle (which is
+    /// centred on the origin).
+    pub window: Rect,
+    pub is_dark: bool,
+}
+
+/// A custom layer drawn over the visualisation, such as school branding or an
+/// extra construction.
+///
+/// Overlays are drawn in the same coordinates as the circle, after the core
+/// elements but beneath the tour and "what's new" panels.
+pub trait Overlay {
+    /// A short name, used when debugging.
+    fn name(&self) -> &str;
+
+    fn update(&mut self, _ctx: &OverlayContext, _delta_time: f32) {}
+
+    /// Called for each event before `update`.
+    fn on_event(&mut self, _event: Event) {}
+
+    fn draw(&self, draw: &Draw, ctx: &OverlayContext);
+
+    /// Returns `true` if the overlay handled `key`, in which case it isn't
+    /// passed on to the built-in keymap.
+    fn key_pressed(&mut self, _key: Key, _mods: ModifiersState) -> bool {
+        false
+    }
+}
+
+/// The overlays registered with the app, updated and drawn in the order they
+/// were added.
+#[derive(Default)]
+pub struct Overlays {
+    overlays: Vec<Box<dyn Overlay>>,
+}
+
+impl fmt::Debug for Overlays {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.overlays.iter().map(|overlay| overlay.name()))
+            .finish()
+    }
+}
+
+impl Overlays {
+    /// The overlays the app starts with. Forks can register their own here
+    /// without touching the core drawing code.
+    pub fn registered() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, overlay: impl Overlay + 'static) {
+        self.overlays.push(Box::new(overlay));
+    }
+
+    pub fn update(
+        &mut self,
+        ctx: &OverlayContext,
+        events: &[Event],
+        delta_time: f32,
+    ) {
+        for overlay in &mut self.overlays {
+            for &event in events {
+                overlay.on_event(event);
+            }
+            overlay.update(ctx, delta_time);
+        }
+    }
+
+    pub fn draw(&self, draw: &Draw, ctx: &OverlayContext) {
+        for overlay in &self.overlays {
+            overlay.draw(draw, ctx);
+        }
+    }
+
+    /// Offers `key` to each overlay in turn, returning `true` once one
+    /// handles it.
+    pub fn key_pressed(&mut self, key: Key, mods: ModifiersState) -> bool {
+        self.overlays
+            .iter_mut()
+            .any(|overlay| overlay.key_pressed(key, mods))
+    }
+}
@@ -0,0 +1,157 @@
+use crate::consts::UNIT_RADIUS;
+use crate::labels::Label;
+use crate::state::MAX_SAFE_INSET;
+use nannou::{prelude::*, winit::event::WindowEvent};
+use nannou_egui::{egui, Egui};
+use std::{fmt, time::Duration};
+
+/// The fastest rate the panel's slider goes up to, in radians per second.
+const MAX_RATE: f32 = 3.0;
+
+/// The parts of the model the panel's widgets change.
+pub struct Controls<'a> {
+    pub is_running: &'a mut bool,
+    pub draw_labels: &'a mut bool,
+    pub draw_values: &'a mut bool,
+    pub draw_theta: &'a mut bool,
+    /// Whether each function's line is shown, with its name.
+    pub visible: [(&'static str, &'a mut bool); 6],
+    /// The order the lines are drawn in, from the bottom up.
+    pub draw_order: &'a mut [Label; 7],
+    pub rate: &'a mut f32,
+    pub radius: &'a mut f32,
+    /// The top, right, bottom and left insets of the safe area.
+    pub safe_area: &'a mut [f32; 4],
+    /// Set when the safe area's controls are shown.
+    pub adjusting_safe_area: &'a mut bool,
+}
+
+/// A collapsible side panel with a widget for each of the main controls.
+///
+/// The widgets change the model's state directly, so they stay in sync with
+/// the keyboard shortcuts.
+pub struct ControlPanel {
+    egui: Egui,
+    open: bool,
+}
+
+impl fmt::Debug for ControlPanel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ControlPanel")
+            .field("open", &self.open)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ControlPanel {
+    pub fn new(window: &Window) -> Self {
+        Self { egui: Egui::from_window(window), open: false }
+    }
+
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+    }
+
+    pub fn handle_raw_event(&mut self, event: &WindowEvent) {
+        self.egui.handle_raw_event(event);
+    }
+
+    /// Whether a widget is taking keyboard input, such as a slider's text
+    /// field, in which case the shortcuts shouldn't also act on it.
+    pub fn wants_keyboard(&self) -> bool {
+        self.open && self.egui.ctx().wants_keyboard_input()
+    }
+
+    /// Whether the mouse is over the panel or dragging one of its widgets.
+    pub fn wants_pointer(&self) -> bool {
+        self.open
+            && (self.egui.ctx().is_pointer_over_area()
+                || self.egui.ctx().wants_pointer_input())
+    }
+
+    /// Lays out the panel for this frame, applying any changes made with it.
+    pub fn update(&mut self, since_start: Duration, controls: Controls<'_>) {
+        self.egui.set_elapsed_time(since_start);
+        let ctx = self.egui.begin_frame();
+
+        egui::SidePanel::left("controls")
+            .resizable(false)
+            .show_animated(&ctx, self.open, |ui| {
+                ui.heading("Controls");
+                ui.checkbox(controls.is_running, "Running");
+                ui.checkbox(controls.draw_labels, "Labels");
+                ui.checkbox(controls.draw_values, "Values");
+                ui.checkbox(controls.draw_theta, "Theta arc");
+
+                ui.collapsing("Functions", |ui| {
+                    for (name, visible) in controls.visible {
+                        ui.checkbox(visible, name);
+                    }
+                });
+
+                ui.collapsing("Draw order", |ui| {
+                    let order = controls.draw_order;
+
+                    // listed from the top, which is drawn last
+                    for i in (0..order.len()).rev() {
+                        ui.horizontal(|ui| {
+                            ui.label(order[i].name());
+
+                            if i + 1 < order.len()
+                                && ui.small_button("up").clicked()
+                            {
+                                order.swap(i, i + 1);
+                            }
+                            if i > 0 && ui.small_button("down").clicked() {
+                                order.swap(i, i - 1);
+                            }
+                        });
+                    }
+                });
+
+                *controls.adjusting_safe_area = false;
+                ui.collapsing("Safe area", |ui| {
+                    *controls.adjusting_safe_area = true;
+
+                    let edges = ["top", "right", "bottom", "left"];
+                    for (inset, edge) in
+                        controls.safe_area.iter_mut().zip(edges)
+                    {
+                        let mut percent = *inset * 100.0;
+                        ui.add(
+                            egui::Slider::new(
+                                &mut percent,
+                                0.0..=MAX_SAFE_INSET * 100.0,
+                            )
+                            .text(edge)
+                            .suffix(" %"),
+                        );
+                        *inset = percent / 100.0;
+                    }
+                });
+
+                ui.separator();
+                ui.add(
+                    egui::Slider::new(controls.rate, 0.0..=MAX_RATE)
+                        .clamp_to_range(false)
+                        .text("rate")
+                        .suffix(" rad/s"),
+                );
+                ui.add(
+                    egui::Slider::new(
+                        controls.radius,
+                        UNIT_RADIUS * 0.25..=UNIT_RADIUS * 3.0,
+                    )
+                    .clamp_to_range(false)
+                    .text("radius")
+                    .suffix(" px"),
+                );
+            });
+    }
+
+    pub fn draw_to_frame(&self, frame: &Frame) {
+        if self.open {
+            self.egui.draw_to_frame(frame).unwrap();
+        }
+    }
+}
@@ -0,0 +1,81 @@
+/// The number of minor divisions of the radius on engineering paper.
+pub const ENGINEERING_DIVISIONS: u32 = 10;
+/// The number of minor divisions between each major line on engineering
+/// paper.
+pub const ENGINEERING_MAJOR_EVERY: u32 = 5;
+/// The number of rings per radius on the polar grid.
+pub const POLAR_RINGS: u32 = 4;
+
+/// The style of paper drawn behind the circle.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Paper {
+    /// Just the x and y axes.
+    #[default]
+    Plain,
+    /// A square grid in tenths of the radius, heavier every half.
+    Engineering,
+}
+
+impl Paper {
+    pub fn cycle(&mut self) {
+        *self = match self {
+            Self::Plain => Self::Engineering,
+            Self::Engineering => Self::Plain,
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "plain" => Some(Self::Plain),
+            "engineering" => Some(Self::Engineering),
+            _ => None,
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Plain => "plain",
+            Self::Engineering => "engineering",
+        }
+    }
+}
+
+/// How far apart the polar grid's spokes are.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SpokeSpacing {
+    /// A spoke every 15°, through every multiple of π/12.
+    #[default]
+    Fine,
+    /// A spoke every 30°, through every multiple of π/6.
+    Coarse,
+}
+
+impl SpokeSpacing {
+    pub fn cycle(&mut self) {
+        *self = match self {
+            Self::Fine => Self::Coarse,
+            Self::Coarse => Self::Fine,
+        }
+    }
+
+    /// Parses the spacing in degrees, `15` or `30`.
+    pub fn parse(degrees: &str) -> Option<Self> {
+        match degrees {
+            "15" => Some(Self::Fine),
+            "30" => Some(Self::Coarse),
+            _ => None,
+        }
+    }
+
+    pub const fn degrees(self) -> u32 {
+        match self {
+            Self::Fine => 15,
+            Self::Coarse => 30,
+        }
+    }
+
+    /// The number of spokes around the grid.
+    pub const fn count(self) -> u32 {
+        360 / self.degrees()
+    }
+}
@@ -0,0 +1,61 @@
+use crate::labels::Label;
+use nannou::prelude::*;
+
+/// The size of a pinned value's tag.
+pub fn tag_size() -> Vec2 {
+    vec2(130.0, 26.0)
+}
+
+/// A value from the panel pinned as a tag beside its line.
+#[derive(Clone, Copy, Debug)]
+pub struct Pin {
+    pub label: Label,
+    /// Where the tag sits relative to the function's label, which it's
+    /// joined to by a leader line.
+    pub offset: Vec2,
+}
+
+/// The values pinned from the value panel, and the one being dragged out of
+/// it.
+#[derive(Debug, Default)]
+pub struct Pins {
+    pins: Vec<Pin>,
+    /// The value being dragged and where it's been dragged to.
+    dragging: Option<(Label, Vec2)>,
+}
+
+impl Pins {
+    pub fn start_drag(&mut self, label: Label, pos: Vec2) {
+        self.dragging = Some((label, pos));
+    }
+
+    pub fn drag_to(&mut self, pos: Vec2) {
+        if let Some((_, drag_pos)) = &mut self.dragging {
+            *drag_pos = pos;
+        }
+    }
+
+    /// Ends the drag, returning the value which was being dragged.
+    pub fn end_drag(&mut self) -> Option<Label> {
+        self.dragging.take().map(|(label, _)| label)
+    }
+
+    pub const fn dragging(&self) -> Option<(Label, Vec2)> {
+        self.dragging
+    }
+
+    /// Pins `label`'s value at `offset` from its label, moving it if it's
+    /// already pinned.
+    pub fn pin(&mut self, label: Label, offset: Vec2) {
+        self.unpin(label);
+        self.pins.push(Pin { label, offset });
+    }
+
+    pub fn unpin(&mut self, label: Label) {
+        self.pins.retain(|pin| pin.label != label);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Pin> {
+        self.pins.iter()
+    }
+}
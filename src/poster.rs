@@ -0,0 +1,59 @@
+use crate::display::{
+    exact_value, fmt_complex, fmt_pi_fraction, STANDARD_TWELFTHS,
+};
+use crate::export::{export_path, write_poster_pdf};
+use crate::watermark::Watermark;
+use std::f32::consts::PI;
+use std::io;
+
+/// The labels for one standard angle around the rim of the poster.
+#[derive(Clone, Debug)]
+pub struct PosterAngle {
+    pub theta: f32,
+    pub degrees: String,
+    pub radians: String,
+    /// The point on the circle, as `(cos θ, sin θ)` or `cos θ + i sin θ`.
+    pub coords: String,
+}
+
+/// The labels for each of the standard angles, with exact values. If
+/// `complex` is set, points are written as complex numbers.
+pub fn standard_angles(complex: bool) -> Vec<PosterAngle> {
+    let exact =
+        |val: f32| exact_value(val).unwrap_or_else(|| format!("{val:.3}"));
+
+    STANDARD_TWELFTHS
+        .iter()
+        .map(|&twelfths| {
+            let theta = twelfths as f32 * PI / 12.0;
+            let (sin, cos) = theta.sin_cos();
+
+            PosterAngle {
+                theta,
+                degrees: format!("{}º", twelfths * 15),
+                radians: fmt_pi_fraction(twelfths),
+                coords: if complex {
+                    fmt_complex(&exact(cos), &exact(sin))
+                }
+                else {
+                    format!("({}, {})", exact(cos), exact(sin))
+                },
+            }
+        })
+        .collect()
+}
+
+/// Exports an A3 poster of the unit circle with every standard angle
+/// labelled.
+pub fn export_poster(complex: bool, watermark: &Watermark) -> io::Result<()> {
+    let path = export_path("poster", "pdf")?;
+    write_poster_pdf(
+        &path,
+        "The unit circle",
+        &standard_angles(complex),
+        watermark,
+    )?;
+
+    println!("exported poster to {}", path.display());
+    Ok(())
+}
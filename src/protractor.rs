@@ -0,0 +1,63 @@
+use std::f32::consts::{PI, TAU};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProtractorStyle {
+    #[default]
+    Hidden,
+    Half,
+    Full,
+}
+
+impl ProtractorStyle {
+    pub fn cycle(&mut self) {
+        *self = match self {
+            Self::Hidden => Self::Half,
+            Self::Half => Self::Full,
+            Self::Full => Self::Hidden,
+        }
+    }
+
+    /// The angle covered by the protractor's scale in radians.
+    pub const fn sweep(self) -> f32 {
+        match self {
+            Self::Hidden => 0.0,
+            Self::Half => PI,
+            Self::Full => TAU,
+        }
+    }
+}
+
+/// A protractor aligned to the unit circle, which can be rotated by dragging.
+#[derive(Debug, Default)]
+pub struct Protractor {
+    pub style: ProtractorStyle,
+    /// The angle of the protractor's zero mark in radians.
+    pub rotation: f32,
+    drag_offset: Option<f32>,
+}
+
+impl Protractor {
+    pub fn is_visible(&self) -> bool {
+        self.style != ProtractorStyle::Hidden
+    }
+
+    /// Starts rotating the protractor from a grab point at `angle` radians.
+    pub fn begin_drag(&mut self, angle: f32) {
+        self.drag_offset = Some(self.rotation - angle);
+    }
+
+    /// Rotates the protractor so that the grab point follows `angle`.
+    pub fn drag_to(&mut self, angle: f32) {
+        if let Some(offset) = self.drag_offset {
+            self.rotation = (angle + offset).rem_euclid(TAU);
+        }
+    }
+
+    pub fn end_drag(&mut self) {
+        self.drag_offset = None;
+    }
+
+    pub const fn is_dragging(&self) -> bool {
+        self.drag_offset.is_some()
+    }
+}
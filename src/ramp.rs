@@ -0,0 +1,97 @@
+/// A change in the rate scheduled over time, such as from 0.1 to 1 rad/s
+/// over 20 seconds, for demos which start slowly and speed up.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateRamp {
+    /// The rate at the start, or the rate when the ramp starts if `None`.
+    from: Option<f32>,
+    to: f32,
+    secs: f32,
+    elapsed: f32,
+}
+
+impl RateRamp {
+    pub fn new(from: Option<f32>, to: f32, secs: f32) -> Self {
+        Self {
+            from: from.map(|from| from.max(0.0)),
+            to: to.max(0.0),
+            secs: secs.max(0.0),
+            elapsed: 0.0,
+        }
+    }
+
+    /// Parses a ramp such as `0.1:1:20` (from 0.1 to 1 rad/s over 20
+    /// seconds), or `1:20` to ramp from whatever the rate is.
+    pub fn parse(text: &str) -> Option<Self> {
+        let parts = text
+            .split(':')
+            .map(|part| part.trim().parse::<f32>().ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        match parts[..] {
+            [from, to, secs] => Some(Self::new(Some(from), to, secs)),
+            [to, secs] => Some(Self::new(None, to, secs)),
+            _ => None,
+        }
+    }
+
+    pub const fn to(&self) -> f32 {
+        self.to
+    }
+
+    /// How far through the ramp is, from `0` to `1`.
+    pub fn progress(&self) -> f32 {
+        if self.secs <= 0.0 {
+            1.0
+        }
+        else {
+            (self.elapsed / self.secs).min(1.0)
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+
+    /// Moves the ramp on by `delta_time`, returning the rate it's reached.
+    /// `rate` is the current rate, which the ramp starts from if it wasn't
+    /// given one.
+    pub fn advance(&mut self, delta_time: f32, rate: f32) -> f32 {
+        let from = *self.from.get_or_insert(rate);
+        self.elapsed += delta_time;
+
+        from + (self.to - from) * self.progress()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_with_and_without_a_start_rate() {
+        assert_eq!(
+            RateRamp::parse("0.1:1:20"),
+            Some(RateRamp::new(Some(0.1), 1.0, 20.0))
+        );
+        assert_eq!(
+            RateRamp::parse(" 1 : 20 "),
+            Some(RateRamp::new(None, 1.0, 20.0))
+        );
+    }
+
+    #[test]
+    fn parse_clamps_negative_values() {
+        let ramp = RateRamp::parse("-1:-5").unwrap();
+
+        assert_eq!(ramp.to(), 0.0);
+        assert_eq!(ramp.progress(), 1.0);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_ramps() {
+        assert_eq!(RateRamp::parse(""), None);
+        assert_eq!(RateRamp::parse("1"), None);
+        assert_eq!(RateRamp::parse("1:2:3:4"), None);
+        assert_eq!(RateRamp::parse("fast:20"), None);
+    }
+}
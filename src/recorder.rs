@@ -0,0 +1,203 @@
+use crate::export::export_path;
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use trig_visuals::TrigValues;
+
+/// The file format data is logged in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogFormat {
+    Csv,
+    /// One JSON object per line.
+    JsonLines,
+}
+
+impl LogFormat {
+    const fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::JsonLines => "jsonl",
+        }
+    }
+}
+
+/// Logs theta and the value of each function every update, for analysis in
+/// a spreadsheet.
+#[derive(Debug)]
+pub struct Recorder {
+    format: LogFormat,
+    path: PathBuf,
+    writer: BufWriter<File>,
+    elapsed: f32,
+    subtitles: Subtitles,
+}
+
+impl Recorder {
+    /// Starts logging to a new file in the export directory.
+    pub fn start(format: LogFormat) -> io::Result<Self> {
+        let path = export_path("trig_log", format.extension())?;
+        let mut writer = BufWriter::new(File::create(&path)?);
+
+        if format == LogFormat::Csv {
+            writeln!(writer, "t,theta,sin,cos,tan,cot,sec,csc")?;
+        }
+
+        Ok(Self {
+            format,
+            path,
+            writer,
+            elapsed: 0.0,
+            subtitles: Subtitles::default(),
+        })
+    }
+
+    /// The seconds since logging started.
+    pub const fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    pub const fn format(&self) -> LogFormat {
+        self.format
+    }
+
+    /// Adds a row for the current update, and notes when the caption shown
+    /// changes.
+    pub fn record(
+        &mut self,
+        delta_time: f32,
+        theta: f32,
+        values: &TrigValues,
+        caption: Option<&str>,
+    ) -> io::Result<()> {
+        self.elapsed += delta_time;
+        self.subtitles.update(self.elapsed, caption);
+
+        let fields = [
+            ("t", self.elapsed),
+            ("theta", theta),
+            ("sin", values.sin),
+            ("cos", values.cos),
+            ("tan", values.tan),
+            ("cot", values.cot),
+            ("sec", values.sec),
+            ("csc", values.csc),
+        ];
+
+        match self.format {
+            // undefined values are left empty
+            LogFormat::Csv => {
+                let row = fields
+                    .map(|(_, val)| {
+                        if val.is_finite() {
+                            val.to_string()
+                        }
+                        else {
+                            String::new()
+                        }
+                    })
+                    .join(",");
+                writeln!(self.writer, "{row}")
+            }
+            // and are null in JSON, which has no infinity
+            LogFormat::JsonLines => {
+                let object = fields
+                    .map(|(key, val)| {
+                        if val.is_finite() {
+                            format!("\"{key}\":{val}")
+                        }
+                        else {
+                            format!("\"{key}\":null")
+                        }
+                    })
+                    .join(",");
+                writeln!(self.writer, "{{{object}}}")
+            }
+        }
+    }
+
+    /// Stops logging, writing anything still buffered, and returns the path
+    /// of the log and of its subtitles, if any captions were shown.
+    pub fn finish(mut self) -> io::Result<(PathBuf, Option<PathBuf>)> {
+        self.writer.flush()?;
+
+        self.subtitles.update(self.elapsed, None);
+        if self.subtitles.cues.is_empty() {
+            return Ok((self.path, None));
+        }
+
+        let srt_path = self.path.with_extension("srt");
+        fs::write(&srt_path, self.subtitles.to_srt())?;
+
+        Ok((self.path, Some(srt_path)))
+    }
+}
+
+/// The captions shown while logging, timed to match the log, so that they
+/// can be added to a screen recording made alongside it.
+#[derive(Debug, Default)]
+struct Subtitles {
+    /// Each caption shown, with the times it appeared and disappeared.
+    cues: Vec<(f32, f32, String)>,
+    /// The caption being shown and when it appeared.
+    current: Option<(f32, String)>,
+}
+
+impl Subtitles {
+    fn update(&mut self, time: f32, caption: Option<&str>) {
+        if self.current.as_ref().map(|(_, text)| text.as_str()) == caption {
+            return;
+        }
+
+        if let Some((start, text)) = self.current.take() {
+            self.cues.push((start, time, text));
+        }
+        self.current = caption.map(|text| (time, String::from(text)));
+    }
+
+    fn to_srt(&self) -> String {
+        let mut srt = String::new();
+
+        for (i, (start, end, text)) in self.cues.iter().enumerate() {
+            _ = write!(
+                srt,
+                "{}\n{} --> {}\n{text}\n\n",
+                i + 1,
+                srt_time(*start),
+                srt_time(*end),
+            );
+        }
+
+        srt
+    }
+}
+
+/// Formats `secs` as an SRT timestamp, such as `00:01:02,500`.
+fn srt_time(secs: f32) -> String {
+    let millis = (secs.max(0.0) * 1000.0).round() as u64;
+
+    format!(
+        "{:02}:{:02}:{:02},{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        millis % 1000,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn srt_time_formats_hours_to_milliseconds() {
+        assert_eq!(srt_time(0.0), "00:00:00,000");
+        assert_eq!(srt_time(3661.5), "01:01:01,500");
+        assert_eq!(srt_time(59.9996), "00:01:00,000");
+    }
+
+    #[test]
+    fn srt_time_clamps_negative_times() {
+        assert_eq!(srt_time(-1.0), "00:00:00,000");
+    }
+}
@@ -0,0 +1,207 @@
+use crate::history::Sample;
+use nannou::prelude::Rgb;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The player page, with `/*DATA*/` replaced by the replay as JSON.
+const PLAYER_HTML: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Trigonometric function visualisation replay</title>
+<style>
+  body { background: #111; color: #ddd; font: 16px serif; text-align: center; }
+  canvas { display: block; margin: 1em auto; }
+  input[type=range] { width: 500px; }
+</style>
+</head>
+<body>
+<canvas id="circle" width="600" height="600"></canvas>
+<button id="play">Pause</button>
+<input id="scrub" type="range" min="0" value="0">
+<span id="time"></span>
+<p id="theta"></p>
+<script>
+const replay = /*DATA*/;
+const canvas = document.getElementById("circle");
+const ctx = canvas.getContext("2d");
+const play = document.getElementById("play");
+const scrub = document.getElementById("scrub");
+const radius = 150;
+// values past this are cut off, as the lines run off the canvas anyway
+const limit = 20;
+
+const samples = replay.samples;
+const total = samples[samples.length - 1][0];
+
+let playing = true;
+// the playback time in seconds
+let position = 0;
+let last = null;
+
+scrub.max = total;
+scrub.step = "any";
+
+// the last sample recorded at or before `secs`
+function sampleAt(secs) {
+  let lo = 0, hi = samples.length - 1;
+  while (lo < hi) {
+    const mid = Math.ceil((lo + hi) / 2);
+    if (samples[mid][0] <= secs) {
+      lo = mid;
+    } else {
+      hi = mid - 1;
+    }
+  }
+  return samples[lo];
+}
+
+function clamp(x) {
+  return Math.max(-limit, Math.min(limit, x));
+}
+
+function line(color, x0, y0, x1, y1) {
+  ctx.strokeStyle = color;
+  ctx.beginPath();
+  ctx.moveTo(x0 * radius, -y0 * radius);
+  ctx.lineTo(x1 * radius, -y1 * radius);
+  ctx.stroke();
+}
+
+function draw(sample) {
+  const [, theta, rate, running] = sample;
+  const s = Math.sin(theta), c = Math.cos(theta);
+  const tan = clamp(Math.tan(theta)), csc = clamp(1 / s);
+  const lines = {
+    sin: [c, 0, c, s],
+    cos: [0, 0, c, 0],
+    tan: [1, 0, 1, tan],
+    cot: [c, s, 0, csc],
+    sec: [0, 0, 1, tan],
+    csc: [0, 0, 0, csc],
+  };
+
+  ctx.setTransform(1, 0, 0, 1, canvas.width / 2, canvas.height / 2);
+  ctx.clearRect(-canvas.width / 2, -canvas.height / 2, canvas.width, canvas.height);
+  ctx.lineWidth = 3;
+
+  ctx.strokeStyle = "rgba(255, 255, 255, 0.3)";
+  ctx.beginPath();
+  ctx.arc(0, 0, radius, 0, 2 * Math.PI);
+  ctx.stroke();
+
+  for (const bookmark of replay.bookmarks) {
+    line("rgba(255, 255, 255, 0.6)",
+      Math.cos(bookmark) * 1.02, Math.sin(bookmark) * 1.02,
+      Math.cos(bookmark) * 1.07, Math.sin(bookmark) * 1.07);
+  }
+  for (const [name, color] of replay.lines) {
+    line(color, ...lines[name]);
+  }
+  line("rgba(255, 255, 255, 0.2)", 0, 0, c, s);
+
+  ctx.fillStyle = "rgba(255, 255, 255, 0.75)";
+  ctx.beginPath();
+  ctx.arc(c * radius, -s * radius, 8, 0, 2 * Math.PI);
+  ctx.fill();
+
+  document.getElementById("time").textContent =
+    position.toFixed(1) + " / " + total.toFixed(1) + " s";
+  document.getElementById("theta").textContent =
+    "θ = " + theta.toFixed(3) + "  rate = " + rate.toFixed(2) + " rad/s" +
+    (running ? "" : "  (paused)");
+}
+
+function frame(now) {
+  if (playing && last !== null) {
+    position += (now - last) / 1000;
+    if (position >= total) {
+      position = total;
+      playing = false;
+      play.textContent = "Play";
+    }
+    scrub.value = position;
+  }
+  last = now;
+  draw(sampleAt(position));
+  requestAnimationFrame(frame);
+}
+
+play.onclick = () => {
+  if (position >= total) {
+    position = 0;
+  }
+  playing = !playing;
+  play.textContent = playing ? "Pause" : "Play";
+};
+scrub.oninput = () => {
+  position = Number(scrub.value);
+};
+
+requestAnimationFrame(frame);
+</script>
+</body>
+</html>
+"#;
+
+/// A recorded session, exported as a web page which plays it back so that
+/// it can be shared without the app.
+#[derive(Debug)]
+pub struct Replay {
+    pub samples: Vec<Sample>,
+    /// The name and colour of each function shown, in the order they're
+    /// drawn.
+    pub lines: Vec<(&'static str, Rgb)>,
+    pub bookmarks: Vec<f32>,
+}
+
+impl Replay {
+    /// Writes the replay to `path` as a single HTML file, with the recording
+    /// embedded as JSON.
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, PLAYER_HTML.replacen("/*DATA*/", &self.to_json(), 1))
+    }
+
+    fn to_json(&self) -> String {
+        let mut json = String::from("{\"lines\": [");
+
+        for (i, (name, color)) in self.lines.iter().enumerate() {
+            let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+            _ = write!(
+                json,
+                "{}[\"{name}\", \"rgb({}, {}, {})\"]",
+                if i == 0 { "" } else { ", " },
+                channel(color.red),
+                channel(color.green),
+                channel(color.blue),
+            );
+        }
+
+        let bookmarks = self
+            .bookmarks
+            .iter()
+            .map(|theta| format!("{theta:.4}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        _ = write!(json, "], \"bookmarks\": [{bookmarks}], \"samples\": [");
+
+        // times are from the first sample, so playback starts straight away
+        let start = self.samples.first().map_or(0.0, |first| first.secs);
+        for (i, sample) in self.samples.iter().enumerate() {
+            _ = write!(
+                json,
+                "{}[{:.3}, {:.4}, {:.3}, {}]",
+                if i == 0 { "" } else { ", " },
+                sample.secs - start,
+                sample.theta,
+                sample.rate,
+                u8::from(sample.is_running),
+            );
+        }
+        json.push_str("]}");
+
+        json
+    }
+}
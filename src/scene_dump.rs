@@ -0,0 +1,77 @@
+use nannou::prelude::*;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A plain-text listing of what's drawn, one element per line, for diffing
+/// the scene between versions when layout or drawing changes.
+///
+/// Lines are sorted and numbers rounded, so that only real changes show up
+/// in a diff.
+#[derive(Debug, Default)]
+pub struct SceneDump {
+    lines: Vec<String>,
+}
+
+impl SceneDump {
+    pub fn value(&mut self, name: &str, value: impl Display) {
+        self.lines.push(format!("{name} {value}"));
+    }
+
+    pub fn point(&mut self, name: &str, pos: Vec2) {
+        self.lines.push(format!("{name} at {}", fmt_point(pos)));
+    }
+
+    pub fn rect(&mut self, name: &str, rect: Rect) {
+        self.lines.push(format!(
+            "{name} from {} to {}",
+            fmt_point(rect.bottom_left()),
+            fmt_point(rect.top_right()),
+        ));
+    }
+
+    pub fn line(&mut self, name: &str, start: Vec2, end: Vec2, color: Rgb) {
+        self.lines.push(format!(
+            "{name} from {} to {} color {}",
+            fmt_point(start),
+            fmt_point(end),
+            fmt_color(color),
+        ));
+    }
+
+    pub fn text(&mut self, name: &str, text: &str, pos: Vec2, color: Rgba) {
+        self.lines.push(format!(
+            "{name} {text:?} at {} color {} opacity {:.3}",
+            fmt_point(pos),
+            fmt_color(Rgb::new(color.red, color.green, color.blue)),
+            color.alpha,
+        ));
+    }
+
+    pub fn write(mut self, path: &Path) -> io::Result<()> {
+        self.lines.sort();
+
+        let mut contents = self.lines.join("\n");
+        contents.push('\n');
+
+        fs::write(path, contents)
+    }
+}
+
+fn fmt_point(pos: Vec2) -> String {
+    format!("({:.2}, {:.2})", pos.x, pos.y)
+}
+
+fn fmt_color(color: Rgb) -> String {
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        to_byte(color.red),
+        to_byte(color.green),
+        to_byte(color.blue)
+    )
+}
+
+fn to_byte(channel: f32) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+}
@@ -0,0 +1,228 @@
+use crate::chapters::Chapter;
+use crate::events::{quadrant, Event};
+use crate::ramp::RateRamp;
+use rhai::{Array, Dynamic, Engine, Map, Scope, AST, FLOAT, INT};
+use std::f64::consts::PI;
+use std::fmt;
+
+/// The script run every frame, if it exists.
+pub const SCRIPT_FILE: &str = "trig_visuals.rhai";
+
+/// Scripts are stopped after this many operations in a single frame, so an
+/// infinite loop can't freeze the app.
+const MAX_OPERATIONS: u64 = 100_000;
+
+/// The part of the visualisation a script can read and change.
+#[derive(Clone, Debug, Default)]
+pub struct ScriptState {
+    pub theta: f32,
+    pub rate: f32,
+    pub is_running: bool,
+    /// Text shown beneath the circle, if the script sets one.
+    pub caption: Option<String>,
+    /// How fast everything runs, as a multiple of real time.
+    pub time_scale: f32,
+    /// Whether the rate is being ramped.
+    pub ramping: bool,
+    /// A ramp of the rate the script started this frame.
+    pub ramp: Option<RateRamp>,
+}
+
+/// A user script which runs every frame, allowing custom motion profiles or
+/// captions without recompiling.
+///
+/// The script sees `theta`, `rate`, `running`, `dt` (the frame time in
+/// seconds, after the time scale), `time` (seconds since the script was
+/// loaded), `time_scale`, `ramping`, `quadrant`, `events` (the names of this
+/// frame's events, such as `"revolution_completed"`) and the value of each
+/// function (`sin`, `cos`, `tan`, `cot`, `sec`, `csc`). Changes to `theta`,
+/// `rate`, `running`, `caption` and `time_scale` are applied after it runs,
+/// and `memory` is an object map which keeps its contents between frames.
+///
+/// Setting `ramp` to an object map with a `to` rate, a `secs` duration and
+/// optionally a `from` rate, such as `#{ to: 1.0, secs: 20.0 }`, starts
+/// ramping the rate from then on.
+///
+/// A script may also set `chapters` to an array of object maps with a `title`
+/// and a `start` time, such as `#{ title: "Sine", start: 10.0 }`. Jumping to
+/// a chapter sets `time` to its start, so scripts driven by `time` can be
+/// seeked through.
+pub struct Script {
+    engine: Engine,
+    ast: AST,
+    memory: Map,
+    time: f32,
+    chapters: Vec<Chapter>,
+}
+
+impl fmt::Debug for Script {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Script")
+            .field("memory", &self.memory)
+            .field("time", &self.time)
+            .field("chapters", &self.chapters)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Script {
+    /// Loads and compiles [`SCRIPT_FILE`], returning `None` if there isn't
+    /// one or it fails to compile.
+    pub fn load() -> Option<Self> {
+        let source = std::fs::read_to_string(SCRIPT_FILE).ok()?;
+
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+
+        match engine.compile(source) {
+            Ok(ast) => Some(Self {
+                engine,
+                ast,
+                memory: Map::new(),
+                time: 0.0,
+                chapters: Vec::new(),
+            }),
+            Err(e) => {
+                eprintln!("failed to compile {SCRIPT_FILE}: {e}");
+                None
+            }
+        }
+    }
+
+    /// Runs the script for one frame, updating `state` with its changes.
+    pub fn run(
+        &mut self,
+        state: &mut ScriptState,
+        events: &[Event],
+        delta_time: f32,
+    ) -> Result<(), String> {
+        self.time += delta_time;
+
+        let theta = FLOAT::from(state.theta);
+        let (sin, cos) = theta.sin_cos();
+        let events: Array = events.iter().copied().map(event_map).collect();
+
+        let mut scope = Scope::new();
+        scope
+            .push("theta", theta)
+            .push("rate", FLOAT::from(state.rate))
+            .push("running", state.is_running)
+            .push("dt", FLOAT::from(delta_time))
+            .push("time", FLOAT::from(self.time))
+            .push("time_scale", FLOAT::from(state.time_scale))
+            .push("ramping", state.ramping)
+            .push("ramp", ())
+            .push("quadrant", INT::from(quadrant(state.theta)))
+            .push("events", events)
+            .push("sin", sin)
+            .push("cos", cos)
+            .push("tan", sin / cos)
+            .push("cot", cos / sin)
+            .push("sec", cos.recip())
+            .push("csc", sin.recip())
+            .push("caption", state.caption.clone().unwrap_or_default())
+            .push("memory", std::mem::take(&mut self.memory));
+
+        let result = self.engine.run_ast_with_scope(&mut scope, &self.ast);
+
+        // the memory is kept even if the script fails partway through
+        self.memory = scope.get_value("memory").unwrap_or_default();
+        result.map_err(|e| e.to_string())?;
+
+        let get_float = |name: &str, default: f32| {
+            scope
+                .get_value::<Dynamic>(name)
+                .as_ref()
+                .and_then(as_f32)
+                .unwrap_or(default)
+        };
+
+        state.theta = get_float("theta", state.theta);
+        state.rate = get_float("rate", state.rate);
+        state.time_scale = get_float("time_scale", state.time_scale);
+        state.is_running =
+            scope.get_value("running").unwrap_or(state.is_running);
+        state.caption = scope
+            .get_value::<String>("caption")
+            .filter(|caption| !caption.is_empty());
+        state.ramp = scope.get_value::<Map>("ramp").and_then(ramp);
+        self.chapters = scope
+            .get_value::<Array>("chapters")
+            .map(|chapters| chapters.into_iter().filter_map(chapter).collect())
+            .unwrap_or_default();
+
+        Ok(())
+    }
+
+    /// The seconds since the script was loaded, or since the last seek.
+    pub const fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Moves the script's clock to `time`.
+    pub fn seek(&mut self, time: f32) {
+        self.time = time.max(0.0);
+    }
+
+    /// The chapters set by the script when it last ran, in the order given.
+    pub fn chapters(&self) -> &[Chapter] {
+        &self.chapters
+    }
+}
+
+/// An event as an object map with its `name` and details, such as
+/// `#{ name: "special_angle_hit", twelfths: 3, angle: 0.785 }`.
+fn event_map(event: Event) -> Dynamic {
+    let mut map = Map::new();
+    map.insert("name".into(), Dynamic::from(event.name().to_string()));
+
+    match event {
+        Event::QuadrantChanged { from, to } => {
+            map.insert("from".into(), Dynamic::from(INT::from(from)));
+            map.insert("to".into(), Dynamic::from(INT::from(to)));
+        }
+        Event::RevolutionCompleted { count } => {
+            map.insert("count".into(), Dynamic::from(INT::from(count)));
+        }
+        Event::SpecialAngleHit { twelfths } => {
+            map.insert("twelfths".into(), Dynamic::from(INT::from(twelfths)));
+            map.insert(
+                "angle".into(),
+                Dynamic::from(FLOAT::from(twelfths) * PI / 12.0),
+            );
+        }
+        Event::CountdownReached => {}
+    }
+
+    Dynamic::from(map)
+}
+
+/// Reads a ramp from a `#{ from, to, secs }` object map, where `from` is
+/// optional.
+fn ramp(map: Map) -> Option<RateRamp> {
+    let float = |name: &str| map.get(name).and_then(as_f32);
+
+    Some(RateRamp::new(float("from"), float("to")?, float("secs")?))
+}
+
+/// Reads an integer or a float as an `f32`, or `None` if it isn't finite.
+fn as_f32(value: &Dynamic) -> Option<f32> {
+    // scripts work in double precision, but the app doesn't need it
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    value
+        .as_float()
+        .or_else(|_| value.as_int().map(|int| int as FLOAT))
+        .ok()
+        .map(|float| float as f32)
+        .filter(|float| float.is_finite())
+}
+
+/// Reads a chapter from a `#{ title, start }` object map, ignoring anything
+/// else.
+fn chapter(value: Dynamic) -> Option<Chapter> {
+    let map = value.try_cast::<Map>()?;
+    let title = map.get("title")?.clone().into_string().ok()?;
+    let start = as_f32(map.get("start")?)?;
+
+    Some(Chapter { title, start })
+}
@@ -0,0 +1,78 @@
+use std::f32::consts::TAU;
+
+/// The number of angles θ snaps to, one every π/12.
+const SNAP_STEPS: f32 = 24.0;
+const SNAP_STEP: f32 = TAU / SNAP_STEPS;
+/// How long the tick shown on snapping to a new angle lasts, in seconds.
+const TICK_SECS: f32 = 0.4;
+
+/// Snaps θ to the nearest multiple of π/12 while it's dragged or stepped,
+/// with a brief tick each time it lands on a new one.
+#[derive(Debug, Default)]
+pub struct Snap {
+    active: bool,
+    /// The angle last snapped to.
+    snapped: Option<f32>,
+    /// The time left on the tick.
+    tick: f32,
+}
+
+impl Snap {
+    pub fn toggle(&mut self) {
+        self.active = !self.active;
+        self.snapped = None;
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        self.tick = (self.tick - delta_time).max(0.0);
+    }
+
+    /// Snaps `angle` to the nearest multiple of π/12, or returns it as it is
+    /// if snapping is off.
+    pub fn apply(&mut self, angle: f32) -> f32 {
+        if !self.active {
+            return angle;
+        }
+
+        self.snap_to((angle / SNAP_STEP).round())
+    }
+
+    /// The next multiple of π/12 after `angle` in `direction` (`1` or `-1`).
+    pub fn step(&mut self, angle: f32, direction: f32) -> f32 {
+        // an angle already on a step moves a whole step
+        let steps = angle / SNAP_STEP;
+        let target = if direction > 0.0 {
+            (steps + 1.0e-3).floor() + 1.0
+        }
+        else {
+            (steps - 1.0e-3).ceil() - 1.0
+        };
+
+        self.snap_to(target)
+    }
+
+    /// The angle last snapped to and the strength of its tick, fading from
+    /// 1 to 0, while it's shown.
+    pub fn tick(&self) -> Option<(f32, f32)> {
+        let angle = self.snapped.filter(|_| self.tick > 0.0)?;
+        Some((angle, self.tick / TICK_SECS))
+    }
+
+    fn snap_to(&mut self, steps: f32) -> f32 {
+        let angle = steps.rem_euclid(SNAP_STEPS) * SNAP_STEP;
+
+        if self
+            .snapped
+            .is_none_or(|snapped| (snapped - angle).abs() > 1.0e-4)
+        {
+            self.snapped = Some(angle);
+            self.tick = TICK_SECS;
+        }
+
+        angle
+    }
+}
@@ -0,0 +1,140 @@
+use crate::consts::DEFAULT_RATE;
+use nannou_audio as audio;
+use std::f64::consts::TAU;
+use std::fmt;
+
+/// The pitch heard at the default rate.
+pub const REFERENCE_PITCH_HZ: f32 = 440.0;
+/// The amplitude of the tone.
+const VOLUME: f32 = 0.2;
+
+/// The pitch the circle sounds at when rotating at `rate`, scaled so that the
+/// default rate is A440.
+pub fn pitch_hz(rate: f32) -> f32 {
+    REFERENCE_PITCH_HZ * rate / DEFAULT_RATE
+}
+
+/// The rate at which the circle sounds at `hz`; the inverse of [`pitch_hz`].
+pub fn rate_for_pitch(hz: f32) -> f32 {
+    DEFAULT_RATE * hz / REFERENCE_PITCH_HZ
+}
+
+/// The name of the nearest equal-tempered note to `hz` (such as `A4`) and
+/// how far off it is in cents.
+pub fn nearest_note(hz: f32) -> Option<(String, i32)> {
+    const NAMES: [&str; 12] = [
+        "C", "C♯", "D", "D♯", "E", "F", "F♯", "G", "G♯", "A", "A♯", "B",
+    ];
+
+    if hz <= 0.0 {
+        return None;
+    }
+
+    let midi = 69.0 + 12.0 * (hz / REFERENCE_PITCH_HZ).log2();
+    let nearest = midi.round();
+    let cents = ((midi - nearest) * 100.0).round() as i32;
+
+    let nearest = nearest as i32;
+    let name = NAMES[nearest.rem_euclid(12) as usize];
+    let octave = nearest.div_euclid(12) - 1;
+
+    Some((format!("{name}{octave}"), cents))
+}
+
+/// The state of the tone on the audio thread.
+struct Tone {
+    phase: f64,
+    hz: f64,
+    amplitude: f32,
+    target_amplitude: f32,
+}
+
+fn render(tone: &mut Tone, buffer: &mut audio::Buffer) {
+    let sample_rate = f64::from(buffer.sample_rate());
+
+    for frame in buffer.frames_mut() {
+        // glide towards the target to avoid clicks when starting and stopping
+        tone.amplitude += (tone.target_amplitude - tone.amplitude) * 0.001;
+
+        let sample = (tone.phase * TAU).sin() as f32 * tone.amplitude;
+        tone.phase = (tone.phase + tone.hz / sample_rate).fract();
+
+        for channel in frame {
+            *channel = sample;
+        }
+    }
+}
+
+/// Plays the rotation of the circle as a sine tone.
+#[derive(Default)]
+pub struct Sound {
+    active: bool,
+    stream: Option<audio::Stream<Tone>>,
+}
+
+impl fmt::Debug for Sound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sound")
+            .field("active", &self.active)
+            .field("has_stream", &self.stream.is_some())
+            .finish()
+    }
+}
+
+impl Sound {
+    /// Toggles sound mode, opening the audio output the first time it's
+    /// used.
+    pub fn toggle(&mut self) {
+        self.active = !self.active;
+
+        if self.active && self.stream.is_none() {
+            match start_stream() {
+                Ok(stream) => self.stream = Some(stream),
+                Err(e) => eprintln!("failed to open audio output: {e}"),
+            }
+        }
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Sets the pitch of the tone, which is only heard while sound mode is
+    /// active and `audible` is set. If the audio output has stopped, sound
+    /// mode is turned off rather than failing again every frame.
+    pub fn update(&mut self, hz: f32, audible: bool) {
+        let Some(stream) = &self.stream
+        else {
+            return;
+        };
+
+        let amplitude = if self.active && audible { VOLUME } else { 0.0 };
+
+        if let Err(e) = stream.send(move |tone| {
+            tone.hz = f64::from(hz);
+            tone.target_amplitude = amplitude;
+        }) {
+            eprintln!("failed to update tone, turning sound off: {e}");
+            self.stream = None;
+            self.active = false;
+        }
+    }
+}
+
+fn start_stream() -> Result<audio::Stream<Tone>, String> {
+    let tone = Tone {
+        phase: 0.0,
+        hz: f64::from(REFERENCE_PITCH_HZ),
+        amplitude: 0.0,
+        target_amplitude: 0.0,
+    };
+
+    let stream = audio::Host::new()
+        .new_output_stream(tone)
+        .render(render)
+        .build()
+        .map_err(|e| e.to_string())?;
+    stream.play().map_err(|e| e.to_string())?;
+
+    Ok(stream)
+}
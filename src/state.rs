@@ -0,0 +1,445 @@
+use crate::calibration::{MAX_BRIGHTNESS, MAX_CONTRAST, MIN_CONTRAST};
+use crate::consts::FADE_TIME_SECS;
+use crate::epicycles::parse_circles;
+use crate::labels::{FadeEasing, Label};
+use crate::layout::Mirror;
+use crate::paper::{Paper, SpokeSpacing};
+use crate::watermark::Corner;
+use std::fs;
+use std::io;
+
+/// The file persistent state is kept in, relative to the working directory.
+pub const STATE_FILE: &str = "trig_visuals.state";
+/// The most of the window's width or height each edge of the safe area can
+/// cut off.
+pub const MAX_SAFE_INSET: f32 = 0.25;
+
+/// State persisted between runs, stored as `key = value` lines.
+#[derive(Clone, Debug)]
+pub struct AppState {
+    /// Whether the onboarding tour has been completed or skipped.
+    pub tour_seen: bool,
+    /// The version of the app which last wrote the state file.
+    pub last_version: Option<String>,
+    /// The fraction of values left blank on exported worksheets.
+    pub worksheet_blanks: f32,
+    /// The fraction of the window kept clear around the edges in broadcast
+    /// mode.
+    pub safe_margin: f32,
+    /// How much of the top, right, bottom and left of the window is kept
+    /// clear, as fractions of its size, for projectors which overscan.
+    pub safe_area: [f32; 4],
+    /// Text shown in a corner of the window and of exports.
+    pub watermark_text: Option<String>,
+    /// The path of an image shown in a corner of the window and of exports.
+    pub watermark_image: Option<String>,
+    pub watermark_opacity: f32,
+    pub watermark_corner: Corner,
+    /// A text-to-speech command which script captions are passed to, such
+    /// as `say` or `espeak -s 140`.
+    pub narration_command: Option<String>,
+    /// The paper style the app starts with.
+    pub paper: Paper,
+    /// Whether the polar grid is shown over the paper.
+    pub polar_grid: bool,
+    /// How far apart the polar grid's spokes are.
+    pub polar_spokes: SpokeSpacing,
+    /// The path of an image drawn behind the circle, such as graph paper or
+    /// a diagram.
+    pub background_image: Option<String>,
+    /// How strongly the paper and background image are drawn.
+    pub background_opacity: f32,
+    /// The size exported images are rendered at, in pixels, rather than the
+    /// size of the window.
+    pub export_resolution: Option<[u32; 2]>,
+    /// The largest denominator used when showing angles as fractions of π.
+    pub max_pi_denominator: u32,
+    /// How far the arrow keys step theta while paused, in degrees.
+    pub step_degrees: f32,
+    /// How far either side of a bookmark θ slows down in bullet time, in
+    /// degrees.
+    pub bullet_time_degrees: f32,
+    /// The fraction of the rate θ turns at as it passes a bookmark in bullet
+    /// time.
+    pub bullet_time_factor: f32,
+    /// The order the functions' lines and the unit line are drawn in, from
+    /// the bottom up.
+    pub draw_order: [Label; 7],
+    /// How long a label takes to fade back in once it's clear of the label
+    /// covering it, in seconds.
+    pub label_fade_in_secs: f32,
+    /// How long a label takes to fade out when another covers it, in
+    /// seconds.
+    pub label_fade_out_secs: f32,
+    pub label_fade_easing: FadeEasing,
+    /// The frequency and amplitude of each circle of the custom epicycles
+    /// waveform.
+    pub epicycles: Vec<(f32, f32)>,
+    /// Whether quitting asks for confirmation first.
+    pub confirm_quit: bool,
+    /// Added to every colour drawn, set on the calibration screen.
+    pub brightness: f32,
+    /// How far every colour drawn is pushed away from mid-grey, set on the
+    /// calibration screen.
+    pub contrast: f32,
+    /// How the figure is mirrored, with its labels kept readable.
+    pub mirror: Mirror,
+    /// How the whole window is mirrored, for rear projection.
+    pub scene_mirror: Mirror,
+}
+
+/// The order the functions' lines and the unit line are drawn in by default,
+/// from the bottom up.
+pub const DEFAULT_DRAW_ORDER: [Label; 7] = [
+    Label::Sin,
+    Label::Cos,
+    Label::Tan,
+    Label::Cot,
+    Label::Sec,
+    Label::Csc,
+    Label::Unit,
+];
+
+impl Default for AppState {
+    fn default() -> Self {
+        Self {
+            tour_seen: false,
+            last_version: None,
+            worksheet_blanks: 0.5,
+            safe_margin: 0.05,
+            safe_area: [0.0; 4],
+            watermark_text: None,
+            watermark_image: None,
+            watermark_opacity: 0.5,
+            watermark_corner: Corner::default(),
+            narration_command: None,
+            paper: Paper::default(),
+            polar_grid: false,
+            polar_spokes: SpokeSpacing::default(),
+            background_image: None,
+            background_opacity: 0.5,
+            export_resolution: None,
+            max_pi_denominator: 12,
+            step_degrees: 1.0,
+            bullet_time_degrees: 15.0,
+            bullet_time_factor: 0.2,
+            draw_order: DEFAULT_DRAW_ORDER,
+            label_fade_in_secs: FADE_TIME_SECS * 3.0,
+            label_fade_out_secs: FADE_TIME_SECS,
+            label_fade_easing: FadeEasing::default(),
+            epicycles: Vec::new(),
+            confirm_quit: true,
+            brightness: 0.0,
+            contrast: 1.0,
+            mirror: Mirror::default(),
+            scene_mirror: Mirror::default(),
+        }
+    }
+}
+
+impl AppState {
+    /// Loads the state file, returning `None` if there isn't one (i.e. on the
+    /// first run).
+    pub fn load() -> Option<Self> {
+        let contents = fs::read_to_string(STATE_FILE).ok()?;
+        let mut state = Self::default();
+
+        for (key, value) in contents
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim(), value.trim()))
+        {
+            match key {
+                "tour_seen" => state.tour_seen = value == "true",
+                "last_version" => state.last_version = Some(value.to_string()),
+                "worksheet_blanks" => {
+                    if let Some(fraction) = parse_finite(value) {
+                        state.worksheet_blanks = fraction.clamp(0.0, 1.0);
+                    }
+                }
+                "safe_margin" => {
+                    if let Some(fraction) = parse_finite(value) {
+                        state.safe_margin = fraction.clamp(0.0, 0.4);
+                    }
+                }
+                "safe_area" => {
+                    if let Some(insets) = parse_safe_area(value) {
+                        state.safe_area = insets;
+                    }
+                }
+                "watermark_text" if !value.is_empty() => {
+                    state.watermark_text = Some(value.to_string());
+                }
+                "watermark_image" if !value.is_empty() => {
+                    state.watermark_image = Some(value.to_string());
+                }
+                "watermark_opacity" => {
+                    if let Some(opacity) = parse_finite(value) {
+                        state.watermark_opacity = opacity.clamp(0.0, 1.0);
+                    }
+                }
+                "watermark_corner" => {
+                    if let Some(corner) = Corner::parse(value) {
+                        state.watermark_corner = corner;
+                    }
+                }
+                "narration_command" if !value.is_empty() => {
+                    state.narration_command = Some(value.to_string());
+                }
+                // polar paper became the polar grid, which can be shown on
+                // either paper
+                "paper" if value == "polar" => state.polar_grid = true,
+                "paper" => {
+                    if let Some(paper) = Paper::parse(value) {
+                        state.paper = paper;
+                    }
+                }
+                "polar_grid" => state.polar_grid = value == "true",
+                "polar_spokes" => {
+                    if let Some(spacing) = SpokeSpacing::parse(value) {
+                        state.polar_spokes = spacing;
+                    }
+                }
+                "background_image" if !value.is_empty() => {
+                    state.background_image = Some(value.to_string());
+                }
+                "background_opacity" => {
+                    if let Some(opacity) = parse_finite(value) {
+                        state.background_opacity = opacity.clamp(0.0, 1.0);
+                    }
+                }
+                "max_pi_denominator" => {
+                    if let Ok(den) = value.parse::<u32>() {
+                        state.max_pi_denominator = den.clamp(1, 64);
+                    }
+                }
+                "step_degrees" => {
+                    if let Some(step) = parse_finite(value) {
+                        state.step_degrees = step.clamp(0.01, 90.0);
+                    }
+                }
+                "bullet_time_degrees" => {
+                    if let Some(window) = parse_finite(value) {
+                        state.bullet_time_degrees = window.clamp(1.0, 90.0);
+                    }
+                }
+                "bullet_time_factor" => {
+                    if let Some(factor) = parse_finite(value) {
+                        state.bullet_time_factor = factor.clamp(0.01, 1.0);
+                    }
+                }
+                "label_fade_in_secs" => {
+                    if let Some(secs) = parse_finite(value) {
+                        state.label_fade_in_secs = secs.clamp(0.01, 5.0);
+                    }
+                }
+                "label_fade_out_secs" => {
+                    if let Some(secs) = parse_finite(value) {
+                        state.label_fade_out_secs = secs.clamp(0.01, 5.0);
+                    }
+                }
+                "label_fade_easing" => {
+                    if let Some(easing) = FadeEasing::parse(value) {
+                        state.label_fade_easing = easing;
+                    }
+                }
+                "epicycles" => state.epicycles = parse_circles(value),
+                "confirm_quit" => state.confirm_quit = value != "false",
+                "brightness" => {
+                    if let Some(brightness) = parse_finite(value) {
+                        state.brightness =
+                            brightness.clamp(-MAX_BRIGHTNESS, MAX_BRIGHTNESS);
+                    }
+                }
+                "contrast" => {
+                    if let Some(contrast) = parse_finite(value) {
+                        state.contrast =
+                            contrast.clamp(MIN_CONTRAST, MAX_CONTRAST);
+                    }
+                }
+                "draw_order" => state.draw_order = parse_draw_order(value),
+                "mirror" => {
+                    if let Some(mirror) = Mirror::parse(value) {
+                        state.mirror = mirror;
+                    }
+                }
+                "scene_mirror" => {
+                    if let Some(mirror) = Mirror::parse(value) {
+                        state.scene_mirror = mirror;
+                    }
+                }
+                "export_resolution" => {
+                    state.export_resolution = parse_resolution(value);
+                }
+                _ => {}
+            }
+        }
+
+        Some(state)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut contents = format!(
+            "tour_seen = {}\nworksheet_blanks = {}\nsafe_margin = {}\n\
+             watermark_opacity = {}\nwatermark_corner = {}\n\
+             paper = {}\npolar_grid = {}\npolar_spokes = {}\n\
+             background_opacity = {}\n\
+             max_pi_denominator = {}\n\
+             step_degrees = {}\nbullet_time_degrees = {}\n\
+             bullet_time_factor = {}\ndraw_order = {}\n\
+             label_fade_in_secs = {}\nlabel_fade_out_secs = {}\n\
+             label_fade_easing = {}\nsafe_area = {}\nconfirm_quit = {}\n\
+             brightness = {}\ncontrast = {}\nmirror = {}\n\
+             scene_mirror = {}\n",
+            self.tour_seen,
+            self.worksheet_blanks,
+            self.safe_margin,
+            self.watermark_opacity,
+            self.watermark_corner.name(),
+            self.paper.name(),
+            self.polar_grid,
+            self.polar_spokes.degrees(),
+            self.background_opacity,
+            self.max_pi_denominator,
+            self.step_degrees,
+            self.bullet_time_degrees,
+            self.bullet_time_factor,
+            self.draw_order.map(Label::name).join(", "),
+            self.label_fade_in_secs,
+            self.label_fade_out_secs,
+            self.label_fade_easing.name(),
+            self.safe_area.map(|inset| inset.to_string()).join(", "),
+            self.confirm_quit,
+            self.brightness,
+            self.contrast,
+            self.mirror.name(),
+            self.scene_mirror.name(),
+        );
+
+        if let Some(version) = &self.last_version {
+            contents.push_str(&format!("last_version = {version}\n"));
+        }
+        if let Some(text) = &self.watermark_text {
+            contents.push_str(&format!("watermark_text = {text}\n"));
+        }
+        if let Some(image) = &self.watermark_image {
+            contents.push_str(&format!("watermark_image = {image}\n"));
+        }
+        if let Some(image) = &self.background_image {
+            contents.push_str(&format!("background_image = {image}\n"));
+        }
+        if let Some([w, h]) = self.export_resolution {
+            contents.push_str(&format!("export_resolution = {w}x{h}\n"));
+        }
+        if let Some(command) = &self.narration_command {
+            contents.push_str(&format!("narration_command = {command}\n"));
+        }
+        if !self.epicycles.is_empty() {
+            let circles = self
+                .epicycles
+                .iter()
+                .map(|(freq, amp)| format!("{freq}:{amp}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            contents.push_str(&format!("epicycles = {circles}\n"));
+        }
+
+        fs::write(STATE_FILE, contents)
+    }
+}
+
+/// Parses `value` as a number if it's finite, as `nan` would get through
+/// clamping.
+fn parse_finite(value: &str) -> Option<f32> {
+    value.parse::<f32>().ok().filter(|val| val.is_finite())
+}
+
+/// Parses the top, right, bottom and left insets of the safe area, such as
+/// `0.05, 0.03, 0.05, 0.03`.
+fn parse_safe_area(value: &str) -> Option<[f32; 4]> {
+    let insets = value
+        .split(',')
+        .map(|inset| {
+            parse_finite(inset.trim())
+                .map(|inset| inset.clamp(0.0, MAX_SAFE_INSET))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    insets.try_into().ok()
+}
+
+/// Parses a resolution such as `3840x2160`.
+fn parse_resolution(value: &str) -> Option<[u32; 2]> {
+    let (w, h) = value.split_once('x')?;
+    let (w, h) = (w.trim().parse().ok()?, h.trim().parse().ok()?);
+
+    (w > 0 && h > 0).then_some([w, h])
+}
+
+/// Parses a draw order such as `sec, sin`, from the bottom up. Lines which
+/// aren't listed are drawn beneath those which are, in their default order.
+fn parse_draw_order(value: &str) -> [Label; 7] {
+    let mut listed = Vec::new();
+    for label in value
+        .split(',')
+        .filter_map(|name| Label::parse(name.trim()))
+    {
+        if DEFAULT_DRAW_ORDER.contains(&label) && !listed.contains(&label) {
+            listed.push(label);
+        }
+    }
+
+    let mut order: Vec<_> = DEFAULT_DRAW_ORDER
+        .into_iter()
+        .filter(|label| !listed.contains(label))
+        .collect();
+    order.extend(listed);
+
+    order.try_into().unwrap_or(DEFAULT_DRAW_ORDER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_finite_rejects_nan_and_infinity() {
+        assert_eq!(parse_finite("1.5"), Some(1.5));
+        assert_eq!(parse_finite("nan"), None);
+        assert_eq!(parse_finite("inf"), None);
+        assert_eq!(parse_safe_area("0.1, NaN, 0.1, 0.1"), None);
+    }
+
+    #[test]
+    fn draw_order_puts_listed_lines_on_top() {
+        assert_eq!(
+            parse_draw_order("sec, sin"),
+            [
+                Label::Cos,
+                Label::Tan,
+                Label::Cot,
+                Label::Csc,
+                Label::Unit,
+                Label::Sec,
+                Label::Sin,
+            ]
+        );
+    }
+
+    #[test]
+    fn draw_order_ignores_unknown_and_repeated_names() {
+        assert_eq!(
+            parse_draw_order("sin, theta, nonsense, sin"),
+            [
+                Label::Cos,
+                Label::Tan,
+                Label::Cot,
+                Label::Sec,
+                Label::Csc,
+                Label::Unit,
+                Label::Sin,
+            ]
+        );
+        assert_eq!(parse_draw_order(""), DEFAULT_DRAW_ORDER);
+    }
+}
@@ -0,0 +1,87 @@
+use std::cell::Cell;
+use std::collections::VecDeque;
+
+/// The number of frames the averages are taken over.
+const WINDOW_FRAMES: usize = 60;
+
+/// A running average over the last [`WINDOW_FRAMES`] samples.
+#[derive(Debug, Default)]
+struct Rolling {
+    samples: VecDeque<f32>,
+    sum: f32,
+}
+
+impl Rolling {
+    fn push(&mut self, sample: f32) {
+        if self.samples.len() == WINDOW_FRAMES {
+            self.sum -= self.samples.pop_front().unwrap_or_default();
+        }
+        self.samples.push_back(sample);
+        self.sum += sample;
+    }
+
+    fn average(&self) -> f32 {
+        if self.samples.is_empty() {
+            0.0
+        }
+        else {
+            self.sum / self.samples.len() as f32
+        }
+    }
+}
+
+/// Frame timings, averaged over the last second or so, for spotting
+/// performance regressions.
+#[derive(Debug, Default)]
+pub struct Stats {
+    visible: bool,
+    frame: Rolling,
+    update: Rolling,
+    draw: Rolling,
+    render: Rolling,
+    /// The time taken to build and render the last frame's drawing, which is
+    /// recorded from `view` and added to the averages on the next update.
+    last_view: Cell<(f32, f32)>,
+}
+
+impl Stats {
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    pub const fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Records the length of a frame and the time spent updating it, in
+    /// seconds.
+    pub fn record_update(&mut self, frame_secs: f32, update_secs: f32) {
+        let (draw_secs, render_secs) = self.last_view.get();
+
+        self.frame.push(frame_secs);
+        self.update.push(update_secs);
+        self.draw.push(draw_secs);
+        self.render.push(render_secs);
+    }
+
+    /// Records the time spent building the scene's drawing and rendering it
+    /// to the frame, in seconds.
+    pub fn record_view(&self, draw_secs: f32, render_secs: f32) {
+        self.last_view.set((draw_secs, render_secs));
+    }
+
+    /// Each statistic's name and formatted value.
+    pub fn lines(&self) -> [String; 5] {
+        let frame = self.frame.average();
+        let fps = if frame > 0.0 { frame.recip() } else { 0.0 };
+        let millis = |rolling: &Rolling| rolling.average() * 1000.0;
+
+        [
+            format!("{fps:.0} fps"),
+            format!("frame  {:.2} ms", millis(&self.frame)),
+            format!("update {:.2} ms", millis(&self.update)),
+            format!("draw   {:.2} ms", millis(&self.draw)),
+            format!("render {:.2} ms", millis(&self.render)),
+        ]
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::VecDeque;
+use std::f32::consts::{PI, TAU};
+
+/// The number of flashes per second.
+pub const STROBE_HZ: f32 = 10.0;
+/// How many past flashes are kept for drawing.
+const TRAIL_LEN: usize = 6;
+
+/// A virtual strobe light which samples θ at fixed intervals, showing how a
+/// fast rotation can appear slow or even backwards (the wagon-wheel effect).
+#[derive(Debug, Default)]
+pub struct Strobe {
+    active: bool,
+    since_flash: f32,
+    /// The θ seen at each recent flash, newest last.
+    flashes: VecDeque<f32>,
+    /// The rate to return to when the demo ends.
+    previous_rate: f32,
+}
+
+impl Strobe {
+    /// Starts or stops the demo, returning the rate to switch to: just below
+    /// one revolution per flash when starting, or the previous rate when
+    /// stopping.
+    pub fn toggle(&mut self, rate: f32) -> f32 {
+        self.active = !self.active;
+        self.flashes.clear();
+        self.since_flash = 0.0;
+
+        if self.active {
+            self.previous_rate = rate;
+            TAU * STROBE_HZ - 0.5
+        }
+        else {
+            self.previous_rate
+        }
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Advances the strobe's fixed timestep, flashing as many times as fit in
+    /// `delta_time`. θ at each flash is worked back from the end of the frame.
+    pub fn update(&mut self, delta_time: f32, theta: f32, rate: f32) {
+        const INTERVAL: f32 = 1.0 / STROBE_HZ;
+
+        if !self.active {
+            return;
+        }
+
+        self.since_flash += delta_time;
+
+        while self.since_flash >= INTERVAL {
+            self.since_flash -= INTERVAL;
+
+            let flash = (theta - rate * self.since_flash).rem_euclid(TAU);
+            if self.flashes.len() == TRAIL_LEN {
+                self.flashes.pop_front();
+            }
+            self.flashes.push_back(flash);
+        }
+    }
+
+    /// θ as seen at the latest flash.
+    pub fn latest(&self) -> Option<f32> {
+        self.flashes.back().copied().filter(|_| self.active)
+    }
+
+    /// θ at each recent flash, oldest first.
+    pub fn flashes(&self) -> impl Iterator<Item = f32> + '_ {
+        self.flashes.iter().copied()
+    }
+}
+
+/// The rate a rotation at `rate` appears to have when seen under a strobe
+/// at [`STROBE_HZ`], as the movement between flashes is ambiguous by whole
+/// revolutions.
+pub fn apparent_rate(rate: f32) -> f32 {
+    let per_flash = rate / STROBE_HZ;
+    // wrapped to (-π, π], the smallest movement which looks the same
+    let wrapped = PI - (PI - per_flash).rem_euclid(TAU);
+    wrapped * STROBE_HZ
+}
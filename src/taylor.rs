@@ -0,0 +1,73 @@
+use std::f32::consts::{PI, TAU};
+
+/// The highest degree of the approximations.
+pub const MAX_DEGREE: u32 = 15;
+
+/// The Taylor polynomials of sin and cos about 0, up to a chosen degree,
+/// drawn over the wave traces to show where they match the true curves.
+#[derive(Debug)]
+pub struct Taylor {
+    active: bool,
+    degree: u32,
+}
+
+impl Default for Taylor {
+    fn default() -> Self {
+        Self { active: false, degree: 5 }
+    }
+}
+
+impl Taylor {
+    pub fn toggle(&mut self) {
+        self.active = !self.active;
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub const fn degree(&self) -> u32 {
+        self.degree
+    }
+
+    pub fn change_degree(&mut self, delta: i32) {
+        self.degree = self
+            .degree
+            .saturating_add_signed(delta)
+            .clamp(1, MAX_DEGREE);
+    }
+
+    /// The approximation of sin x, with `x` wrapped to `-π..π` first so that
+    /// it matches the circle's angle rather than the angle travelled.
+    pub fn sin(&self, x: f32) -> f32 {
+        series(wrap(x), 1, self.degree)
+    }
+
+    /// The approximation of cos x, wrapped like [`Taylor::sin`].
+    pub fn cos(&self, x: f32) -> f32 {
+        series(wrap(x), 0, self.degree)
+    }
+}
+
+/// Wraps `x` to `-π..π`, where the polynomials are most accurate.
+fn wrap(x: f32) -> f32 {
+    (x + PI).rem_euclid(TAU) - PI
+}
+
+/// Sums the alternating terms `xᵏ / k!` for every other `k` from `first` up
+/// to `degree`: the odd terms for sin and the even terms for cos.
+fn series(x: f32, first: u32, degree: u32) -> f32 {
+    let mut term = if first == 0 { 1.0 } else { x };
+    let mut sum = 0.0;
+    let mut k = first;
+
+    while k <= degree {
+        sum += term;
+        // each term follows from the last, rather than from powers and
+        // factorials which overflow quickly
+        term *= -x * x / ((k + 1) * (k + 2)) as f32;
+        k += 2;
+    }
+
+    sum
+}
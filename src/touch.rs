@@ -0,0 +1,95 @@
+use nannou::prelude::*;
+use std::collections::BTreeMap;
+
+/// The longest a two-finger tap can last, in seconds.
+const TAP_SECS: f32 = 0.3;
+/// How far either finger can move during a tap.
+const TAP_DISTANCE: f32 = 20.0;
+
+/// A gesture made with two fingers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Gesture {
+    /// The fingers turned about each other by an angle in radians,
+    /// anticlockwise being positive.
+    Rotate(f32),
+    Tap,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Finger {
+    start: Vec2,
+    pos: Vec2,
+}
+
+/// Recognises two-finger gestures from touch events.
+#[derive(Debug, Default)]
+pub struct Touches {
+    /// The fingers which are down, by their touch ID.
+    fingers: BTreeMap<u64, Finger>,
+    /// When the second finger went down, while there are two.
+    pair_start: Option<f32>,
+    /// Whether either of the two fingers has moved too far for a tap.
+    pair_moved: bool,
+}
+
+impl Touches {
+    /// Handles a touch event at `time` seconds, returning the gesture it
+    /// completes or continues, if any.
+    pub fn handle(&mut self, event: TouchEvent, time: f32) -> Option<Gesture> {
+        match event.phase {
+            TouchPhase::Started => {
+                let finger =
+                    Finger { start: event.position, pos: event.position };
+                self.fingers.insert(event.id, finger);
+
+                // a third finger cancels the gesture
+                self.pair_start = (self.fingers.len() == 2).then_some(time);
+                self.pair_moved = false;
+
+                None
+            }
+            TouchPhase::Moved => {
+                let before = self.pair_angle();
+
+                let finger = self.fingers.get_mut(&event.id)?;
+                finger.pos = event.position;
+                if finger.pos.distance(finger.start) > TAP_DISTANCE {
+                    self.pair_moved = true;
+                }
+
+                let turned = self.pair_angle()? - before?;
+                // the shortest way round, in case it crossed ±π
+                Some(Gesture::Rotate((turned + PI).rem_euclid(TAU) - PI))
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                let was_pair = self.fingers.len() == 2;
+                self.fingers.remove(&event.id);
+
+                // the tap happens as soon as either finger lifts
+                let is_tap = was_pair
+                    && event.phase == TouchPhase::Ended
+                    && !self.pair_moved
+                    && self
+                        .pair_start
+                        .is_some_and(|start| time - start <= TAP_SECS);
+                self.pair_start = None;
+
+                is_tap.then_some(Gesture::Tap)
+            }
+        }
+    }
+
+    /// The angle of the line from the first finger to the second, if exactly
+    /// two are down.
+    fn pair_angle(&self) -> Option<f32> {
+        let mut fingers = self.fingers.values();
+
+        match (fingers.next(), fingers.next(), fingers.next()) {
+            (Some(a), Some(b), None) => {
+                let line = b.pos - a.pos;
+                Some(line.y.atan2(line.x))
+            }
+            _ => None,
+        }
+    }
+}
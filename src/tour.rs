@@ -0,0 +1,96 @@
+use crate::labels::Label;
+
+/// The part of the scene a tour step draws attention to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Highlight {
+    Circle,
+    Segment(Label),
+    ValuePanel,
+    None,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TourStep {
+    pub caption: &'static str,
+    pub highlight: Highlight,
+}
+
+const STEPS: [TourStep; 9] = [
+    TourStep {
+        caption: "This is the unit circle, which has a radius of 1. \
+                  The point moves around it as θ increases.",
+        highlight: Highlight::Circle,
+    },
+    TourStep {
+        caption: "sin θ is the height of the point above the x-axis.",
+        highlight: Highlight::Segment(Label::Sin),
+    },
+    TourStep {
+        caption: "cos θ is the distance of the point from the y-axis.",
+        highlight: Highlight::Segment(Label::Cos),
+    },
+    TourStep {
+        caption: "tan θ is measured along the vertical tangent to the \
+                  circle at x = 1.",
+        highlight: Highlight::Segment(Label::Tan),
+    },
+    TourStep {
+        caption: "cot θ runs along the tangent at the point, from the \
+                  point to the y-axis.",
+        highlight: Highlight::Segment(Label::Cot),
+    },
+    TourStep {
+        caption: "sec θ is the distance from the origin to the top of the \
+                  tan θ line.",
+        highlight: Highlight::Segment(Label::Sec),
+    },
+    TourStep {
+        caption: "csc θ is the distance from the origin to where the cot θ \
+                  line meets the y-axis.",
+        highlight: Highlight::Segment(Label::Csc),
+    },
+    TourStep {
+        caption: "The panel on the right shows each value. Click a value to \
+                  hide or show its line, or a heading to fold its group away.",
+        highlight: Highlight::ValuePanel,
+    },
+    TourStep {
+        caption: "Space starts and stops the motion, Up and Down change its \
+                  speed, and R resets θ. Press F1 to see this tour again.",
+        highlight: Highlight::None,
+    },
+];
+
+/// A short guided tour of the scene, advanced one step at a time.
+#[derive(Debug, Default)]
+pub struct Tour {
+    step: Option<usize>,
+}
+
+impl Tour {
+    pub fn start(&mut self) {
+        self.step = Some(0);
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.step.is_some()
+    }
+
+    pub fn current(&self) -> Option<(usize, TourStep)> {
+        self.step.map(|i| (i, STEPS[i]))
+    }
+
+    pub const fn num_steps() -> usize {
+        STEPS.len()
+    }
+
+    /// Moves to the next step, returning `true` if the tour has finished.
+    pub fn advance(&mut self) -> bool {
+        self.step = self.step.map(|i| i + 1).filter(|&i| i < STEPS.len());
+        self.step.is_none()
+    }
+
+    pub fn skip(&mut self) {
+        self.step = None;
+    }
+}
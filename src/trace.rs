@@ -0,0 +1,138 @@
+use std::collections::VecDeque;
+use std::f32::consts::{PI, TAU};
+
+/// How far back the trace goes, in radians travelled.
+const MAX_TRAVEL: f32 = 4.0 * TAU;
+
+/// A point on the traces, recorded as θ moves.
+#[derive(Clone, Copy, Debug)]
+pub struct TracePoint {
+    /// The total angle travelled when the point was recorded.
+    pub travelled: f32,
+    pub sin: f32,
+    pub cos: f32,
+}
+
+/// A record of past values of sin θ and cos θ against the angle travelled,
+/// for drawing them "unwrapped" from the circle as waves.
+#[derive(Debug, Default)]
+pub struct Trace {
+    active: bool,
+    /// Whether the area under the sine trace from 0 to θ is shaded.
+    integral: bool,
+    points: VecDeque<TracePoint>,
+    travelled: f32,
+    last_theta: Option<f32>,
+}
+
+impl Trace {
+    pub fn toggle(&mut self) {
+        self.active = !self.active;
+        self.points.clear();
+        self.travelled = 0.0;
+        self.last_theta = None;
+    }
+
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Toggles the shading of the area under the sine trace, showing the
+    /// traces too if they're hidden.
+    pub fn toggle_integral(&mut self) {
+        self.integral = !self.integral;
+
+        if self.integral && !self.active {
+            self.toggle();
+        }
+    }
+
+    pub const fn shows_integral(&self) -> bool {
+        self.active && self.integral
+    }
+
+    /// Records θ if it has moved since the last frame. θ is taken to have
+    /// moved the shorter way around, and moving backward rewinds the trace.
+    pub fn update(&mut self, theta: f32) {
+        if !self.active {
+            return;
+        }
+
+        // in [-π, π), so a step back isn't taken as most of a turn forward
+        let delta = self
+            .last_theta
+            .replace(theta)
+            .map_or(0.0, |last| (theta - last + PI).rem_euclid(TAU) - PI);
+        if delta == 0.0 && !self.points.is_empty() {
+            return;
+        }
+
+        self.travelled += delta;
+        while self
+            .points
+            .back()
+            .is_some_and(|pt| pt.travelled >= self.travelled)
+        {
+            self.points.pop_back();
+        }
+        self.points.push_back(TracePoint {
+            travelled: self.travelled,
+            sin: theta.sin(),
+            cos: theta.cos(),
+        });
+
+        while self
+            .points
+            .front()
+            .is_some_and(|pt| self.travelled - pt.travelled > MAX_TRAVEL)
+        {
+            self.points.pop_front();
+        }
+    }
+
+    /// The recorded points, each paired with how far behind the newest one
+    /// it is in radians.
+    pub fn points(&self) -> impl Iterator<Item = (f32, TracePoint)> + '_ {
+        self.points
+            .iter()
+            .map(|&pt| (self.travelled - pt.travelled, pt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn active_trace() -> Trace {
+        let mut trace = Trace::default();
+        trace.toggle();
+        trace
+    }
+
+    #[test]
+    fn moving_forward_across_zero_adds_the_short_way() {
+        let mut trace = active_trace();
+        trace.update(TAU - 0.1);
+        trace.update(0.1);
+
+        let behind: Vec<f32> =
+            trace.points().map(|(behind, _)| behind).collect();
+        assert_eq!(behind.len(), 2);
+        assert!((behind[0] - 0.2).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn stepping_back_rewinds_the_trace() {
+        let mut trace = active_trace();
+        for step in 0..=10 {
+            trace.update(step as f32 * 0.1);
+        }
+        trace.update(0.55);
+
+        let points: Vec<_> = trace.points().collect();
+        assert_eq!(points.len(), 7);
+        assert!(points.iter().all(|&(behind, _)| behind >= 0.0));
+        assert!((points[0].0 - 0.55).abs() < 1.0e-4);
+        assert!((points[6].1.sin - 0.55_f32.sin()).abs() < 1.0e-6);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::labels::Label;
+
+/// A group of rows in the value panel, which can be collapsed to its heading.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueGroup {
+    /// sin, cos and tan.
+    Primary,
+    /// cot, sec and csc.
+    Reciprocal,
+    /// Values worked out from the others: the Pythagorean identity and the
+    /// arc length.
+    Derived,
+}
+
+impl ValueGroup {
+    pub const ALL: [Self; 3] = [Self::Primary, Self::Reciprocal, Self::Derived];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::Reciprocal => "reciprocal",
+            Self::Derived => "derived",
+        }
+    }
+
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Primary => 0,
+            Self::Reciprocal => 1,
+            Self::Derived => 2,
+        }
+    }
+}
+
+/// A line of the value panel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueLine {
+    Theta,
+    /// The heading of a group, which collapses or expands it when clicked.
+    Heading(ValueGroup),
+    Value(Label),
+    /// The complex point `z = cos θ + i sin θ`, in complex plane mode.
+    Z,
+    /// `sin²θ + cos²θ`, which is always 1.
+    Identity,
+    /// The length of the arc from 0 to θ.
+    ArcLength,
+    Rate,
+}
+
+impl ValueLine {
+    /// The group the line belongs to, if any.
+    pub const fn group(self) -> Option<ValueGroup> {
+        match self {
+            Self::Heading(group) => Some(group),
+            Self::Value(Label::Sin | Label::Cos | Label::Tan) => {
+                Some(ValueGroup::Primary)
+            }
+            Self::Value(_) => Some(ValueGroup::Reciprocal),
+            Self::Identity | Self::ArcLength => Some(ValueGroup::Derived),
+            Self::Theta | Self::Z | Self::Rate => None,
+        }
+    }
+
+    /// Whether clicking the line does anything, which also makes it
+    /// focusable.
+    pub const fn is_interactive(self) -> bool {
+        matches!(self, Self::Heading(_) | Self::Value(_))
+    }
+}
+
+/// Which groups of the value panel are collapsed.
+#[derive(Debug)]
+pub struct ValueGroups {
+    collapsed: [bool; 3],
+}
+
+impl Default for ValueGroups {
+    /// The derived values start collapsed, so the panel shows the six
+    /// functions as it always has.
+    fn default() -> Self {
+        Self { collapsed: [false, false, true] }
+    }
+}
+
+impl ValueGroups {
+    pub fn toggle(&mut self, group: ValueGroup) {
+        let collapsed = &mut self.collapsed[group.index()];
+        *collapsed = !*collapsed;
+    }
+
+    pub const fn is_collapsed(&self, group: ValueGroup) -> bool {
+        self.collapsed[group.index()]
+    }
+
+    /// The lines of the panel from top to bottom, leaving out the rows of
+    /// collapsed groups.
+    pub fn lines(&self, complex_plane: bool) -> Vec<ValueLine> {
+        let mut lines = vec![ValueLine::Theta];
+
+        for group in ValueGroup::ALL {
+            lines.push(ValueLine::Heading(group));
+
+            if self.is_collapsed(group) {
+                continue;
+            }
+
+            match group {
+                ValueGroup::Primary => lines.extend(
+                    [Label::Sin, Label::Cos, Label::Tan].map(ValueLine::Value),
+                ),
+                ValueGroup::Reciprocal => lines.extend(
+                    [Label::Cot, Label::Sec, Label::Csc].map(ValueLine::Value),
+                ),
+                ValueGroup::Derived => {
+                    lines.extend([ValueLine::Identity, ValueLine::ArcLength]);
+                }
+            }
+        }
+
+        // z isn't part of a group, but sits with the primary functions it's
+        // made from
+        if complex_plane {
+            let after_primary = lines
+                .iter()
+                .position(|&line| {
+                    line == ValueLine::Heading(ValueGroup::Reciprocal)
+                })
+                .unwrap_or(lines.len());
+            lines.insert(after_primary, ValueLine::Z);
+        }
+
+        lines.push(ValueLine::Rate);
+        lines
+    }
+}
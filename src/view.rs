@@ -0,0 +1,67 @@
+use nannou::prelude::*;
+
+/// The transform from world coordinates, in which the unit circle is centred
+/// on the origin with a radius of `1`, to pixels.
+///
+/// Geometry is worked out in world coordinates, so that it's the same at any
+/// size or resolution, and only turned into pixels when it's drawn. Text is a
+/// fixed size in pixels, so labels are placed with a [`Placement`] instead.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct View {
+    /// Where the centre of the circle is, in pixels.
+    pub origin: Vec2,
+    /// The number of pixels in a world unit, which is the circle's radius.
+    pub scale: f32,
+    /// How far world coordinates are turned counterclockwise, in radians.
+    pub rotation: f32,
+}
+
+impl View {
+    pub const fn new(origin: Vec2, scale: f32) -> Self {
+        Self { origin, scale, rotation: 0.0 }
+    }
+
+    /// This view turned counterclockwise by `angle`, so that the x-axis of
+    /// world coordinates points along it.
+    pub const fn rotated(self, angle: f32) -> Self {
+        Self { rotation: angle, ..self }
+    }
+
+    /// The position of `world` in pixels, kept finite so that the lines of
+    /// undefined values can still be drawn.
+    pub fn to_pixels(&self, world: Vec2) -> Vec2 {
+        (self.origin + turn(world, self.rotation) * self.scale)
+            .clamp(Vec2::splat(-f32::MAX), Vec2::splat(f32::MAX))
+    }
+
+    /// The position of `pixels` in world coordinates.
+    pub fn to_world(&self, pixels: Vec2) -> Vec2 {
+        turn((pixels - self.origin) / self.scale, -self.rotation)
+    }
+
+    /// The position of `placement` in pixels.
+    pub fn place(&self, placement: Placement) -> Vec2 {
+        self.to_pixels(placement.anchor) + turn(placement.offset, self.rotation)
+    }
+}
+
+/// `v` turned counterclockwise by `angle`.
+fn turn(v: Vec2, angle: f32) -> Vec2 {
+    let (sin, cos) = angle.sin_cos();
+    vec2(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
+}
+
+/// Where a label sits: an anchor on the figure, in world coordinates, and an
+/// offset from it in pixels, which keeps the label clear of its line whatever
+/// the size of the circle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placement {
+    pub anchor: Vec2,
+    pub offset: Vec2,
+}
+
+impl Placement {
+    pub const fn new(anchor: Vec2, offset: Vec2) -> Self {
+        Self { anchor, offset }
+    }
+}
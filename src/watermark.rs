@@ -0,0 +1,121 @@
+use crate::state::AppState;
+use std::path::PathBuf;
+
+/// The gap between a watermark and the edges of the page or window.
+const MARGIN: f32 = 15.0;
+/// The gap between a watermark's image and its text.
+const GAP: f32 = 6.0;
+
+/// A corner of the window or page.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+impl Corner {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "top_left" => Some(Self::TopLeft),
+            "top_right" => Some(Self::TopRight),
+            "bottom_left" => Some(Self::BottomLeft),
+            "bottom_right" => Some(Self::BottomRight),
+            _ => None,
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::TopLeft => "top_left",
+            Self::TopRight => "top_right",
+            Self::BottomLeft => "bottom_left",
+            Self::BottomRight => "bottom_right",
+        }
+    }
+}
+
+/// Where the parts of a watermark go, as centre points.
+#[derive(Clone, Copy, Debug)]
+pub struct Placement {
+    pub image: [f32; 2],
+    pub text: [f32; 2],
+}
+
+/// An image and/or line of text drawn in a corner of the window and of every
+/// exported page, for institutions which require branded materials.
+#[derive(Clone, Debug, Default)]
+pub struct Watermark {
+    pub text: Option<String>,
+    /// A PNG (or other image) file, loaded at startup.
+    pub image: Option<PathBuf>,
+    pub opacity: f32,
+    pub corner: Corner,
+}
+
+impl Watermark {
+    pub fn from_state(state: &AppState) -> Self {
+        Self {
+            text: state.watermark_text.clone(),
+            image: state.watermark_image.as_ref().map(PathBuf::from),
+            opacity: state.watermark_opacity,
+            corner: state.watermark_corner,
+        }
+    }
+
+    /// The watermark set in the state file, if any.
+    pub fn load() -> Self {
+        AppState::load()
+            .map(|state| Self::from_state(&state))
+            .unwrap_or_default()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.text.is_none() && self.image.is_none()
+    }
+
+    /// Places the watermark in its corner of `bounds` (given as the minimum
+    /// and maximum points, with y up), with the text beneath the image.
+    /// `image_size` is `[0, 0]` if there's no image.
+    pub fn place(
+        &self,
+        bounds: [[f32; 2]; 2],
+        image_size: [f32; 2],
+        font_size: f32,
+    ) -> Placement {
+        let [[left, bottom], [right, top]] = bounds;
+
+        let text_size = self.text.as_ref().map_or([0.0, 0.0], |text| {
+            // rough average glyph width, as the font metrics aren't known
+            [text.chars().count() as f32 * font_size * 0.5, font_size]
+        });
+        let gap = if image_size[1] > 0.0 && text_size[1] > 0.0 {
+            GAP
+        }
+        else {
+            0.0
+        };
+        let width = image_size[0].max(text_size[0]);
+        let height = image_size[1] + gap + text_size[1];
+
+        let x = match self.corner {
+            Corner::TopLeft | Corner::BottomLeft => left + MARGIN + width / 2.0,
+            Corner::TopRight | Corner::BottomRight => {
+                right - MARGIN - width / 2.0
+            }
+        };
+        let block_top = match self.corner {
+            Corner::TopLeft | Corner::TopRight => top - MARGIN,
+            Corner::BottomLeft | Corner::BottomRight => {
+                bottom + MARGIN + height
+            }
+        };
+
+        Placement {
+            image: [x, block_top - image_size[1] / 2.0],
+            text: [x, block_top - image_size[1] - gap - text_size[1] / 2.0],
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::poster::PosterAngle;
+use nannou::rand::{seq::index, thread_rng};
+
+/// What a blanked value is replaced with.
+pub const BLANK: &str = "________";
+
+/// Randomly chooses `fraction` of `count` items to blank, returning whether
+/// each one is blanked.
+fn pick_blanks(count: usize, fraction: f32) -> Vec<bool> {
+    let num_blanks = (count as f32 * fraction.clamp(0.0, 1.0)).round() as usize;
+    let mut blanks = vec![false; count];
+
+    for i in index::sample(&mut thread_rng(), count, num_blanks) {
+        blanks[i] = true;
+    }
+
+    blanks
+}
+
+/// Copies `angles` with `fraction` of their labels blanked at random.
+pub fn blank_poster(angles: &[PosterAngle], fraction: f32) -> Vec<PosterAngle> {
+    let mut blanks = pick_blanks(angles.len() * 3, fraction).into_iter();
+    let mut blank = |label: &String| {
+        if blanks.next().unwrap_or(false) {
+            String::from(BLANK)
+        }
+        else {
+            label.clone()
+        }
+    };
+
+    angles
+        .iter()
+        .map(|angle| PosterAngle {
+            theta: angle.theta,
+            degrees: blank(&angle.degrees),
+            radians: blank(&angle.radians),
+            coords: blank(&angle.coords),
+        })
+        .collect()
+}
+
+/// Copies `rows` with `fraction` of their values blanked at random.
+pub fn blank_rows(
+    rows: &[(String, String)],
+    fraction: f32,
+) -> Vec<(String, String)> {
+    rows.iter()
+        .zip(pick_blanks(rows.len(), fraction))
+        .map(|((name, value), is_blank)| {
+            let value = if is_blank {
+                String::from(BLANK)
+            }
+            else {
+                value.clone()
+            };
+            (name.clone(), value)
+        })
+        .collect()
+}